@@ -25,6 +25,29 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("UTC round trip", |b| {
+        b.iter(|| {
+            let e = Epoch::from_gregorian_utc_hms(2015, 2, 7, 11, 22, 33);
+            black_box(Epoch::from_utc_duration(black_box(e.to_utc_duration())));
+            black_box(e.to_gregorian_utc());
+        })
+    });
+
+    c.bench_function("Gregorian TAI round trip", |b| {
+        b.iter(|| {
+            let e = Epoch::from_gregorian_tai_hms(black_box(2415), 2, 7, 11, 22, 33);
+            black_box(e.to_gregorian_tai());
+        })
+    });
+
+    c.bench_function("Leap seconds", |b| {
+        let e = Epoch::from_gregorian_tai_hms(2015, 2, 7, 11, 22, 33);
+        b.iter(|| {
+            black_box(black_box(e).leap_seconds(true));
+            black_box(black_box(e).leap_seconds(false));
+        })
+    });
+
     c.bench_function("Duration to f64 seconds", |b| {
         b.iter(|| {
             let d: Duration = Unit::Second * black_box(3.0);
@@ -26,6 +26,21 @@ fn epoch_jde_et_seconds() {
     black_box(Epoch::from_jde_tdb(2459943.186081989));
 }
 
+fn epoch_utc_round_trip() {
+    let e = Epoch::from_gregorian_utc_hms(2015, 2, 7, 11, 22, 33);
+    black_box(Epoch::from_utc_duration(e.to_utc_duration()));
+}
+
+fn epoch_to_gregorian_utc() {
+    let e = Epoch::from_gregorian_tai_hms(2015, 2, 7, 11, 22, 33);
+    black_box(e.to_gregorian_utc());
+}
+
+fn epoch_leap_seconds() {
+    let e = Epoch::from_gregorian_tai_hms(2015, 2, 7, 11, 22, 33);
+    black_box(e.leap_seconds(true));
+}
+
 fn epoch_add() {
     let e: Epoch = Epoch::from_gregorian_tai_hms(2015, 2, 7, 11, 22, 33);
     black_box(e + 50 * Unit::Second);
@@ -67,6 +82,9 @@ iai::main!(
     epoch_jde_tdb_seconds,
     epoch_from_et_seconds,
     epoch_jde_et_seconds,
+    epoch_utc_round_trip,
+    epoch_to_gregorian_utc,
+    epoch_leap_seconds,
     epoch_add,
     epoch_sub,
     parse_rfc3339_with_seconds,
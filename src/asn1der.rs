@@ -65,6 +65,9 @@ impl<'a> Decode<'a> for Epoch {
             TimeScale::GPST => Self::from_gpst_duration(duration),
             TimeScale::GST => Self::from_gst_duration(duration),
             TimeScale::BDT => Self::from_bdt_duration(duration),
+            TimeScale::TCB => Self::from_tcb_duration(duration),
+            TimeScale::TCG => Self::from_tcg_duration(duration),
+            TimeScale::UT1 => Self::from_duration(duration, TimeScale::UT1),
         })
     }
 }
@@ -92,7 +95,7 @@ impl<'a> Decode<'a> for Unit {
 // Testing the encoding and decoding of an Epoch inherently also tests the encoding and decoding of a Duration
 #[test]
 fn test_encdec() {
-    for ts_u8 in 0..=7 {
+    for ts_u8 in 0..=10 {
         let ts: TimeScale = ts_u8.into();
 
         let epoch = if ts == TimeScale::UTC {
@@ -110,6 +113,9 @@ fn test_encdec() {
             TimeScale::GPST => epoch.to_gpst_duration(),
             TimeScale::GST => epoch.to_gst_duration(),
             TimeScale::BDT => epoch.to_bdt_duration(),
+            TimeScale::TCB => epoch.to_tcb_duration(),
+            TimeScale::TCG => epoch.to_tcg_duration(),
+            TimeScale::UT1 => epoch.to_duration_in_time_scale(TimeScale::UT1),
         };
 
         let e_dur = epoch.to_duration();
@@ -0,0 +1,486 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::epoch::{days_from_civil, days_in_month};
+use crate::{Epoch, Errors, MonthName, ParsingErrors, TimeScale, Weekday};
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Defines how calendar arithmetic resolves a day of the month which does not exist in the resulting month,
+/// e.g. January 31 plus one month, or February 29 plus one year.
+///
+/// The same policy applies to the inserted leap second of UTC (23:59:60) when the resulting day has no leap second.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MonthEndPolicy {
+    /// Use the last day of the resulting month, e.g. January 31 plus one month is February 28 (or 29 in a leap year).
+    /// A leap second becomes 23:59:59.
+    #[default]
+    Clamp,
+    /// Carry the extra days into the following month, e.g. January 31 plus one month is March 03 (or 02 in a leap year).
+    /// A leap second becomes midnight of the following day.
+    Overflow,
+    /// Return `Errors::Carry`.
+    Strict,
+}
+
+/// A duration in calendar units, i.e. whose length in time depends on the epoch it is added to.
+///
+/// When added to an Epoch, the years and months are applied first, then the days, all in the time scale requested
+/// in `Epoch::add_calendar_duration`. For example, P1M1D from January 31 is March 01 in a non-leap year with `MonthEndPolicy::Clamp`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CalendarDuration {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl CalendarDuration {
+    /// Builds a new calendar duration from its years, months, and days
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// Builds a new calendar duration of the provided number of years
+    pub const fn from_years(years: i32) -> Self {
+        Self::new(years, 0, 0)
+    }
+
+    /// Builds a new calendar duration of the provided number of months
+    pub const fn from_months(months: i32) -> Self {
+        Self::new(0, months, 0)
+    }
+
+    /// Builds a new calendar duration of the provided number of days
+    pub const fn from_days(days: i32) -> Self {
+        Self::new(0, 0, days)
+    }
+
+    /// Returns the years and months of this calendar duration as a number of months
+    pub const fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+}
+
+impl Neg for CalendarDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(
+            self.years.saturating_neg(),
+            self.months.saturating_neg(),
+            self.days.saturating_neg(),
+        )
+    }
+}
+
+impl Add for CalendarDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.years.saturating_add(rhs.years),
+            self.months.saturating_add(rhs.months),
+            self.days.saturating_add(rhs.days),
+        )
+    }
+}
+
+impl Sub for CalendarDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.years.saturating_sub(rhs.years),
+            self.months.saturating_sub(rhs.months),
+            self.days.saturating_sub(rhs.days),
+        )
+    }
+}
+
+impl Mul<i32> for CalendarDuration {
+    type Output = Self;
+
+    fn mul(self, q: i32) -> Self {
+        Self::new(
+            self.years.saturating_mul(q),
+            self.months.saturating_mul(q),
+            self.days.saturating_mul(q),
+        )
+    }
+}
+
+impl fmt::Display for CalendarDuration {
+    /// Prints this calendar duration in the ISO 8601 format, e.g. `P1Y2M3D`, where each component may be negative, e.g. `P-1M`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == Self::default() {
+            return write!(f, "P0D");
+        }
+        write!(f, "P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        Ok(())
+    }
+}
+
+/// Defines the dates of a `CalendarSeries`, as a subset of the iCalendar recurrence rules (RFC 5545 RRULE).
+///
+/// Negative days of the month count from the end of the month, e.g. -1 is the last day of the month.
+/// As in RFC 5545, the periods (e.g. months) where the rule does not match any existing date are skipped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CalendarRule {
+    /// Every day (`FREQ=DAILY`)
+    Daily,
+    /// Every week on the provided weekday (`FREQ=WEEKLY;BYDAY=TU`)
+    Weekly { weekday: Weekday },
+    /// Every month on the provided day of the month (`FREQ=MONTHLY;BYMONTHDAY=-1`)
+    MonthlyOnDay { day: i8 },
+    /// Every month on the n-th provided weekday of the month, e.g. the first Monday is `nth: 1` and the last Friday is `nth: -1` (`FREQ=MONTHLY;BYDAY=1MO`)
+    MonthlyOnWeekday { nth: i8, weekday: Weekday },
+    /// Every year on the provided day of the provided month (`FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29`)
+    YearlyOnDay { month: MonthName, day: i8 },
+}
+
+impl CalendarRule {
+    /// Returns whether this rule may match any date.
+    const fn is_valid(&self) -> bool {
+        match *self {
+            Self::Daily | Self::Weekly { .. } => true,
+            Self::MonthlyOnDay { day } => day != 0 && day >= -31 && day <= 31,
+            Self::MonthlyOnWeekday { nth, .. } => nth != 0 && nth >= -5 && nth <= 5,
+            Self::YearlyOnDay { month, day } => {
+                // Leap years have the longest months
+                let last_day = days_in_month(2000, month as u8 + 1) as i8;
+                day != 0 && day >= -last_day && day <= last_day
+            }
+        }
+    }
+}
+
+impl fmt::Display for CalendarRule {
+    /// Prints this rule in the RFC 5545 RRULE format
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Daily => write!(f, "FREQ=DAILY"),
+            Self::Weekly { weekday } => write!(f, "FREQ=WEEKLY;BYDAY={}", rrule_weekday(weekday)),
+            Self::MonthlyOnDay { day } => write!(f, "FREQ=MONTHLY;BYMONTHDAY={day}"),
+            Self::MonthlyOnWeekday { nth, weekday } => {
+                write!(f, "FREQ=MONTHLY;BYDAY={nth}{}", rrule_weekday(weekday))
+            }
+            Self::YearlyOnDay { month, day } => {
+                write!(
+                    f,
+                    "FREQ=YEARLY;BYMONTH={};BYMONTHDAY={day}",
+                    month as u8 + 1
+                )
+            }
+        }
+    }
+}
+
+/// Returns the two letter code of the weekday used in RFC 5545
+const fn rrule_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+/// Returns the day of the month of the provided (possibly negative) day in the provided month, if it exists.
+fn resolve_month_day(year: i32, month: u8, day: i8) -> Option<u8> {
+    let last_day = days_in_month(year, month) as i8;
+    if day > 0 && day <= last_day {
+        Some(day as u8)
+    } else if day < 0 && -day <= last_day {
+        Some((last_day + 1 + day) as u8)
+    } else {
+        None
+    }
+}
+
+/// Returns the day of the month of the n-th (possibly negative) provided weekday in the provided month, if it exists.
+fn resolve_month_weekday(year: i32, month: u8, nth: i8, weekday: Weekday) -> Option<u8> {
+    let last_day = days_in_month(year, month);
+    let day = if nth > 0 {
+        let first = weekday_of_civil_day(days_from_civil(year, month, 1));
+        1 + days_until(first, weekday) + 7 * (nth as u8 - 1)
+    } else {
+        let last = weekday_of_civil_day(days_from_civil(year, month, last_day));
+        let last_match = last_day - days_until(weekday, last);
+        last_match.checked_sub(7 * (-nth) as u8 - 7)?
+    };
+    (day >= 1 && day <= last_day).then_some(day)
+}
+
+/// Returns the weekday of the provided number of days since 1900 January 01.
+fn weekday_of_civil_day(days: i64) -> Weekday {
+    // J1900 was a Monday so we just have to modulo the number of days by the number of days per week.
+    Weekday::from(days.rem_euclid(7) as u8)
+}
+
+/// Returns the number of days from the `from` weekday to the next `to` weekday, which is zero if they are the same.
+fn days_until(from: Weekday, to: Weekday) -> u8 {
+    (u8::from(to) + 7 - u8::from(from)) % 7
+}
+
+/// An iterator of the Epochs matching a calendar rule, e.g. the last day of each month, or the first Monday of each quarter.
+///
+/// All of the Epochs are at the time of day of the start of the series, in the time scale in which the calendar is evaluated.
+/// This is the time scale of the start epoch, unless changed with `with_time_scale`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarSeries {
+    start: Epoch,
+    end: Epoch,
+    rule: CalendarRule,
+    interval: u32,
+    time_scale: TimeScale,
+    period: i64,
+    /// Gregorian year and month of the start in the time scale of the calendar
+    start_year: i32,
+    start_month: u8,
+    /// Days since 1900 January 01 of the start and of the end in the time scale of the calendar
+    start_day: i64,
+    end_day: i64,
+    /// Time of day (hours, minutes, seconds, nanoseconds) of the start in the time scale of the calendar
+    time_of_day: (u8, u8, u8, u32),
+}
+
+impl CalendarSeries {
+    /// Returns an iterator of the Epochs matching the rule, **inclusive** on start **and** on end.
+    /// Returns `Errors::ParseError(ParsingErrors::ValueError)` if the rule cannot match any date, e.g. the 32nd day of the month or the sixth Monday of the month.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{CalendarRule, CalendarSeries, Epoch, Weekday};
+    ///
+    /// // Every Tuesday at 14:00 UTC
+    /// let start = Epoch::from_gregorian_utc_hms(2023, 3, 1, 14, 0, 0);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2023, 4, 1);
+    /// let series = CalendarSeries::new(start, end, CalendarRule::Weekly { weekday: Weekday::Tuesday }).unwrap();
+    /// let tuesdays: Vec<Epoch> = series.collect();
+    /// assert_eq!(tuesdays.len(), 4);
+    /// assert_eq!(tuesdays[0], Epoch::from_gregorian_utc_hms(2023, 3, 7, 14, 0, 0));
+    /// assert_eq!(tuesdays[3], Epoch::from_gregorian_utc_hms(2023, 3, 28, 14, 0, 0));
+    /// ```
+    pub fn new(start: Epoch, end: Epoch, rule: CalendarRule) -> Result<Self, Errors> {
+        if !rule.is_valid() {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        Ok(Self {
+            start,
+            end,
+            rule,
+            interval: 1,
+            time_scale: start.time_scale,
+            period: 0,
+            start_year: 0,
+            start_month: 0,
+            start_day: 0,
+            end_day: 0,
+            time_of_day: (0, 0, 0, 0),
+        }
+        .with_time_scale(start.time_scale))
+    }
+
+    /// Returns a copy of this series which only matches every `interval` periods (days, weeks, months, or years) from the start (RRULE `INTERVAL`).
+    /// An interval of zero is treated as one.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{CalendarRule, CalendarSeries, Epoch, Weekday};
+    ///
+    /// // First Monday of each quarter
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2024, 12, 31);
+    /// let rule = CalendarRule::MonthlyOnWeekday { nth: 1, weekday: Weekday::Monday };
+    /// let series = CalendarSeries::new(start, end, rule).unwrap().with_interval(3);
+    /// assert_eq!(
+    ///     series.collect::<Vec<Epoch>>(),
+    ///     vec![
+    ///         Epoch::from_gregorian_utc_at_midnight(2024, 1, 1),
+    ///         Epoch::from_gregorian_utc_at_midnight(2024, 4, 1),
+    ///         Epoch::from_gregorian_utc_at_midnight(2024, 7, 1),
+    ///         Epoch::from_gregorian_utc_at_midnight(2024, 10, 7),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Returns a copy of this series where the calendar is evaluated in the provided time scale.
+    /// The Epochs of the series remain in the time scale of the start epoch.
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: TimeScale) -> Self {
+        self.time_scale = time_scale;
+
+        let (year, month, day, hour, minute, second, nanos) =
+            self.start.in_time_scale(time_scale).to_gregorian();
+        self.start_year = year;
+        self.start_month = month;
+        self.start_day = days_from_civil(year, month, day);
+        self.time_of_day = (hour, minute, second, nanos);
+
+        let (year, month, day, _, _, _, _) = self.end.in_time_scale(time_scale).to_gregorian();
+        self.end_day = days_from_civil(year, month, day);
+        self
+    }
+
+    /// Returns the calendar rule of this series
+    pub const fn rule(&self) -> CalendarRule {
+        self.rule
+    }
+}
+
+impl Iterator for CalendarSeries {
+    type Item = Epoch;
+
+    fn next(&mut self) -> Option<Epoch> {
+        let (year, month, start_day) = (self.start_year, self.start_month, self.start_day);
+
+        loop {
+            let step = self.period.checked_mul(i64::from(self.interval))?;
+            self.period += 1;
+
+            // The first day of the period, and the day matching the rule in that period, if any.
+            let (period_day, matching_day) = match self.rule {
+                CalendarRule::Daily => (start_day + step, Some(start_day + step)),
+                CalendarRule::Weekly { weekday } => {
+                    let first =
+                        start_day + i64::from(days_until(weekday_of_civil_day(start_day), weekday));
+                    (first + 7 * step, Some(first + 7 * step))
+                }
+                CalendarRule::MonthlyOnDay { .. } | CalendarRule::MonthlyOnWeekday { .. } => {
+                    let months = i64::from(year) * 12 + i64::from(month - 1) + step;
+                    let year = i32::try_from(months.div_euclid(12)).ok()?;
+                    let month = months.rem_euclid(12) as u8 + 1;
+                    let day = match self.rule {
+                        CalendarRule::MonthlyOnDay { day } => resolve_month_day(year, month, day),
+                        CalendarRule::MonthlyOnWeekday { nth, weekday } => {
+                            resolve_month_weekday(year, month, nth, weekday)
+                        }
+                        _ => unreachable!(),
+                    };
+                    (
+                        days_from_civil(year, month, 1),
+                        day.map(|day| days_from_civil(year, month, day)),
+                    )
+                }
+                CalendarRule::YearlyOnDay {
+                    month: month_name,
+                    day,
+                } => {
+                    let year = i32::try_from(i64::from(year) + step).ok()?;
+                    let month = month_name as u8 + 1;
+                    (
+                        days_from_civil(year, 1, 1),
+                        resolve_month_day(year, month, day)
+                            .map(|day| days_from_civil(year, month, day)),
+                    )
+                }
+            };
+
+            if period_day > self.end_day {
+                return None;
+            }
+
+            if let Some(days) = matching_day {
+                // Only the leap second itself may be missing from that day, in which case the end of that day is used.
+                if let Ok(epoch) = Epoch::from_civil_day(
+                    days,
+                    self.time_of_day,
+                    MonthEndPolicy::Clamp,
+                    self.time_scale,
+                ) {
+                    if epoch > self.end {
+                        return None;
+                    } else if epoch >= self.start {
+                        return Some(epoch.in_time_scale(self.start.time_scale));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for CalendarSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CalendarSeries [{} : {} : {}",
+            self.start, self.end, self.rule
+        )?;
+        if self.interval > 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CalendarDuration {
+    #[new]
+    fn new_py(years: i32, months: i32, days: i32) -> Self {
+        Self::new(years, months, days)
+    }
+
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn calendar_duration_display() {
+    assert_eq!(format!("{}", CalendarDuration::default()), "P0D");
+    assert_eq!(format!("{}", CalendarDuration::new(1, 2, 3)), "P1Y2M3D");
+    assert_eq!(format!("{}", -CalendarDuration::from_months(1)), "P-1M");
+    assert_eq!(
+        format!(
+            "{}",
+            CalendarDuration::from_years(1) * 3 - CalendarDuration::from_days(2)
+        ),
+        "P3Y-2D"
+    );
+    assert_eq!(CalendarDuration::new(2, -3, 0).total_months(), 21);
+}
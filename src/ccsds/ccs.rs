@@ -0,0 +1,182 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{field, field_mut, read_bcd, write_bcd, TimeCode};
+use crate::epoch::{days_from_civil, days_in_month, is_leap_year};
+use crate::{Epoch, Errors, MonthEndPolicy, ParsingErrors, TimeScale};
+
+/// Time code identification of the P-field of a CCS.
+const CCS_ID: u8 = 0b101;
+
+/// The CCSDS Calendar Segmented time code (CCS): the date and time of day in the Gregorian calendar of its time scale,
+/// in binary coded decimal.
+///
+/// The date is either the year, month and day of the month, or the year and day of the year (from 001). It is followed
+/// by the hour, minute and second (up to 60 during a leap second of UTC), then by 0 to 6 octets of two decimal digits each
+/// of the fraction of a second, i.e. down to picoseconds.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CcsFormat {
+    day_of_year: bool,
+    subsecond_octets: u8,
+    time_scale: TimeScale,
+}
+
+impl CcsFormat {
+    /// Maximum number of octets of the fraction of a second.
+    pub const MAX_SUBSECOND_OCTETS: u8 = 6;
+
+    /// Initializes a CCS in TAI with the month and day of the month, and the provided number of octets of the fraction of a second.
+    pub fn month_day(subsecond_octets: u8) -> Result<Self, Errors> {
+        Self::new(false, subsecond_octets)
+    }
+
+    /// Initializes a CCS in TAI with the day of the year, and the provided number of octets of the fraction of a second.
+    pub fn day_of_year(subsecond_octets: u8) -> Result<Self, Errors> {
+        Self::new(true, subsecond_octets)
+    }
+
+    fn new(day_of_year: bool, subsecond_octets: u8) -> Result<Self, Errors> {
+        if subsecond_octets > Self::MAX_SUBSECOND_OCTETS {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        Ok(Self {
+            day_of_year,
+            subsecond_octets,
+            time_scale: TimeScale::TAI,
+        })
+    }
+
+    /// Returns a copy of this time code in the provided time scale.
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: TimeScale) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Returns whether the date is the day of the year, rather than the month and day of the month.
+    pub const fn is_day_of_year(&self) -> bool {
+        self.day_of_year
+    }
+
+    /// Returns the number of octets of the fraction of a second.
+    pub const fn subsecond_octets(&self) -> u8 {
+        self.subsecond_octets
+    }
+
+    /// Returns the time scale of this time code.
+    pub const fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    /// Returns the power of ten between nanoseconds and the fraction of a second, and whether the fraction is finer than nanoseconds.
+    fn subsecond_scale(&self) -> (u64, bool) {
+        let digits = 2 * u32::from(self.subsecond_octets);
+        if digits > 9 {
+            (10_u64.pow(digits - 9), true)
+        } else {
+            (10_u64.pow(9 - digits), false)
+        }
+    }
+}
+
+impl TimeCode for CcsFormat {
+    fn pfield(&self) -> ([u8; 2], usize) {
+        let first = CCS_ID << 4 | u8::from(self.day_of_year) << 3 | self.subsecond_octets;
+        ([first, 0], 1)
+    }
+
+    fn with_pfield(&self, buf: &[u8]) -> Result<(Self, usize), Errors> {
+        let first = field(buf, 1)?[0];
+        // The P-field of a CCS is never extended.
+        if first >> 4 != CCS_ID {
+            return Err(Errors::ParseError(ParsingErrors::InvalidPField));
+        }
+        match Self::new(first & 0b1000 != 0, first & 0b111) {
+            Ok(time_code) => Ok((time_code.with_time_scale(self.time_scale), 1)),
+            Err(_) => Err(Errors::ParseError(ParsingErrors::InvalidPField)),
+        }
+    }
+
+    fn tfield_len(&self) -> usize {
+        7 + usize::from(self.subsecond_octets)
+    }
+
+    fn encode_tfield(&self, epoch: Epoch, buf: &mut [u8]) -> Result<usize, Errors> {
+        let len = self.tfield_len();
+        let buf = field_mut(buf, len)?;
+
+        let (year, month, day, hour, minute, second, nanos) =
+            epoch.in_time_scale(self.time_scale).to_gregorian();
+        if !(1..=9999).contains(&year) {
+            return Err(Errors::Overflow);
+        }
+
+        write_bcd(year as u64, &mut buf[..2]);
+        if self.day_of_year {
+            let day_of_year = days_from_civil(year, month, day) - days_from_civil(year, 1, 1) + 1;
+            write_bcd(day_of_year as u64, &mut buf[2..4]);
+        } else {
+            write_bcd(u64::from(month), &mut buf[2..3]);
+            write_bcd(u64::from(day), &mut buf[3..4]);
+        }
+        write_bcd(u64::from(hour), &mut buf[4..5]);
+        write_bcd(u64::from(minute), &mut buf[5..6]);
+        write_bcd(u64::from(second), &mut buf[6..7]);
+
+        let subseconds = match self.subsecond_scale() {
+            (scale, true) => u64::from(nanos) * scale,
+            (scale, false) => u64::from(nanos) / scale,
+        };
+        write_bcd(subseconds, &mut buf[7..]);
+        Ok(len)
+    }
+
+    fn decode_tfield(&self, buf: &[u8]) -> Result<Epoch, Errors> {
+        let buf = field(buf, self.tfield_len())?;
+        let value_error = Errors::ParseError(ParsingErrors::ValueError);
+
+        let year = read_bcd(&buf[..2])? as i32;
+        let days = if self.day_of_year {
+            let day_of_year = read_bcd(&buf[2..4])? as i64;
+            let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+            if !(1..=days_in_year).contains(&day_of_year) {
+                return Err(value_error);
+            }
+            days_from_civil(year, 1, 1) + day_of_year - 1
+        } else {
+            let month = read_bcd(&buf[2..3])? as u8;
+            let day = read_bcd(&buf[3..4])? as u8;
+            if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+                return Err(value_error);
+            }
+            days_from_civil(year, month, day)
+        };
+
+        let hour = read_bcd(&buf[4..5])? as u8;
+        let minute = read_bcd(&buf[5..6])? as u8;
+        let second = read_bcd(&buf[6..7])? as u8;
+        if year == 0 || hour > 23 || minute > 59 || second > 60 {
+            return Err(value_error);
+        }
+
+        let subseconds = read_bcd(&buf[7..])?;
+        let nanos = match self.subsecond_scale() {
+            (scale, true) => subseconds / scale,
+            (scale, false) => subseconds * scale,
+        };
+
+        Epoch::from_civil_day(
+            days,
+            (hour, minute, second, nanos as u32),
+            MonthEndPolicy::Strict,
+            self.time_scale,
+        )
+    }
+}
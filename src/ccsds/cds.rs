@@ -0,0 +1,238 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{field, field_mut, pfield_epoch, read_uint, write_uint, TimeCode, CCSDS_REF_EPOCH};
+use crate::epoch::days_from_civil;
+use crate::{
+    Epoch, Errors, MonthEndPolicy, ParsingErrors, TimeScale, NANOSECONDS_PER_MICROSECOND,
+    NANOSECONDS_PER_MILLISECOND,
+};
+
+/// Time code identification of the P-field of a CDS.
+const CDS_ID: u8 = 0b100;
+/// Milliseconds in a day, excluding a leap second.
+const MILLISECONDS_PER_DAY: u32 = 86_400_000;
+
+/// Resolution of the submillisecond segment of a CDS.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CdsResolution {
+    /// No submillisecond segment.
+    #[default]
+    Milliseconds,
+    /// Microseconds of the millisecond, on two octets.
+    Microseconds,
+    /// Picoseconds of the millisecond, on four octets.
+    Picoseconds,
+}
+
+impl CdsResolution {
+    /// Returns the length of the submillisecond segment in octets.
+    const fn octets(self) -> usize {
+        match self {
+            Self::Milliseconds => 0,
+            Self::Microseconds => 2,
+            Self::Picoseconds => 4,
+        }
+    }
+}
+
+/// The CCSDS Day Segmented time code (CDS): the number of days elapsed since the epoch of the time code, the milliseconds
+/// of the day, and optionally the microseconds or picoseconds of the millisecond, in the time scale of the time code.
+///
+/// Days are those of the Gregorian calendar of the time scale, so the epoch of the time code should be at midnight in that time scale.
+/// In UTC, the milliseconds of a day with a leap second go up to 86,400,999.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CdsFormat {
+    day_octets: u8,
+    resolution: CdsResolution,
+    agency_epoch: Option<Epoch>,
+    time_scale: TimeScale,
+}
+
+impl CdsFormat {
+    /// Initializes a level 1 CDS, counting TAI days since 1958 January 01, with a day segment of 2 or 3 octets and the provided resolution.
+    pub fn new(day_octets: u8, resolution: CdsResolution) -> Result<Self, Errors> {
+        if !(2..=3).contains(&day_octets) {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        Ok(Self {
+            day_octets,
+            resolution,
+            agency_epoch: None,
+            time_scale: TimeScale::TAI,
+        })
+    }
+
+    /// Returns a copy of this time code counting days since the provided agency-defined epoch (level 2).
+    #[must_use]
+    pub fn with_agency_epoch(mut self, epoch: Epoch) -> Self {
+        self.agency_epoch = Some(epoch);
+        self
+    }
+
+    /// Returns a copy of this time code counting days in the provided time scale.
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: TimeScale) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Returns the number of octets of the day segment.
+    pub const fn day_octets(&self) -> u8 {
+        self.day_octets
+    }
+
+    /// Returns the resolution of the submillisecond segment.
+    pub const fn resolution(&self) -> CdsResolution {
+        self.resolution
+    }
+
+    /// Returns the agency-defined epoch of this time code, or None if it is a level 1 time code.
+    pub const fn agency_epoch(&self) -> Option<Epoch> {
+        self.agency_epoch
+    }
+
+    /// Returns the epoch since which this time code counts days.
+    pub fn epoch(&self) -> Epoch {
+        self.agency_epoch.unwrap_or(CCSDS_REF_EPOCH)
+    }
+
+    /// Returns the time scale in which this time code counts days.
+    pub const fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    /// Returns the number of days between 1900 January 01 and the day of the epoch of this time code.
+    fn epoch_days(&self) -> i64 {
+        let (year, month, day, _, _, _, _) =
+            self.epoch().in_time_scale(self.time_scale).to_gregorian();
+        days_from_civil(year, month, day)
+    }
+}
+
+impl TimeCode for CdsFormat {
+    fn pfield(&self) -> ([u8; 2], usize) {
+        let resolution = match self.resolution {
+            CdsResolution::Milliseconds => 0b00,
+            CdsResolution::Microseconds => 0b01,
+            CdsResolution::Picoseconds => 0b10,
+        };
+        let first = CDS_ID << 4
+            | u8::from(self.agency_epoch.is_some()) << 3
+            | u8::from(self.day_octets == 3) << 2
+            | resolution;
+        ([first, 0], 1)
+    }
+
+    fn with_pfield(&self, buf: &[u8]) -> Result<(Self, usize), Errors> {
+        let first = field(buf, 1)?[0];
+        // The P-field of a CDS is never extended.
+        if first >> 4 != CDS_ID {
+            return Err(Errors::ParseError(ParsingErrors::InvalidPField));
+        }
+        let resolution = match first & 0b11 {
+            0b00 => CdsResolution::Milliseconds,
+            0b01 => CdsResolution::Microseconds,
+            0b10 => CdsResolution::Picoseconds,
+            _ => return Err(Errors::ParseError(ParsingErrors::InvalidPField)),
+        };
+
+        Ok((
+            Self {
+                day_octets: if first & 0b100 != 0 { 3 } else { 2 },
+                resolution,
+                agency_epoch: pfield_epoch(first & 0b1000 != 0, self.agency_epoch)?,
+                time_scale: self.time_scale,
+            },
+            1,
+        ))
+    }
+
+    fn tfield_len(&self) -> usize {
+        usize::from(self.day_octets) + 4 + self.resolution.octets()
+    }
+
+    fn encode_tfield(&self, epoch: Epoch, buf: &mut [u8]) -> Result<usize, Errors> {
+        let len = self.tfield_len();
+        let buf = field_mut(buf, len)?;
+
+        let (year, month, day, hour, minute, second, nanos) =
+            epoch.in_time_scale(self.time_scale).to_gregorian();
+        let days = days_from_civil(year, month, day) - self.epoch_days();
+        if days < 0 || days >> (8 * self.day_octets) != 0 {
+            return Err(Errors::Overflow);
+        }
+
+        let milliseconds = ((u32::from(hour) * 60 + u32::from(minute)) * 60 + u32::from(second))
+            * 1_000
+            + nanos / NANOSECONDS_PER_MILLISECOND as u32;
+        let submilliseconds = nanos % NANOSECONDS_PER_MILLISECOND as u32;
+
+        let (days_buf, buf) = buf.split_at_mut(usize::from(self.day_octets));
+        let (milliseconds_buf, submilliseconds_buf) = buf.split_at_mut(4);
+        write_uint(days as u128, days_buf);
+        write_uint(u128::from(milliseconds), milliseconds_buf);
+        match self.resolution {
+            CdsResolution::Milliseconds => {}
+            CdsResolution::Microseconds => write_uint(
+                u128::from(submilliseconds / NANOSECONDS_PER_MICROSECOND as u32),
+                submilliseconds_buf,
+            ),
+            CdsResolution::Picoseconds => {
+                write_uint(u128::from(submilliseconds) * 1_000, submilliseconds_buf)
+            }
+        }
+        Ok(len)
+    }
+
+    fn decode_tfield(&self, buf: &[u8]) -> Result<Epoch, Errors> {
+        let (days_buf, buf) = field(buf, self.tfield_len())?.split_at(usize::from(self.day_octets));
+        let (milliseconds_buf, submilliseconds_buf) = buf.split_at(4);
+
+        let days = read_uint(days_buf) as i64;
+        let milliseconds = read_uint(milliseconds_buf) as u32;
+        let submilliseconds = read_uint(submilliseconds_buf) as u32;
+
+        // Up to 86,400,999 milliseconds on a day with a leap second.
+        if milliseconds >= MILLISECONDS_PER_DAY + 1_000 {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        let submilliseconds_nanos = match self.resolution {
+            CdsResolution::Milliseconds => 0,
+            CdsResolution::Microseconds if submilliseconds < 1_000 => {
+                submilliseconds * NANOSECONDS_PER_MICROSECOND as u32
+            }
+            CdsResolution::Picoseconds if submilliseconds < 1_000_000_000 => {
+                submilliseconds / 1_000
+            }
+            _ => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+        };
+
+        let seconds = milliseconds / 1_000;
+        let (hour, minute, second) = if seconds >= 86_400 {
+            (23, 59, 60)
+        } else {
+            (
+                (seconds / 3_600) as u8,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+            )
+        };
+        let nanos =
+            milliseconds % 1_000 * NANOSECONDS_PER_MILLISECOND as u32 + submilliseconds_nanos;
+
+        Epoch::from_civil_day(
+            self.epoch_days() + days,
+            (hour, minute, second, nanos),
+            MonthEndPolicy::Strict,
+            self.time_scale,
+        )
+    }
+}
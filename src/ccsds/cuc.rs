@@ -0,0 +1,200 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{field, field_mut, pfield_epoch, read_uint, write_uint, TimeCode, CCSDS_REF_EPOCH};
+use crate::{Duration, Epoch, Errors, ParsingErrors, TimeScale, NANOSECONDS_PER_SECOND};
+
+/// Time code identification of the P-field of a level 1 CUC.
+const LEVEL1_ID: u8 = 0b001;
+/// Time code identification of the P-field of a level 2 CUC.
+const LEVEL2_ID: u8 = 0b010;
+
+/// The CCSDS Unsegmented time Code (CUC): the number of seconds (the coarse time) and of binary fractions of a second
+/// (the fine time) elapsed since the epoch of the time code, in its time scale.
+///
+/// The coarse time spans 1 to 7 octets, and the fine time 0 to 10 octets, where each octet of fine time divides the second by 256 more.
+/// Fine times are truncated to their resolution when encoding, and rounded to the nearest nanosecond when decoding, so epochs
+/// survive a round trip through four or more octets of fine time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CucFormat {
+    coarse_octets: u8,
+    fine_octets: u8,
+    agency_epoch: Option<Epoch>,
+    time_scale: TimeScale,
+}
+
+impl CucFormat {
+    /// Maximum number of octets of coarse time.
+    pub const MAX_COARSE_OCTETS: u8 = 7;
+    /// Maximum number of octets of fine time.
+    pub const MAX_FINE_OCTETS: u8 = 10;
+
+    /// Initializes a level 1 CUC, counting TAI seconds since 1958 January 01, with the provided number of octets of coarse and fine time.
+    pub fn new(coarse_octets: u8, fine_octets: u8) -> Result<Self, Errors> {
+        if !(1..=Self::MAX_COARSE_OCTETS).contains(&coarse_octets)
+            || fine_octets > Self::MAX_FINE_OCTETS
+        {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        Ok(Self {
+            coarse_octets,
+            fine_octets,
+            agency_epoch: None,
+            time_scale: TimeScale::TAI,
+        })
+    }
+
+    /// Returns a copy of this time code counting time since the provided agency-defined epoch (level 2).
+    #[must_use]
+    pub fn with_agency_epoch(mut self, epoch: Epoch) -> Self {
+        self.agency_epoch = Some(epoch);
+        self
+    }
+
+    /// Returns a copy of this time code counting time in the provided time scale.
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: TimeScale) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Returns the number of octets of coarse time.
+    pub const fn coarse_octets(&self) -> u8 {
+        self.coarse_octets
+    }
+
+    /// Returns the number of octets of fine time.
+    pub const fn fine_octets(&self) -> u8 {
+        self.fine_octets
+    }
+
+    /// Returns the agency-defined epoch of this time code, or None if it is a level 1 time code.
+    pub const fn agency_epoch(&self) -> Option<Epoch> {
+        self.agency_epoch
+    }
+
+    /// Returns the epoch since which this time code counts time.
+    pub fn epoch(&self) -> Epoch {
+        self.agency_epoch.unwrap_or(CCSDS_REF_EPOCH)
+    }
+
+    /// Returns the time scale in which this time code counts time.
+    pub const fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+}
+
+impl TimeCode for CucFormat {
+    fn pfield(&self) -> ([u8; 2], usize) {
+        let id = if self.agency_epoch.is_some() {
+            LEVEL2_ID
+        } else {
+            LEVEL1_ID
+        };
+        // The first octet holds up to 4 octets of coarse time and 3 of fine time, the extension holds the others.
+        let coarse = self.coarse_octets.min(4);
+        let fine = self.fine_octets.min(3);
+        let first = id << 4 | (coarse - 1) << 2 | fine;
+
+        if coarse == self.coarse_octets && fine == self.fine_octets {
+            ([first, 0], 1)
+        } else {
+            let second = (self.coarse_octets - coarse) << 5 | (self.fine_octets - fine) << 2;
+            ([0x80 | first, second], 2)
+        }
+    }
+
+    fn with_pfield(&self, buf: &[u8]) -> Result<(Self, usize), Errors> {
+        let first = field(buf, 1)?[0];
+        let level2 = match (first >> 4) & 0b111 {
+            LEVEL1_ID => false,
+            LEVEL2_ID => true,
+            _ => return Err(Errors::ParseError(ParsingErrors::InvalidPField)),
+        };
+
+        let mut coarse_octets = ((first >> 2) & 0b11) + 1;
+        let mut fine_octets = first & 0b11;
+        let mut len = 1;
+
+        if first & 0x80 != 0 {
+            let second = field(buf, 2)?[1];
+            if second & 0x80 != 0 {
+                // No further extension of the P-field is defined.
+                return Err(Errors::ParseError(ParsingErrors::InvalidPField));
+            }
+            coarse_octets += (second >> 5) & 0b11;
+            fine_octets += (second >> 2) & 0b111;
+            len = 2;
+        }
+
+        Ok((
+            Self {
+                coarse_octets,
+                fine_octets,
+                agency_epoch: pfield_epoch(level2, self.agency_epoch)?,
+                time_scale: self.time_scale,
+            },
+            len,
+        ))
+    }
+
+    fn tfield_len(&self) -> usize {
+        usize::from(self.coarse_octets + self.fine_octets)
+    }
+
+    fn encode_tfield(&self, epoch: Epoch, buf: &mut [u8]) -> Result<usize, Errors> {
+        let len = self.tfield_len();
+        let buf = field_mut(buf, len)?;
+
+        let elapsed = (epoch.to_duration_in_time_scale(self.time_scale)
+            - self.epoch().to_duration_in_time_scale(self.time_scale))
+        .total_nanoseconds();
+        if elapsed < 0 {
+            return Err(Errors::Overflow);
+        }
+
+        let nanoseconds_per_second = u128::from(NANOSECONDS_PER_SECOND);
+        let seconds = elapsed as u128 / nanoseconds_per_second;
+        let nanoseconds = elapsed as u128 % nanoseconds_per_second;
+        if seconds >> (8 * self.coarse_octets) != 0 {
+            return Err(Errors::Overflow);
+        }
+        let fine = (nanoseconds << (8 * self.fine_octets)) / nanoseconds_per_second;
+
+        let (coarse_buf, fine_buf) = buf.split_at_mut(usize::from(self.coarse_octets));
+        write_uint(seconds, coarse_buf);
+        write_uint(fine, fine_buf);
+        Ok(len)
+    }
+
+    fn decode_tfield(&self, buf: &[u8]) -> Result<Epoch, Errors> {
+        let (coarse_buf, fine_buf) =
+            field(buf, self.tfield_len())?.split_at(usize::from(self.coarse_octets));
+
+        let nanoseconds_per_second = u128::from(NANOSECONDS_PER_SECOND);
+        let seconds = read_uint(coarse_buf);
+        let nanoseconds = match 8 * u32::from(self.fine_octets) {
+            0 => 0,
+            // Rounds to the nearest nanosecond.
+            bits => (read_uint(fine_buf) * nanoseconds_per_second + (1 << (bits - 1))) >> bits,
+        };
+
+        let elapsed = seconds * nanoseconds_per_second + nanoseconds;
+        if elapsed > Duration::MAX.total_nanoseconds() as u128 {
+            return Err(Errors::Overflow);
+        }
+        let duration = self
+            .epoch()
+            .to_duration_in_time_scale(self.time_scale)
+            .checked_add(Duration::from_total_nanoseconds(elapsed as i128))?;
+
+        Ok(Epoch::from_duration(duration, self.time_scale))
+    }
+}
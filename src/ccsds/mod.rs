@@ -0,0 +1,146 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Binary time codes of CCSDS 301.0-B-4 "Time Code Formats", as used in the telemetry and telecommands of spacecraft.
+//!
+//! A time code is made of a T-field, which holds the time itself, optionally preceded by a P-field (the preamble),
+//! which identifies the time code and its layout. Three time codes are supported:
+//! + [`CucFormat`]: the CCSDS Unsegmented time Code, a binary count of seconds and fractions of a second since the epoch of the time code;
+//! + [`CdsFormat`]: the CCSDS Day Segmented time code, a count of days since the epoch of the time code and of milliseconds in the day;
+//! + [`CcsFormat`]: the CCSDS Calendar Segmented time code, the calendar date and time of day in binary coded decimal.
+//!
+//! The CUC and CDS codes count time either since the CCSDS epoch of 1958 January 01 (level 1), or since an epoch defined by the agency (level 2).
+//! The time scale of all codes is chosen by the mission, and defaults to TAI.
+//!
+//! Time codes are encoded into and decoded from buffers provided by the caller, so this module does not allocate.
+//!
+//! # Example
+//! ```
+//! use hifitime::ccsds::{CucFormat, TimeCode, CCSDS_REF_EPOCH};
+//! use hifitime::Unit;
+//!
+//! // Four octets of seconds and two octets of binary fractions of a second since 1958 January 01 TAI.
+//! let cuc = CucFormat::new(4, 2).unwrap();
+//! let epoch = CCSDS_REF_EPOCH + Unit::Second * 1.5;
+//!
+//! let mut buf = [0_u8; 16];
+//! let len = cuc.encode(epoch, &mut buf).unwrap();
+//! assert_eq!(buf[..len], [0x1E, 0x00, 0x00, 0x00, 0x01, 0x80, 0x00]);
+//! assert_eq!(cuc.decode(&buf[..len]).unwrap(), epoch);
+//! ```
+
+pub mod ccs;
+pub mod cds;
+pub mod cuc;
+
+pub use ccs::CcsFormat;
+pub use cds::{CdsFormat, CdsResolution};
+pub use cuc::CucFormat;
+
+use crate::{Duration, Epoch, Errors, ParsingErrors};
+
+/// The epoch of level 1 CCSDS time codes, 1958 January 01 at midnight TAI.
+pub const CCSDS_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 1_830_297_600_000_000_000,
+});
+
+/// A CCSDS time code, which encodes an epoch into its T-field, optionally preceded by its P-field.
+pub trait TimeCode: Sized {
+    /// Returns the P-field identifying this time code, and its length in octets.
+    fn pfield(&self) -> ([u8; 2], usize);
+
+    /// Returns a copy of this time code with the layout of the P-field at the start of the buffer, and the length of that P-field.
+    ///
+    /// The time scale (and agency epoch, if the P-field is level 2) of the copy are those of this time code.
+    fn with_pfield(&self, buf: &[u8]) -> Result<(Self, usize), Errors>;
+
+    /// Returns the length of the T-field of this time code in octets.
+    fn tfield_len(&self) -> usize;
+
+    /// Encodes the epoch into the T-field at the start of the buffer, and returns the length of the T-field.
+    fn encode_tfield(&self, epoch: Epoch, buf: &mut [u8]) -> Result<usize, Errors>;
+
+    /// Decodes the epoch of the T-field at the start of the buffer.
+    fn decode_tfield(&self, buf: &[u8]) -> Result<Epoch, Errors>;
+
+    /// Encodes the P-field then the T-field of the epoch at the start of the buffer, and returns their length.
+    fn encode(&self, epoch: Epoch, buf: &mut [u8]) -> Result<usize, Errors> {
+        let (pfield, len) = self.pfield();
+        if buf.len() < len + self.tfield_len() {
+            return Err(Errors::ParseError(ParsingErrors::BufferTooSmall));
+        }
+        buf[..len].copy_from_slice(&pfield[..len]);
+        Ok(len + self.encode_tfield(epoch, &mut buf[len..])?)
+    }
+
+    /// Decodes the epoch of the time code at the start of the buffer, whose layout is read from its P-field.
+    fn decode(&self, buf: &[u8]) -> Result<Epoch, Errors> {
+        let (time_code, len) = self.with_pfield(buf)?;
+        time_code.decode_tfield(&buf[len..])
+    }
+}
+
+/// Returns the slice of the provided length at the start of the buffer.
+fn field(buf: &[u8], len: usize) -> Result<&[u8], Errors> {
+    buf.get(..len)
+        .ok_or(Errors::ParseError(ParsingErrors::BufferTooSmall))
+}
+
+/// Returns the mutable slice of the provided length at the start of the buffer.
+fn field_mut(buf: &mut [u8], len: usize) -> Result<&mut [u8], Errors> {
+    buf.get_mut(..len)
+        .ok_or(Errors::ParseError(ParsingErrors::BufferTooSmall))
+}
+
+/// Returns the level 1 or level 2 (agency-defined) epoch from the time code identification of a P-field.
+fn pfield_epoch(level2: bool, agency_epoch: Option<Epoch>) -> Result<Option<Epoch>, Errors> {
+    if level2 {
+        // The agency epoch is not part of the P-field.
+        agency_epoch
+            .map(Some)
+            .ok_or(Errors::ParseError(ParsingErrors::InvalidPField))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Writes the value as a big endian unsigned integer filling the buffer.
+fn write_uint(value: u128, buf: &mut [u8]) {
+    for (i, octet) in buf.iter_mut().rev().enumerate() {
+        *octet = (value >> (8 * i)) as u8;
+    }
+}
+
+/// Reads the big endian unsigned integer filling the buffer.
+fn read_uint(buf: &[u8]) -> u128 {
+    buf.iter()
+        .fold(0, |value, &octet| (value << 8) | u128::from(octet))
+}
+
+/// Writes the value as binary coded decimal filling the buffer, two digits per octet.
+fn write_bcd(mut value: u64, buf: &mut [u8]) {
+    for octet in buf.iter_mut().rev() {
+        *octet = (((value / 10 % 10) << 4) | (value % 10)) as u8;
+        value /= 100;
+    }
+}
+
+/// Reads the binary coded decimal filling the buffer, two digits per octet.
+fn read_bcd(buf: &[u8]) -> Result<u64, Errors> {
+    buf.iter().try_fold(0, |value, &octet| {
+        let (tens, units) = (octet >> 4, octet & 0x0F);
+        if tens > 9 || units > 9 {
+            Err(Errors::ParseError(ParsingErrors::ValueError))
+        } else {
+            Ok(value * 100 + u64::from(tens) * 10 + u64::from(units))
+        }
+    })
+}
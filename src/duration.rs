@@ -19,7 +19,7 @@ use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::fmt;
 use core::hash::{Hash, Hasher};
-use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Rem, RemAssign, Sub, SubAssign};
 
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
@@ -160,6 +160,66 @@ impl Duration {
         }
     }
 
+    /// Converts the total nanoseconds as i128 into this Duration, or returns an overflow error if they are beyond the bounds of Duration, instead of saturating.
+    pub fn try_from_total_nanoseconds(nanos: i128) -> Result<Self, Errors> {
+        if nanos < Self::MIN.total_nanoseconds() || nanos > Self::MAX.total_nanoseconds() {
+            Err(Errors::Overflow)
+        } else {
+            Ok(Self::from_total_nanoseconds(nanos))
+        }
+    }
+
+    /// Normalizes the provided centuries and nanoseconds into a Duration, and returns whether that overflowed, in which case the Duration is saturated.
+    fn overflowing_from_parts(centuries: i32, nanoseconds: u64) -> (Self, bool) {
+        let centuries = centuries + (nanoseconds / NANOSECONDS_PER_CENTURY) as i32;
+        let nanoseconds = nanoseconds % NANOSECONDS_PER_CENTURY;
+        if centuries > i16::MAX.into() {
+            // The MAX is exactly one century past the largest century.
+            (
+                Self::MAX,
+                centuries != i32::from(i16::MAX) + 1 || nanoseconds != 0,
+            )
+        } else if centuries < i16::MIN.into() {
+            (Self::MIN, true)
+        } else {
+            (
+                Self {
+                    centuries: centuries as i16,
+                    nanoseconds,
+                },
+                false,
+            )
+        }
+    }
+
+    /// Returns the sum of both durations and whether that overflowed, in which case the sum is saturated.
+    fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        // Both nanoseconds are at most one century, so their sum fits in a u64.
+        Self::overflowing_from_parts(
+            i32::from(self.centuries) + i32::from(rhs.centuries),
+            self.nanoseconds + rhs.nanoseconds,
+        )
+    }
+
+    /// Returns the difference of both durations and whether that overflowed, in which case the difference is saturated.
+    fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        // Adds the opposite of rhs, whose nanoseconds are the complement of its nanoseconds in the century before its centuries.
+        Self::overflowing_from_parts(
+            i32::from(self.centuries) - i32::from(rhs.centuries) - 1,
+            self.nanoseconds + (NANOSECONDS_PER_CENTURY - rhs.nanoseconds),
+        )
+    }
+
+    /// Wraps the total nanoseconds around the bounds of Duration, like the centuries would wrap around the bounds of an i16.
+    fn from_total_nanoseconds_wrapping(nanos: i128) -> Self {
+        let (min, max) = (Self::MIN.total_nanoseconds(), Self::MAX.total_nanoseconds());
+        if nanos >= min && nanos <= max {
+            Self::from_total_nanoseconds(nanos)
+        } else {
+            Self::from_total_nanoseconds((nanos - min).rem_euclid(max - min) + min)
+        }
+    }
+
     #[must_use]
     /// Create a new duration from the truncated nanoseconds (+/- 2927.1 years of duration)
     pub fn from_truncated_nanoseconds(nanos: i64) -> Self {
@@ -328,16 +388,9 @@ impl Duration {
     /// Returns the total nanoseconds in a signed 128 bit integer
     #[must_use]
     pub fn total_nanoseconds(&self) -> i128 {
-        if self.centuries == -1 {
-            -i128::from(NANOSECONDS_PER_CENTURY - self.nanoseconds)
-        } else if self.centuries >= 0 {
-            i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
-                + i128::from(self.nanoseconds)
-        } else {
-            // Centuries negative by a decent amount
-            i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
-                - i128::from(self.nanoseconds)
-        }
+        // The nanoseconds are always into the current century, including for negative centuries.
+        i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
+            + i128::from(self.nanoseconds)
     }
 
     /// Returns the truncated nanoseconds in a signed 64 bit integer, if the duration fits.
@@ -423,6 +476,131 @@ impl Duration {
         self.centuries.signum() as i8
     }
 
+    /// Returns the sum of both durations, or an overflow error if it is beyond the bounds of Duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Errors, Unit};
+    ///
+    /// assert_eq!((Unit::Day * 1).checked_add(Unit::Hour * 1), Ok(Unit::Hour * 25));
+    /// assert_eq!(Duration::MAX.checked_add(Unit::Nanosecond * 1), Err(Errors::Overflow));
+    /// ```
+    pub fn checked_add(&self, rhs: Self) -> Result<Self, Errors> {
+        match self.overflowing_add(&rhs) {
+            (sum, false) => Ok(sum),
+            (_, true) => Err(Errors::Overflow),
+        }
+    }
+
+    /// Returns the difference of both durations, or an overflow error if it is beyond the bounds of Duration.
+    pub fn checked_sub(&self, rhs: Self) -> Result<Self, Errors> {
+        match self.overflowing_sub(&rhs) {
+            (difference, false) => Ok(difference),
+            (_, true) => Err(Errors::Overflow),
+        }
+    }
+
+    /// Returns this duration multiplied by the provided integer, or an overflow error if it is beyond the bounds of Duration.
+    pub fn checked_mul(&self, q: i64) -> Result<Self, Errors> {
+        match self.total_nanoseconds().checked_mul(q.into()) {
+            Some(nanos) => Self::try_from_total_nanoseconds(nanos),
+            None => Err(Errors::Overflow),
+        }
+    }
+
+    /// Returns this duration divided by the provided integer and truncated to the nanosecond, or an overflow error if the divisor is zero.
+    pub fn checked_div(&self, q: i64) -> Result<Self, Errors> {
+        match self.total_nanoseconds().checked_div(q.into()) {
+            Some(nanos) => Self::try_from_total_nanoseconds(nanos),
+            None => Err(Errors::Overflow),
+        }
+    }
+
+    /// Returns the sum of both durations, saturating at the bounds of Duration. This is the same as the `+` operator.
+    pub fn saturating_add(&self, rhs: Self) -> Self {
+        self.overflowing_add(&rhs).0
+    }
+
+    /// Returns the difference of both durations, saturating at the bounds of Duration. This is the same as the `-` operator.
+    pub fn saturating_sub(&self, rhs: Self) -> Self {
+        self.overflowing_sub(&rhs).0
+    }
+
+    /// Returns this duration multiplied by the provided integer, saturating at the bounds of Duration. This is the same as the `*` operator.
+    pub fn saturating_mul(&self, q: i64) -> Self {
+        Self::from_total_nanoseconds(self.total_nanoseconds().saturating_mul(q.into()))
+    }
+
+    /// Returns this duration divided by the provided integer and truncated to the nanosecond.
+    /// Dividing by zero saturates to the bound of Duration with the sign of this duration, or returns zero if this duration is zero.
+    pub fn saturating_div(&self, q: i64) -> Self {
+        match self.total_nanoseconds().checked_div(q.into()) {
+            Some(nanos) => Self::from_total_nanoseconds(nanos),
+            None => match self.total_nanoseconds().signum() {
+                1 => Self::MAX,
+                -1 => Self::MIN,
+                _ => Self::ZERO,
+            },
+        }
+    }
+
+    /// Returns the sum of both durations, wrapping around the bounds of Duration, e.g. one nanosecond past the MAX is one nanosecond past the MIN.
+    pub fn wrapping_add(&self, rhs: Self) -> Self {
+        Self::from_total_nanoseconds_wrapping(self.total_nanoseconds() + rhs.total_nanoseconds())
+    }
+
+    /// Returns the difference of both durations, wrapping around the bounds of Duration.
+    pub fn wrapping_sub(&self, rhs: Self) -> Self {
+        Self::from_total_nanoseconds_wrapping(self.total_nanoseconds() - rhs.total_nanoseconds())
+    }
+
+    /// Returns this duration multiplied by the provided integer, wrapping around the bounds of Duration.
+    pub fn wrapping_mul(&self, q: i64) -> Self {
+        let span = Self::MAX.total_nanoseconds() - Self::MIN.total_nanoseconds();
+        // Multiply modulo the span of Duration in two halves so that the intermediate products fit in an i128.
+        let nanos = self.total_nanoseconds().rem_euclid(span);
+        let (q_hi, q_lo) = (i128::from(q >> 32), i128::from(q & 0xFFFF_FFFF));
+        let hi = ((nanos * q_hi).rem_euclid(span) << 32).rem_euclid(span);
+        Self::from_total_nanoseconds_wrapping(hi + nanos * q_lo)
+    }
+
+    /// Returns the number of whole `rhs` durations in this duration, rounded such that the remainder is positive, like `i128::div_euclid`.
+    /// This is computed exactly on the nanoseconds, e.g. to count the number of frames in a pass.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// let frame = 10.milliseconds();
+    /// assert_eq!(1.minutes().div_euclid(frame), 6_000);
+    /// assert_eq!((1.seconds() + 5.milliseconds()).div_euclid(frame), 100);
+    /// assert_eq!((-5).milliseconds().div_euclid(frame), -1);
+    /// ```
+    pub fn div_euclid(&self, rhs: Self) -> i128 {
+        self.total_nanoseconds().div_euclid(rhs.total_nanoseconds())
+    }
+
+    /// Returns the positive remainder of the division of this duration by `rhs`, like `i128::rem_euclid`.
+    /// This is computed exactly on the nanoseconds, e.g. to find the phase of an epoch within a frame.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// let frame = 10.milliseconds();
+    /// assert_eq!((1.seconds() + 5.milliseconds()).rem_euclid(frame), 5.milliseconds());
+    /// assert_eq!((-5).milliseconds().rem_euclid(frame), 5.milliseconds());
+    /// ```
+    pub fn rem_euclid(&self, rhs: Self) -> Self {
+        Self::from_total_nanoseconds(self.total_nanoseconds().rem_euclid(rhs.total_nanoseconds()))
+    }
+
     /// Decomposes a Duration in its sign, days, hours, minutes, seconds, ms, us, ns
     #[must_use]
     pub fn decompose(&self) -> (i8, u64, u64, u64, u64, u64, u64, u64) {
@@ -839,10 +1017,7 @@ impl Duration {
 impl Mul<i64> for Duration {
     type Output = Duration;
     fn mul(self, q: i64) -> Self::Output {
-        Duration::from_total_nanoseconds(
-            self.total_nanoseconds()
-                .saturating_mul((q * Unit::Nanosecond).total_nanoseconds()),
-        )
+        self.saturating_mul(q)
     }
 }
 
@@ -1003,56 +1178,7 @@ impl Add for Duration {
     /// + `Duration { centuries: 0, nanoseconds: 1 }` is a positive duration of zero centuries and one nanosecond.
     /// + `Duration { centuries: -1, nanoseconds: 1 }` is a negative duration representing "one century before zero minus one nanosecond"
     fn add(self, rhs: Self) -> Duration {
-        // Check that the addition fits in an i16
-        let mut me = self;
-        match me.centuries.checked_add(rhs.centuries) {
-            None => {
-                // Overflowed, so we've hit the bound.
-                if me.centuries < 0 {
-                    // We've hit the negative bound, so return MIN.
-                    return Self::MIN;
-                } else {
-                    // We've hit the positive bound, so return MAX.
-                    return Self::MAX;
-                }
-            }
-            Some(centuries) => {
-                me.centuries = centuries;
-            }
-        }
-
-        if me.centuries == Self::MIN.centuries && self.nanoseconds < Self::MIN.nanoseconds {
-            // Then we do the operation backward
-            match me
-                .nanoseconds
-                .checked_sub(NANOSECONDS_PER_CENTURY - rhs.nanoseconds)
-            {
-                Some(nanos) => me.nanoseconds = nanos,
-                None => {
-                    me.centuries += 1; // Safe because we're at the MIN
-                    me.nanoseconds = rhs.nanoseconds
-                }
-            }
-        } else {
-            match me.nanoseconds.checked_add(rhs.nanoseconds) {
-                Some(nanoseconds) => me.nanoseconds = nanoseconds,
-                None => {
-                    // Rare case where somehow the input data was not normalized. So let's normalize it and call add again.
-                    let mut rhs = rhs;
-                    rhs.normalize();
-
-                    match me.centuries.checked_add(rhs.centuries) {
-                        None => return Self::MAX,
-                        Some(centuries) => me.centuries = centuries,
-                    };
-                    // Now it will fit!
-                    me.nanoseconds += rhs.nanoseconds;
-                }
-            }
-        }
-
-        me.normalize();
-        me
+        self.saturating_add(rhs)
     }
 }
 
@@ -1144,37 +1270,57 @@ impl Sub for Duration {
     /// assert_eq!(Duration::MIN - one_ns, Duration::MIN);
     /// ```
     fn sub(self, rhs: Self) -> Self {
-        let mut me = self;
-        match me.centuries.checked_sub(rhs.centuries) {
-            None => {
-                // Underflowed, so we've hit the min
-                return Self::MIN;
-            }
-            Some(centuries) => {
-                me.centuries = centuries;
-            }
+        self.saturating_sub(rhs)
+    }
+}
+
+impl Div for Duration {
+    type Output = f64;
+
+    /// Returns the ratio of both durations.
+    /// The whole part of the ratio is computed exactly on the nanoseconds, so it is exact as long as it is representable in an f64.
+    /// Dividing by a zero duration returns an infinite or NaN ratio, like f64.
+    ///
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(1.days() / 1.hours(), 24.0);
+    /// assert_eq!(90.minutes() / 1.hours(), 1.5);
+    /// assert_eq!((-1).seconds() / 4.seconds(), -0.25);
+    /// ```
+    fn div(self, rhs: Self) -> f64 {
+        let (lhs, rhs) = (self.total_nanoseconds(), rhs.total_nanoseconds());
+        if rhs == 0 {
+            lhs as f64 / 0.0
+        } else {
+            (lhs / rhs) as f64 + (lhs % rhs) as f64 / rhs as f64
         }
+    }
+}
 
-        match me.nanoseconds.checked_sub(rhs.nanoseconds) {
-            None => {
-                // Decrease the number of centuries, and realign
-                match me.centuries.checked_sub(1) {
-                    Some(centuries) => {
-                        me.centuries = centuries;
-                        me.nanoseconds = me.nanoseconds + NANOSECONDS_PER_CENTURY - rhs.nanoseconds;
-                    }
-                    None => {
-                        // We're at the min number of centuries already, and we have extra nanos, so we're saturated the duration limit
-                        return Self::MIN;
-                    }
-                };
-                // me.nanoseconds = me.nanoseconds + NANOSECONDS_PER_CENTURY - rhs.nanoseconds;
-            }
-            Some(nanos) => me.nanoseconds = nanos,
-        };
+impl Rem for Duration {
+    type Output = Self;
 
-        me.normalize();
-        me
+    /// Returns the remainder of the division of this duration by `rhs`, which has the sign of this duration, like the `%` operator on integers.
+    /// This is computed exactly on the nanoseconds. Use `rem_euclid` for a remainder that is always positive.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is zero.
+    ///
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(100.minutes() % 1.hours(), 40.minutes());
+    /// assert_eq!((-100).minutes() % 1.hours(), (-40).minutes());
+    /// ```
+    fn rem(self, rhs: Self) -> Self {
+        Self::from_total_nanoseconds(self.total_nanoseconds() % rhs.total_nanoseconds())
+    }
+}
+
+impl RemAssign for Duration {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
     }
 }
 
@@ -1565,6 +1711,76 @@ fn formal_duration_truncated_ns_reciprocity() {
     }
 }
 
+#[cfg(kani)]
+#[kani::proof]
+fn formal_duration_checked_add_sub() {
+    let lhs: Duration = kani::any();
+    let rhs: Duration = kani::any();
+    let fits = |nanos: i128| {
+        nanos >= Duration::MIN.total_nanoseconds() && nanos <= Duration::MAX.total_nanoseconds()
+    };
+
+    let sum = lhs.total_nanoseconds() + rhs.total_nanoseconds();
+    match lhs.checked_add(rhs) {
+        Ok(checked) => {
+            // When it does not overflow, the operator and all variants are exact.
+            assert!(fits(sum));
+            assert_eq!(checked.total_nanoseconds(), sum);
+            assert_eq!((lhs + rhs).total_nanoseconds(), sum);
+            assert_eq!(lhs.wrapping_add(rhs).total_nanoseconds(), sum);
+        }
+        Err(e) => {
+            assert_eq!(e, Errors::Overflow);
+            assert!(!fits(sum));
+            let saturated = lhs.saturating_add(rhs);
+            assert!(saturated == Duration::MIN || saturated == Duration::MAX);
+        }
+    }
+
+    let difference = lhs.total_nanoseconds() - rhs.total_nanoseconds();
+    match lhs.checked_sub(rhs) {
+        Ok(checked) => {
+            assert!(fits(difference));
+            assert_eq!(checked.total_nanoseconds(), difference);
+            assert_eq!((lhs - rhs).total_nanoseconds(), difference);
+            assert_eq!(lhs.wrapping_sub(rhs).total_nanoseconds(), difference);
+        }
+        Err(e) => {
+            assert_eq!(e, Errors::Overflow);
+            assert!(!fits(difference));
+            let saturated = lhs.saturating_sub(rhs);
+            assert!(saturated == Duration::MIN || saturated == Duration::MAX);
+        }
+    }
+}
+
+#[cfg(kani)]
+#[kani::proof]
+fn formal_duration_checked_mul_div() {
+    let dur: Duration = kani::any();
+    let q: i64 = kani::any();
+
+    if let Ok(product) = dur.checked_mul(q) {
+        let exact = dur.total_nanoseconds() * i128::from(q);
+        assert_eq!(product.total_nanoseconds(), exact);
+        assert_eq!((dur * q).total_nanoseconds(), exact);
+        assert_eq!(dur.wrapping_mul(q).total_nanoseconds(), exact);
+    }
+
+    match dur.checked_div(q) {
+        Ok(quotient) => {
+            let exact = dur.total_nanoseconds() / i128::from(q);
+            assert_eq!(quotient.total_nanoseconds(), exact);
+            assert_eq!(dur.saturating_div(q).total_nanoseconds(), exact);
+        }
+        Err(e) => {
+            // Only a division by zero may fail, since the MIN divided by minus one is exactly the MAX.
+            assert_eq!(e, Errors::Overflow);
+            assert_eq!(q, 0);
+        }
+    }
+}
+
 #[cfg(kani)]
 mod tests {
     use super::*;
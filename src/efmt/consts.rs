@@ -266,6 +266,74 @@ pub const RFC3339_FLEX: Format = Format {
     num_items: 8,
 };
 
+/// The RFC3339_FLEX format followed by the abbreviation of the time zone, e.g. `2023-11-05T01:30:00-06:00 MDT`.
+pub const RFC3339_ZONED: Format = Format {
+    items: [
+        Some(Item {
+            token: Token::Year,
+            sep_char: Some('-'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Month,
+            sep_char: Some('-'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Day,
+            sep_char: Some('T'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Hour,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Minute,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Second,
+            sep_char: Some('.'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Subsecond,
+            sep_char: None,
+            second_sep_char: None,
+            optional: true,
+        }),
+        Some(Item {
+            token: Token::OffsetHours,
+            sep_char: Some(' '),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::TimeZoneAbbreviation,
+            sep_char: None,
+            second_sep_char: None,
+            optional: false,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    num_items: 9,
+};
+
 pub const ISO8601_DATE: Format = Format {
     items: [
         Some(Item {
@@ -335,6 +403,151 @@ pub const ISO8601_ORDINAL: Format = Format {
     num_items: 2,
 };
 
+/// CCSDS ASCII Time Code A (CCSDS 301.0-B-4), e.g. `2023-11-28T12:34:56.789Z`.
+///
+/// Any number of digits of the fraction of a second is parsed, and the fraction is only printed (to the nanosecond) if it is non-zero.
+/// The `Z` designates UTC, so epochs are formatted in UTC whatever their time scale.
+pub const CCSDS_ASCII_A: Format = Format {
+    items: [
+        Some(Item {
+            token: Token::Year,
+            sep_char: Some('-'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Month,
+            sep_char: Some('-'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Day,
+            sep_char: Some('T'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Hour,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Minute,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Second,
+            sep_char: Some('.'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Subsecond,
+            sep_char: Some('Z'),
+            second_sep_char: None,
+            optional: true,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    num_items: 7,
+};
+
+/// CCSDS ASCII Time Code B (CCSDS 301.0-B-4) with the ordinal day of the year from `001`, e.g. `2023-332T12:34:56.789Z`.
+///
+/// Any number of digits of the fraction of a second is parsed, and the fraction is only printed (to the nanosecond) if it is non-zero.
+/// The `Z` designates UTC, so epochs are formatted in UTC whatever their time scale.
+pub const CCSDS_ASCII_B: Format = Format {
+    items: [
+        Some(Item {
+            token: Token::Year,
+            sep_char: Some('-'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::OrdinalDay,
+            sep_char: Some('T'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Hour,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Minute,
+            sep_char: Some(':'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Second,
+            sep_char: Some('.'),
+            second_sep_char: None,
+            optional: false,
+        }),
+        Some(Item {
+            token: Token::Subsecond,
+            sep_char: Some('Z'),
+            second_sep_char: None,
+            optional: true,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    num_items: 6,
+};
+
+/// TAI64N label in external form, e.g. `@4000000037c219bf2ef02e94`. TAI64 and TAI64NA labels are also parsed.
+pub const TAI64N: Format = Format {
+    items: [
+        Some(Item {
+            token: Token::Tai64n,
+            sep_char: None,
+            second_sep_char: None,
+            optional: false,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    num_items: 1,
+};
+
 pub const RFC2822: Format = Format {
     items: [
         Some(Item {
@@ -9,8 +9,11 @@
  */
 
 use super::formatter::Item;
+use crate::epoch::{civil_from_days, days_from_civil};
 use crate::{parser::Token, ParsingErrors};
-use crate::{Epoch, Errors, MonthName, TimeScale, Unit, Weekday};
+use crate::{Duration, Epoch, Errors, MonthName, TimeScale, Unit, Weekday};
+#[cfg(feature = "std")]
+use crate::{LocalTimePolicy, TimeZone};
 use core::fmt;
 use core::str::FromStr;
 
@@ -46,11 +49,12 @@ const MAX_TOKENS: usize = 16;
 /// | `%M` | Minute number, zero-padded to 2 digits | `39` for the 39th minutes of the hour | N/A |
 /// | `%S` | Seconds, zero-padded to 2 digits | `27` for the 27th second of the minute | N/A |
 /// | `%f` | Sub-seconds, zero-padded to 9 digits | `000000007` for the 7th nanosecond past the second | (2) |
-/// | `%w` | Weekday in decimal form with C89 standard | `01` for Dynamical barycentric time | (3) |
+/// | `%w` | Weekday in decimal form with C89 standard, where Sunday is zero | `2` for Tuesday | (4) |
 /// | `%z` | Offset timezone if the formatter is provided with an epoch. | `+15:00` For GMT +15 hours and zero minutes | N/A |
 ///
 /// * (1): Hifitime supports years from -34668 to 34668. If your epoch is larger than +/- 9999 years, the formatting of the years _will_ show all five digits of the year.
-/// * (2): Hifitime supports exactly nanosecond precision, and this is not lost when formatting.
+/// * (2): Hifitime supports exactly nanosecond precision, and this is not lost when formatting. When parsing, any number of digits is accepted, and digits beyond the nanoseconds are truncated.
+/// * (4): When parsing, `7` is also accepted for Sunday. Any parsed weekday (`%A`, `%a` or `%w`) must match the date, or a `WeekdayMismatch` error is returned.
 ///
 /// ## Hifitime specific tokens
 ///
@@ -59,8 +63,15 @@ const MAX_TOKENS: usize = 16;
 /// | :-- | :-- | :-- | :-- |
 /// | `%T` | Time scale used to represent this date | `TDB` for Dynamical barycentric time | (3) |
 /// | `%J` | Full day of year as a double | `59.62325231481524` for 29 February 2000 14:57:29 UTC | N/A |
+/// | `%Z` | Abbreviation of the time zone | `MST` for Mountain Standard Time | (5) |
+/// | `%K` | Name of the time zone in the IANA time zone database | `America/Denver` | (5) |
+/// | `%o` | Ordinal day of the year from `001`, zero-padded to 3 digits | `060` for 29 February 2000 | N/A |
+/// | `%@` | TAI64N label in external form, i.e. `@` followed by the hexadecimal label | `@4000000037c219bf2ef02e94` | (6) |
 ///
 /// * (3): Hifitime supports many time scales and these should not be lost when formatting. **This is a novelty compared to other time management libraries** as most do not have any concept of time scales.
+/// * (5): The time zone is only known when formatting a `LocalEpoch` (cf. `Epoch::to_local`), otherwise `UTC` or the offset of the formatter is printed.
+///   When parsing, use `Format::parse_in` to resolve the time zone, and `Format::parse` only accepts UTC.
+/// * (6): The label is always in TAI. When parsing, TAI64 and TAI64NA labels are also accepted, and the label sets the whole epoch.
 ///
 ///
 /// # Example
@@ -105,6 +116,13 @@ const MAX_TOKENS: usize = 16;
 ///
 /// let fmt = Formatter::new(bday, consts::RFC2822);
 /// assert_eq!(format!("{fmt}"), format!("Tue, 29 Feb 2000 14:57:29"));
+///
+/// // Month and weekday names are also parsed, in short or long form and in any of lower, title or upper case.
+/// let fmt = Format::from_str("%d-%b-%Y %H:%M:%S").unwrap();
+/// assert_eq!(
+///     fmt.parse("29-FEB-2000 14:57:29").unwrap(),
+///     Epoch::from_gregorian_utc_hms(2000, 2, 29, 14, 57, 29)
+/// );
 /// ```
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Format {
@@ -113,6 +131,18 @@ pub struct Format {
 }
 
 impl Format {
+    /// Whether this format ends with the `Z` designating UTC, e.g. the CCSDS ASCII time codes.
+    pub(crate) fn designates_utc(&self) -> bool {
+        self.num_items > 0
+            && self.items[self.num_items - 1].is_some_and(|item| {
+                item.sep_char_is('Z')
+                    && !matches!(
+                        item.token,
+                        Token::TimeZoneAbbreviation | Token::TimeZoneName
+                    )
+            })
+    }
+
     pub(crate) fn need_gregorian(&self) -> bool {
         for item in self.items.iter().take(self.num_items) {
             match item.as_ref().unwrap().token {
@@ -126,13 +156,17 @@ impl Format {
                 | Token::Second
                 | Token::Subsecond
                 | Token::OffsetHours
-                | Token::OffsetMinutes => return true,
+                | Token::OffsetMinutes
+                | Token::OrdinalDay => return true,
                 Token::Timescale
                 | Token::DayOfYearInteger
                 | Token::DayOfYear
                 | Token::Weekday
                 | Token::WeekdayShort
-                | Token::WeekdayDecimal => {
+                | Token::WeekdayDecimal
+                | Token::TimeZoneAbbreviation
+                | Token::TimeZoneName
+                | Token::Tai64n => {
                     // These tokens don't need the gregorian, but other tokens in the list of tokens might.
                     // Hence, we don't return anything here and continue the loop.
                 }
@@ -141,7 +175,59 @@ impl Format {
         false
     }
 
+    /// Parses the provided string into an Epoch. Any time zone abbreviation (`%Z`) or name (`%K`) must be UTC: use `parse_in` for local times.
     pub fn parse(&self, s_in: &str) -> Result<Epoch, Errors> {
+        let (epoch, zone) = self.parse_with_zone(s_in)?;
+
+        // Without a time zone, we only know UTC.
+        for name in [zone.abbreviation, zone.name].into_iter().flatten() {
+            if !is_utc(name) {
+                return Err(Errors::ParseError(ParsingErrors::UnknownTimeZone));
+            }
+        }
+
+        Ok(epoch)
+    }
+
+    /// Parses the provided string of a local time in the provided time zone into an Epoch.
+    ///
+    /// The time zone abbreviation (`%Z`) resolves the local time if it is provided, e.g. `01:30 MDT` or `01:30 MST` when the clocks are set back.
+    /// Otherwise, the policy resolves local times which are ambiguous or skipped because of a change of offset of the time zone.
+    /// If the string includes an offset (`%z`), that offset is used.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::UnknownTimeZone` if the time zone name (`%K`) is not that of the time zone, or if the abbreviation is not used around that time;
+    /// + `ParsingErrors::AmbiguousLocalTime` or `ParsingErrors::NonexistentLocalTime` if the policy rejects the local time.
+    #[cfg(feature = "std")]
+    pub fn parse_in(
+        &self,
+        s_in: &str,
+        time_zone: &TimeZone,
+        policy: LocalTimePolicy,
+    ) -> Result<Epoch, Errors> {
+        let (wall_clock, zone) = self.parse_with_zone(s_in)?;
+
+        if let Some(name) = zone.name {
+            if name != time_zone.name() {
+                return Err(Errors::ParseError(ParsingErrors::UnknownTimeZone));
+            }
+        }
+
+        if zone.has_offset {
+            // The offset was already applied.
+            Ok(wall_clock)
+        } else if let Some(abbreviation) = zone.abbreviation {
+            if is_utc(abbreviation) {
+                Ok(wall_clock)
+            } else {
+                time_zone.resolve_abbreviated(wall_clock, abbreviation)
+            }
+        } else {
+            time_zone.resolve(wall_clock, policy)
+        }
+    }
+
+    fn parse_with_zone<'a>(&self, s_in: &'a str) -> Result<(Epoch, ParsedZone<'a>), Errors> {
         // All of the integers in a date: year, month, day, hour, minute, second, subsecond, offset hours, offset minutes
         let mut decomposed = [0_i32; MAX_TOKENS];
         // The parsed time scale, defaults to UTC
@@ -149,7 +235,10 @@ impl Format {
         // The offset sign, defaults to positive.
         let mut offset_sign = 1;
         let mut day_of_year: Option<f64> = None;
+        let mut ordinal_day: Option<i32> = None;
+        let mut tai64_label: Option<Epoch> = None;
         let mut weekday: Option<Weekday> = None;
+        let mut zone = ParsedZone::default();
 
         // Previous index of interest in the string
         let mut prev_idx = 0;
@@ -167,12 +256,12 @@ impl Format {
             // 2. Or we've hit a non-numeric char and the token is fully numeric
             // 3. Or, token is not numeric (e.g. month name) and the current char is the separator
             // 4. And, if the length of the current substring is longer than 1 and the char is not the optional separator of the previous token.
-            if idx == s.len() - 1
-                || ((cur_token.is_numeric() && !char.is_numeric())
-                    || (!cur_token.is_numeric() && (cur_item.sep_char_is(char))))
-            {
+            let token_ended = (cur_token.is_numeric() && !char.is_numeric())
+                || (!cur_token.is_numeric() && (cur_item.sep_char_is(char)));
+            if idx == s.len() - 1 || token_ended {
                 // If we've found the second separator of the previous token, let's simply increment the start index of the next substring.
                 if idx == prev_idx
+                    && token_ended
                     && (prev_item.second_sep_char.is_none() || prev_item.second_sep_char_is(char))
                 {
                     prev_idx += 1;
@@ -180,21 +269,27 @@ impl Format {
                 }
 
                 if cur_token == Token::Timescale {
-                    // Then we match the timescale directly.
-                    if idx != s.len() - 1 {
-                        // We have some remaining characters, so let's parse those in the only formats we know.
-                        ts = TimeScale::from_str(s[idx..].trim())?;
+                    // Then we match the timescale directly from the remaining characters.
+                    let ts_str = s[prev_idx..].trim();
+                    if !ts_str.is_empty() && ts_str != "Z" {
+                        ts = TimeScale::from_str(ts_str)?;
                     }
                     break;
-                } else if char == 'Z' {
-                    // This is a single character to represent UTC
-                    // UTC is the default time scale, so we don't need to do anything.
+                }
+                // A single character to represent UTC, which is the default time scale: parse the pending token, if any, and stop.
+                let utc_designator = char == 'Z'
+                    && !matches!(cur_token, Token::TimeZoneAbbreviation | Token::TimeZoneName);
+                if utc_designator && idx <= prev_idx {
                     break;
                 }
+                // Whether this is the last token to parse
+                let mut last_token = utc_designator;
                 prev_item = cur_item;
                 prev_token = cur_token;
 
-                let end_idx = if idx != s.len() - 1 || !char.is_numeric() {
+                let end_idx = if utc_designator {
+                    idx
+                } else if token_ended {
                     // Only advance the token if we aren't at the end of the string
                     if cur_item.sep_char_is_not(char)
                         && (cur_item.second_sep_char.is_none()
@@ -208,16 +303,27 @@ impl Format {
                     }
 
                     // Advance the token, unless we're at the end of the tokens.
-                    if cur_item_idx == self.num_items {
-                        break;
-                    }
-                    cur_item_idx += 1;
-                    match self.items[cur_item_idx] {
-                        Some(item) => {
+                    match self.items.get(cur_item_idx + 1).copied().flatten() {
+                        Some(item) if cur_item_idx + 1 < self.num_items => {
+                            cur_item_idx += 1;
                             cur_item = item;
                             cur_token = cur_item.token;
                         }
-                        None => break,
+                        // The separator of the last token ends it, e.g. a `)`.
+                        _ if cur_item.sep_char_is(char) || cur_item.second_sep_char_is(char) => {
+                            last_token = true
+                        }
+                        // The time zone offset ends the string, e.g. `+00:00` or ` UTC`.
+                        _ if cur_token == Token::OffsetHours => break,
+                        // Any other character after the last token is unexpected, e.g. the time zone of a mail header,
+                        // which would otherwise drop that token.
+                        _ => {
+                            return Err(Errors::ParseError(ParsingErrors::UnexpectedCharacter {
+                                found: char,
+                                option1: cur_item.sep_char,
+                                option2: cur_item.second_sep_char,
+                            }))
+                        }
                     }
 
                     idx
@@ -243,42 +349,53 @@ impl Format {
                         }
                     }
                     Token::WeekdayDecimal => {
-                        todo!()
+                        // Set the weekday from its C89 number, where Sunday is zero.
+                        match lexical_core::parse(sub_str.as_bytes()) {
+                            Ok(val) => {
+                                prev_token.value_ok(val)?;
+                                weekday = Some(Weekday::from_c89_weekday(val as u8));
+                            }
+                            Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+                        }
                     }
                     Token::MonthName | Token::MonthNameShort => {
                         match MonthName::from_str(sub_str) {
                             Ok(month) => {
                                 decomposed[1] = ((month as u8) + 1) as i32;
                             }
+                            Err(err) => return Err(Errors::ParseError(err)),
+                        }
+                    }
+                    Token::Subsecond => {
+                        // Only the nanoseconds are kept from any number of digits.
+                        let digits = &sub_str[..sub_str.len().min(9)];
+                        if !sub_str.bytes().all(|byte| byte.is_ascii_digit()) {
+                            return Err(Errors::ParseError(ParsingErrors::ValueError));
+                        }
+                        match lexical_core::parse::<i32>(digits.as_bytes()) {
+                            Ok(val) => {
+                                decomposed[6] = val * 10_i32.pow((9 - digits.len()) as u32);
+                            }
                             Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
                         }
                     }
+                    Token::TimeZoneAbbreviation => zone.abbreviation = Some(sub_str.trim()),
+                    Token::TimeZoneName => zone.name = Some(sub_str.trim()),
+                    Token::Tai64n => tai64_label = Some(Epoch::from_tai64_str(sub_str)?),
                     _ => {
                         match lexical_core::parse(sub_str.as_bytes()) {
                             Ok(val) => {
                                 // Check that this valid is OK for the token we're reading it as.
                                 prev_token.value_ok(val)?;
+                                if prev_token == Token::OffsetHours {
+                                    zone.has_offset = true;
+                                }
                                 match prev_token.gregorian_position() {
-                                    Some(pos) => {
-                                        // If these are the subseconds, we must convert them to nanoseconds
-                                        if prev_token == Token::Subsecond {
-                                            if end_idx - prev_idx != 9 {
-                                                decomposed[pos] = val
-                                                    * 10_i32.pow((9 - (end_idx - prev_idx)) as u32);
-                                            } else {
-                                                decomposed[pos] = val;
-                                            }
-                                        } else {
-                                            decomposed[pos] = val
-                                        }
-                                    }
+                                    Some(pos) => decomposed[pos] = val,
                                     None => match prev_token {
                                         Token::DayOfYearInteger => day_of_year = Some(val as f64),
-                                        Token::Weekday => todo!(),
-                                        Token::WeekdayShort => todo!(),
-                                        Token::WeekdayDecimal => todo!(),
-                                        Token::MonthName => todo!(),
-                                        Token::MonthNameShort => todo!(),
+                                        Token::OrdinalDay => ordinal_day = Some(val),
+                                        // Named tokens and the decimal weekday are parsed above.
                                         _ => unreachable!(),
                                     },
                                 }
@@ -290,6 +407,10 @@ impl Format {
                     }
                 }
 
+                if last_token {
+                    break;
+                }
+
                 prev_idx = idx + 1;
                 // If we are about to parse an hours offset, we need to set the sign now.
                 if cur_token == Token::OffsetHours {
@@ -308,6 +429,23 @@ impl Format {
             i64::from(decomposed[7]) * Unit::Hour + i64::from(decomposed[8]) * Unit::Minute
         };
 
+        if let Some(epoch) = tai64_label {
+            // The label is the epoch itself.
+            return Ok((epoch, zone));
+        }
+
+        if let Some(ordinal_day) = ordinal_day {
+            // The ordinal day sets the month and day of the month, so that leap seconds are kept.
+            let year = decomposed[0];
+            let start_of_year = days_from_civil(year, 1, 1);
+            if ordinal_day > (days_from_civil(year + 1, 1, 1) - start_of_year) as i32 {
+                return Err(Errors::ParseError(ParsingErrors::ValueError));
+            }
+            let (_, month, day) = civil_from_days(start_of_year + i64::from(ordinal_day) - 1);
+            decomposed[1] = i32::from(month);
+            decomposed[2] = i32::from(day);
+        }
+
         let epoch = match day_of_year {
             Some(days) => {
                 // Parse the elapsed time in the given day
@@ -339,10 +477,28 @@ impl Format {
             }
         }
 
-        Ok(epoch + tz)
+        if tz == Duration::ZERO {
+            // Adding a zero offset in UTC would lose a leap second.
+            Ok((epoch, zone))
+        } else {
+            Ok((epoch + tz, zone))
+        }
     }
 }
 
+/// Returns whether the provided time zone abbreviation or name is UTC.
+fn is_utc(name: &str) -> bool {
+    matches!(name, "UTC" | "GMT" | "UT" | "Z" | "Etc/UTC" | "Etc/GMT")
+}
+
+/// The time zone information found when parsing a string.
+#[derive(Default)]
+struct ParsedZone<'a> {
+    abbreviation: Option<&'a str>,
+    name: Option<&'a str>,
+    has_offset: bool,
+}
+
 impl fmt::Debug for Format {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "EpochFormat:`")?;
@@ -491,6 +647,22 @@ impl FromStr for Format {
                         ));
                         me.num_items += 1;
                     }
+                    'o' => {
+                        me.items[me.num_items] = Some(Item::new(
+                            Token::OrdinalDay,
+                            token.chars().nth(1),
+                            token.chars().nth(2),
+                        ));
+                        me.num_items += 1;
+                    }
+                    '@' => {
+                        me.items[me.num_items] = Some(Item::new(
+                            Token::Tai64n,
+                            token.chars().nth(1),
+                            token.chars().nth(2),
+                        ));
+                        me.num_items += 1;
+                    }
                     'z' => {
                         me.items[me.num_items] = Some(Item::new(
                             Token::OffsetHours,
@@ -499,6 +671,22 @@ impl FromStr for Format {
                         ));
                         me.num_items += 1;
                     }
+                    'Z' => {
+                        me.items[me.num_items] = Some(Item::new(
+                            Token::TimeZoneAbbreviation,
+                            token.chars().nth(1),
+                            token.chars().nth(2),
+                        ));
+                        me.num_items += 1;
+                    }
+                    'K' => {
+                        me.items[me.num_items] = Some(Item::new(
+                            Token::TimeZoneName,
+                            token.chars().nth(1),
+                            token.chars().nth(2),
+                        ));
+                        me.num_items += 1;
+                    }
                     _ => return Err(ParsingErrors::UnknownFormattingToken(char)),
                 },
                 None => continue, // We're probably just at the start of the string
@@ -528,6 +716,15 @@ fn epoch_format_from_str() {
 
     let fmt = Format::from_str("%a, %d %b %Y %H:%M:%S").unwrap();
     assert_eq!(fmt, crate::efmt::consts::RFC2822);
+
+    let fmt = Format::from_str("%Y-%m-%dT%H:%M:%S.%f?Z").unwrap();
+    assert_eq!(fmt, crate::efmt::consts::CCSDS_ASCII_A);
+
+    let fmt = Format::from_str("%Y-%oT%H:%M:%S.%f?Z").unwrap();
+    assert_eq!(fmt, crate::efmt::consts::CCSDS_ASCII_B);
+
+    let fmt = Format::from_str("%@").unwrap();
+    assert_eq!(fmt, crate::efmt::consts::TAI64N);
 }
 
 #[cfg(feature = "std")]
@@ -10,6 +10,7 @@
 
 use core::fmt;
 
+use crate::epoch::days_from_civil;
 use crate::{parser::Token, Duration, Epoch, TimeScale};
 
 use super::format::Format;
@@ -84,6 +85,12 @@ pub struct Formatter {
 
 impl Formatter {
     pub fn new(epoch: Epoch, format: Format) -> Self {
+        // The `Z` designator means that the epoch is printed in UTC, whatever its time scale.
+        let epoch = if format.designates_utc() {
+            epoch.in_time_scale(TimeScale::UTC)
+        } else {
+            epoch
+        };
         Self {
             epoch,
             offset: Duration::ZERO,
@@ -108,8 +115,20 @@ impl Formatter {
     }
 }
 
-impl fmt::Display for Formatter {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Formatter {
+    /// Writes this formatter with the abbreviation and name of its time zone, if known.
+    fn write(&self, f: &mut fmt::Formatter, zone: Option<(&str, &str)>) -> fmt::Result {
+        let write_zone = |f: &mut fmt::Formatter, token: Token| -> fmt::Result {
+            match (zone, token) {
+                (Some((abbreviation, _)), Token::TimeZoneAbbreviation) => {
+                    write!(f, "{abbreviation}")
+                }
+                (Some((_, name)), _) => write!(f, "{name}"),
+                (None, _) if self.offset == Duration::ZERO => write!(f, "UTC"),
+                (None, _) => write_offset(f, self.offset),
+            }
+        };
+
         // We make sure to only call this as needed.
         let write_sep = |f: &mut fmt::Formatter, i: usize, format: &Format| -> fmt::Result {
             if i > 0 {
@@ -127,7 +146,7 @@ impl fmt::Display for Formatter {
 
         if self.format.need_gregorian() {
             // This is a specific branch so we don't recompute the gregorian information for each token.
-            let (y, mm, dd, hh, min, s, nanos) = Epoch::compute_gregorian(self.epoch.to_duration());
+            let (y, mm, dd, hh, min, s, nanos) = self.epoch.to_gregorian();
             // And format.
             for (i, maybe_item) in self
                 .format
@@ -171,24 +190,7 @@ impl fmt::Display for Formatter {
                     }
                     Token::OffsetHours => {
                         write_sep(f, i, &self.format)?;
-                        let (sign, days, mut hours, minutes, seconds, _, _, _) =
-                            self.offset.decompose();
-
-                        if days > 0 {
-                            hours += 24 * days;
-                        }
-
-                        write!(
-                            f,
-                            "{}{:02}:{:02}",
-                            if sign >= 0 { '+' } else { '-' },
-                            hours,
-                            minutes
-                        )?;
-
-                        if seconds > 0 {
-                            write!(f, "{:02}", seconds)?;
-                        }
+                        write_offset(f, self.offset)?;
                     }
                     Token::OffsetMinutes => {
                         // To print the offset, someone should use OffsetHours, so return an error here.
@@ -204,6 +206,11 @@ impl fmt::Display for Formatter {
                         write_sep(f, i, &self.format)?;
                         write!(f, "{:03}", self.epoch.day_of_year().floor() as u16)?
                     }
+                    Token::OrdinalDay => {
+                        write_sep(f, i, &self.format)?;
+                        let ordinal_day = days_from_civil(y, mm, dd) - days_from_civil(y, 1, 1) + 1;
+                        write!(f, "{ordinal_day:03}")?
+                    }
                     Token::DayOfYear => {
                         write_sep(f, i, &self.format)?;
                         write!(f, "{}", self.epoch.day_of_year())?
@@ -228,8 +235,18 @@ impl fmt::Display for Formatter {
                         write_sep(f, i, &self.format)?;
                         write!(f, "{:x}", self.epoch.month_name())?
                     }
+                    Token::TimeZoneAbbreviation | Token::TimeZoneName => {
+                        write_sep(f, i, &self.format)?;
+                        write_zone(f, item.token)?;
+                    }
+                    Token::Tai64n => {
+                        write_sep(f, i, &self.format)?;
+                        write_tai64n(f, self.epoch)?;
+                    }
                 };
             }
+            // Print the separators following the last item, e.g. the `Z` designating UTC.
+            write_sep(f, self.format.num_items, &self.format)?;
         } else {
             for (i, maybe_item) in self
                 .format
@@ -242,24 +259,15 @@ impl fmt::Display for Formatter {
                 match item.token {
                     Token::OffsetHours => {
                         write_sep(f, i, &self.format)?;
-                        let (sign, days, mut hours, minutes, seconds, _, _, _) =
-                            self.offset.decompose();
-
-                        if days > 0 {
-                            hours += 24 * days;
-                        }
-
-                        write!(
-                            f,
-                            "{}{:02}:{:02}",
-                            if sign >= 0 { '+' } else { '-' },
-                            hours,
-                            minutes
-                        )?;
-
-                        if seconds > 0 {
-                            write!(f, "{:02}", seconds)?;
-                        }
+                        write_offset(f, self.offset)?;
+                    }
+                    Token::TimeZoneAbbreviation | Token::TimeZoneName => {
+                        write_sep(f, i, &self.format)?;
+                        write_zone(f, item.token)?;
+                    }
+                    Token::Tai64n => {
+                        write_sep(f, i, &self.format)?;
+                        write_tai64n(f, self.epoch)?;
                     }
                     Token::OffsetMinutes => {
                         // To print the offset, someone should use OffsetHours, so return an error here.
@@ -285,14 +293,77 @@ impl fmt::Display for Formatter {
                     }
                     _ => unreachable!(),
                 };
-
-                if let Some(sep) = item.sep_char {
-                    write!(f, "{sep}")?;
-                } else if let Some(sep) = item.second_sep_char {
-                    write!(f, "{sep}")?;
-                }
             }
+            write_sep(f, self.format.num_items, &self.format)?;
         }
         Ok(())
     }
 }
+
+impl fmt::Display for Formatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, None)
+    }
+}
+
+/// Writes the TAI64N label of the epoch in external form, e.g. `@4000000037c219bf2ef02e94`.
+fn write_tai64n(f: &mut fmt::Formatter, epoch: Epoch) -> fmt::Result {
+    let (seconds, nanoseconds) = epoch.tai64_parts();
+    write!(f, "@{seconds:016x}{nanoseconds:08x}")
+}
+
+/// Writes the offset as `+hh:mm`, with the seconds appended if any.
+fn write_offset(f: &mut fmt::Formatter, offset: Duration) -> fmt::Result {
+    let (sign, days, mut hours, minutes, seconds, _, _, _) = offset.decompose();
+
+    if days > 0 {
+        hours += 24 * days;
+    }
+
+    write!(
+        f,
+        "{}{:02}:{:02}",
+        if sign >= 0 { '+' } else { '-' },
+        hours,
+        minutes
+    )?;
+
+    if seconds > 0 {
+        write!(f, "{:02}", seconds)?;
+    }
+    Ok(())
+}
+
+/// A formatter of an epoch in a time zone, which also knows the abbreviation and the name of that time zone.
+///
+/// This is built by `LocalEpoch::format`, and the time zone tokens `%Z` and `%K` print the abbreviation and the name of the time zone.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ZonedFormatter<'a> {
+    formatter: Formatter,
+    abbreviation: &'a str,
+    name: &'a str,
+}
+
+impl<'a> ZonedFormatter<'a> {
+    /// Formats the epoch with the provided offset of its time zone, with the provided abbreviation and name of that time zone.
+    pub fn new(
+        epoch: Epoch,
+        offset: Duration,
+        abbreviation: &'a str,
+        name: &'a str,
+        format: Format,
+    ) -> Self {
+        Self {
+            formatter: Formatter::with_timezone(epoch, offset, format),
+            abbreviation,
+            name,
+        }
+    }
+}
+
+impl<'a> fmt::Display for ZonedFormatter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.formatter
+            .write(f, Some((self.abbreviation, self.name)))
+    }
+}
@@ -13,4 +13,4 @@ pub mod format;
 pub mod formatter;
 
 pub use format::Format;
-pub use formatter::Formatter;
+pub use formatter::{Formatter, ZonedFormatter};
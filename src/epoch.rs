@@ -9,14 +9,19 @@
  */
 
 use crate::duration::{Duration, Unit};
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondProvider};
+use crate::leap_seconds::{
+    registered_leap_seconds, LatestLeapSeconds, LeapSecond, LeapSecondProvider, SmearModel,
+};
 use crate::parser::Token;
 use crate::{
-    Errors, MonthName, TimeScale, BDT_REF_EPOCH, DAYS_PER_YEAR_NLD, ET_EPOCH_S, GPST_REF_EPOCH,
-    GST_REF_EPOCH, J1900_OFFSET, J2000_TO_J1900_DURATION, MJD_OFFSET, NANOSECONDS_PER_DAY,
-    NANOSECONDS_PER_MICROSECOND, NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_SECOND_U32,
-    UNIX_REF_EPOCH,
+    CalendarDuration, Errors, MonthEndPolicy, MonthName, TimeScale, BDT_REF_EPOCH,
+    DAYS_PER_CENTURY_I64, ET_EPOCH_S, GPST_REF_EPOCH, GST_REF_EPOCH, J1900_OFFSET,
+    J2000_TO_J1900_DURATION, MJD_OFFSET, NANOSECONDS_PER_DAY, NANOSECONDS_PER_HOUR,
+    NANOSECONDS_PER_MINUTE, NANOSECONDS_PER_SECOND, NANOSECONDS_PER_SECOND_U32,
+    UNIX_REF_EPOCH_UTC_DURATION,
 };
+#[cfg(feature = "std")]
+use crate::{LocalEpoch, TimeZone};
 
 use crate::efmt::format::Format;
 
@@ -40,6 +45,9 @@ use pyo3::types::PyType;
 #[cfg(feature = "python")]
 use crate::leap_seconds_file::LeapSecondsFile;
 
+#[cfg(feature = "python")]
+use crate::leap_seconds_kernel::LeapSecondsKernel;
+
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
 
@@ -49,11 +57,22 @@ use core::str::FromStr;
 use num_traits::{Euclid, Float};
 
 #[cfg(feature = "ut1")]
+use crate::ut1::Ut1Source;
+
+#[cfg(feature = "python")]
 use crate::ut1::Ut1Provider;
 
 const TT_OFFSET_MS: i64 = 32_184;
 const ET_OFFSET_US: i64 = 32_184_935;
 
+/// TAI64 labels count the TAI seconds since 1970 January 01 at midnight TAI, offset by 2^62.
+const TAI64_OFFSET: u64 = 1 << 62;
+/// 1970 January 01 at midnight TAI, the epoch of the TAI64 label 2^62.
+const TAI64_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 2_208_988_800_000_000_000,
+});
+
 /// NAIF leap second kernel data for M_0 used to calculate the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter.
 pub const NAIF_M0: f64 = 6.239996;
 /// NAIF leap second kernel data for M_1 used to calculate the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter.
@@ -63,6 +82,66 @@ pub const NAIF_EB: f64 = 1.671e-2;
 /// NAIF leap second kernel data used to calculate the difference between ET and TAI.
 pub const NAIF_K: f64 = 1.657e-3;
 
+/// The `DELTET` constants of a NAIF leap second kernel, used to compute the difference between Ephemeris Time (ET) and TAI.
+///
+/// The default values are those of the NAIF leap second kernels distributed since 1997 (e.g. `naif0012.txt`), i.e. [NAIF_K], [NAIF_EB], [NAIF_M0] and [NAIF_M1].
+/// Use the constants of a specific kernel, as read by `LeapSecondsKernel`, with [Epoch::to_et_duration_with] and [Epoch::from_et_duration_with].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeltetConstants {
+    /// `DELTET/DELTA_T_A`: the difference between TT and TAI, in seconds
+    pub delta_t_a: f64,
+    /// `DELTET/K`: the amplitude of the periodic term of the difference between ET and TT, in seconds
+    pub k: f64,
+    /// `DELTET/EB`: the eccentricity of the heliocentric orbit of the Earth-Moon barycenter
+    pub eb: f64,
+    /// First value of `DELTET/M`: the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter at J2000, in radians
+    pub m0: f64,
+    /// Second value of `DELTET/M`: the rate of the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter, in radians per second
+    pub m1: f64,
+}
+
+impl Default for DeltetConstants {
+    fn default() -> Self {
+        Self {
+            delta_t_a: (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
+            k: NAIF_K,
+            eb: NAIF_EB,
+            m0: NAIF_M0,
+            m1: NAIF_M1,
+        }
+    }
+}
+
+/// Rate difference between TCG and TT, as defined by IAU 2000 Resolution B1.9: dTT/dTCG = 1 - L_G.
+pub const IAU_L_G: f64 = 6.969290134e-10;
+/// Rate difference between TCB and TDB, as defined by IAU 2006 Resolution B3: dTDB/dTCB = 1 - L_B.
+pub const IAU_L_B: f64 = 1.550519768e-8;
+/// Offset TDB_0 in seconds between TDB and TCB, as defined by IAU 2006 Resolution B3.
+pub const IAU_TDB0_S: f64 = -6.55e-5;
+/// The 1977 January 01 00:00:32.184 TT instant (i.e. 1977-01-01 midnight TAI) when TCG, TCB and TT all coincide, as a TT duration since J1900.
+const IAU_T0_J1900: Duration = Duration {
+    centuries: 0,
+    nanoseconds: 2_429_913_632_184_000_000,
+};
+
+/// Returns the provided TAI - UT1 offset at this epoch, or TAI - UTC if the UT1 source does not cover this epoch,
+/// since UT1 is always within 0.9 s of UTC.
+fn ut1_offset_or_utc(epoch: &Epoch, offset: Option<Duration>) -> Duration {
+    offset.unwrap_or_else(|| epoch.duration_since_j1900_tai - epoch.to_utc_duration())
+}
+
+/// Returns TAI - UT1 at the provided epoch from the registered UT1 source, if any covers this epoch.
+#[cfg(feature = "ut1")]
+fn registered_ut1_offset(epoch: &Epoch) -> Option<Duration> {
+    crate::ut1::registered_delta_tai_minus_ut1(epoch)
+}
+
+/// Without the `ut1` feature, no UT1 source can be registered.
+#[cfg(not(feature = "ut1"))]
+fn registered_ut1_offset(_epoch: &Epoch) -> Option<Duration> {
+    None
+}
+
 /// Years when January had the leap second
 const fn january_years(year: i32) -> bool {
     matches!(
@@ -107,17 +186,6 @@ const fn usual_days_per_month(month: u8) -> u8 {
     }
 }
 
-/// Calculates the prefix-sum of days counted up to the month start
-const CUMULATIVE_DAYS_FOR_MONTH: [u16; 12] = {
-    let mut days = [0; 12];
-    let mut month = 1;
-    while month < 12 {
-        days[month] = days[month - 1] + usual_days_per_month(month as u8 - 1) as u16;
-        month += 1;
-    }
-    days
-};
-
 /// Defines a nanosecond-precision Epoch.
 ///
 /// Refer to the appropriate functions for initializing this Epoch from different time scales or representations.
@@ -251,14 +319,305 @@ impl Epoch {
         iers_only: bool,
         provider: L,
     ) -> Option<f64> {
-        for leap_second in provider.rev() {
-            if self.duration_since_j1900_tai.to_seconds() >= leap_second.timestamp_tai_s
-                && (!iers_only || leap_second.announced_by_iers)
-            {
-                return Some(leap_second.delta_at);
+        Self::find_leap_second_tai(self.duration_since_j1900_tai, iers_only, &provider)
+            .0
+            .map(|leap_second| leap_second.delta_at_tai(self.duration_since_j1900_tai))
+    }
+
+    /// Returns the latest leap second of the provider which applies at the provided TAI duration since J1900, and, if this duration
+    /// is within an inserted leap second (i.e. 23:59:60 UTC), the UTC midnight at which that leap second ends and the time elapsed since its start.
+    ///
+    /// In TAI, an inserted leap second applies from its start, whereas a removed leap second applies from the following UTC midnight.
+    fn find_leap_second_tai<L: LeapSecondProvider>(
+        tai_duration: Duration,
+        iers_only: bool,
+        provider: &L,
+    ) -> (Option<LeapSecond>, Option<(Duration, Duration)>) {
+        let leap_seconds = provider.leap_seconds();
+        // The leap seconds are labeled with the UTC midnight from which they apply, which is never after they apply in TAI.
+        let count =
+            leap_seconds.partition_point(|leap_second| leap_second.timestamp_tai <= tai_duration);
+        let mut applicable = leap_seconds[..count]
+            .iter()
+            .rev()
+            .filter(|leap_second| !iers_only || leap_second.announced_by_iers);
+        let (latest, previous) = match applicable.next() {
+            Some(latest) => (*latest, applicable.next().copied()),
+            None => return (None, None),
+        };
+
+        let midnight = latest.timestamp_tai;
+        let before = previous.map_or(Duration::ZERO, |previous| {
+            Self::delta_at_duration(previous.delta_at_utc(midnight))
+        });
+        let after = Self::delta_at_duration(latest.delta_at_utc(midnight));
+        if tai_duration < midnight + before.min(after) {
+            (previous, None)
+        } else if tai_duration < midnight + after && previous.is_some() && latest.announced_by_iers
+        {
+            (
+                Some(latest),
+                Some((midnight, tai_duration - midnight - before)),
+            )
+        } else {
+            (Some(latest), None)
+        }
+    }
+
+    /// Returns the UTC midnight at which the inserted leap second (i.e. 23:59:60 UTC) that this epoch is within ends, if any,
+    /// and the time elapsed since the start of that leap second.
+    fn utc_leap_second(&self) -> Option<(Duration, Duration)> {
+        match registered_leap_seconds() {
+            Some(table) => {
+                Self::find_leap_second_tai(self.duration_since_j1900_tai, false, &table).1
+            }
+            None => {
+                Self::find_leap_second_tai(
+                    self.duration_since_j1900_tai,
+                    false,
+                    &LatestLeapSeconds::default(),
+                )
+                .1
             }
         }
-        None
+    }
+
+    /// Returns the Gregorian date and time of this epoch in its own time scale, where an inserted leap second of UTC is second 60.
+    pub(crate) fn to_gregorian(self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        match self.time_scale {
+            TimeScale::UTC => self.to_gregorian_utc(),
+            _ => Self::compute_gregorian(self.to_duration_since_j1900()),
+        }
+    }
+
+    /// Shifts the Gregorian date of this epoch in the provided time scale by the provided months then days, keeping the time of day.
+    fn add_calendar(
+        &self,
+        months: i64,
+        days: i64,
+        policy: MonthEndPolicy,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        let (year, month, day, hour, minute, second, nanos) =
+            self.in_time_scale(time_scale).to_gregorian();
+
+        let months = i64::from(year) * 12 + i64::from(month - 1) + months;
+        let year = i32::try_from(months.div_euclid(12)).map_err(|_| Errors::Overflow)?;
+        let month = months.rem_euclid(12) as u8 + 1;
+
+        let last_day = days_in_month(year, month);
+        let days = days
+            + if day <= last_day {
+                days_from_civil(year, month, day)
+            } else {
+                match policy {
+                    MonthEndPolicy::Clamp => days_from_civil(year, month, last_day),
+                    MonthEndPolicy::Overflow => {
+                        days_from_civil(year, month, last_day) + i64::from(day - last_day)
+                    }
+                    MonthEndPolicy::Strict => return Err(Errors::Carry),
+                }
+            };
+
+        Ok(
+            Self::from_civil_day(days, (hour, minute, second, nanos), policy, time_scale)?
+                .in_time_scale(self.time_scale),
+        )
+    }
+
+    /// Sets the duration in the time scale of this epoch like `set`, or returns an overflow error if this duration
+    /// or its conversion to TAI is at the bounds of Duration, where it may have saturated.
+    fn checked_set(&self, new_duration: Duration) -> Result<Self, Errors> {
+        let is_bound = |duration: Duration| duration == Duration::MIN || duration == Duration::MAX;
+        let duration_in_time_scale = self.to_duration();
+        if is_bound(duration_in_time_scale) || is_bound(new_duration) {
+            return Err(Errors::Overflow);
+        }
+        let epoch = self.set(new_duration);
+        if is_bound(epoch.duration_since_j1900_tai) {
+            Err(Errors::Overflow)
+        } else {
+            Ok(epoch)
+        }
+    }
+
+    /// Builds an Epoch at the provided time of day (hours, minutes, seconds, nanoseconds) of the provided number of days since 1900 January 01
+    /// in the Gregorian calendar of the provided time scale. If that day has no leap second, a time of day in the leap second is resolved with the policy.
+    ///
+    /// Returns an overflow error if this date is beyond the range of Duration, instead of saturating.
+    pub(crate) fn from_civil_day(
+        days: i64,
+        time: (u8, u8, u8, u32),
+        policy: MonthEndPolicy,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        let (hour, minute, second, nanos) = time;
+        Duration::try_from_total_nanoseconds(
+            i128::from(days) * i128::from(NANOSECONDS_PER_DAY)
+                + i128::from(hour) * i128::from(NANOSECONDS_PER_HOUR)
+                + i128::from(minute) * i128::from(NANOSECONDS_PER_MINUTE)
+                + i128::from(second) * i128::from(NANOSECONDS_PER_SECOND)
+                + i128::from(nanos),
+        )?;
+        let (year, month, day) = civil_from_days(days);
+
+        let epoch = match Self::maybe_from_gregorian(
+            year, month, day, hour, minute, second, nanos, time_scale,
+        ) {
+            // The resulting day has no leap second.
+            Err(Errors::Carry) if second == 60 => {
+                let epoch = Self::maybe_from_gregorian(
+                    year, month, day, hour, minute, 59, nanos, time_scale,
+                )?;
+                match policy {
+                    MonthEndPolicy::Clamp => epoch,
+                    MonthEndPolicy::Overflow => epoch + Unit::Second,
+                    MonthEndPolicy::Strict => return Err(Errors::Carry),
+                }
+            }
+            result => result?,
+        };
+
+        // The conversion to TAI may also saturate at the bounds of Duration.
+        if epoch.duration_since_j1900_tai == Duration::MIN
+            || epoch.duration_since_j1900_tai == Duration::MAX
+        {
+            Err(Errors::Overflow)
+        } else {
+            Ok(epoch)
+        }
+    }
+
+    /// Returns the latest leap second of the provider which occurred at or before the provided UTC duration since J1900.
+    fn find_leap_second<L: LeapSecondProvider>(
+        duration: Duration,
+        iers_only: bool,
+        provider: &L,
+    ) -> Option<LeapSecond> {
+        let leap_seconds = provider.leap_seconds();
+        // Number of leap seconds which occurred at or before this duration.
+        let count =
+            leap_seconds.partition_point(|leap_second| leap_second.timestamp_tai <= duration);
+        leap_seconds[..count]
+            .iter()
+            .rev()
+            .find(|leap_second| !iers_only || leap_second.announced_by_iers)
+            .copied()
+    }
+
+    /// Returns TAI - UTC at the provided UTC duration since J1900, including the drift of UTC from 1960 to 1972 as in SOFA's `iauDat`.
+    fn delta_at_utc(utc_duration: Duration) -> Duration {
+        match registered_leap_seconds() {
+            Some(table) => Self::delta_at_utc_with(utc_duration, &table),
+            None => Self::delta_at_utc_with(utc_duration, &LatestLeapSeconds::default()),
+        }
+    }
+
+    /// Returns TAI - UTC at the provided UTC duration since J1900 from the provided leap second provider.
+    fn delta_at_utc_with<L: LeapSecondProvider>(utc_duration: Duration, provider: &L) -> Duration {
+        match Self::find_leap_second(utc_duration, false, provider) {
+            Some(leap_second) => Self::delta_at_duration(leap_second.delta_at_utc(utc_duration)),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Returns the smear window of the provided model which contains the provided duration since J1900, counted in UTC if `utc` is set
+    /// and in TAI otherwise. The window is returned as its start in UTC, its start in TAI, its length in UTC and its length in TAI.
+    ///
+    /// Only the leap seconds announced by the IERS are smeared, and the first of these is not since it replaced the drifting offsets of UTC.
+    fn smear_window<L: LeapSecondProvider>(
+        duration: Duration,
+        utc: bool,
+        model: SmearModel,
+        provider: &L,
+    ) -> Option<(Duration, Duration, Duration, Duration)> {
+        let (before, after) = model.window();
+        let leap_seconds = provider.leap_seconds();
+        // The ends of the windows are chronological because the leap seconds are months apart.
+        let idx = leap_seconds.partition_point(|leap_second| {
+            let mut window_end = leap_second.timestamp_tai + after;
+            if !utc {
+                window_end += Self::delta_at_duration(leap_second.delta_at);
+            }
+            window_end <= duration
+        });
+        let next = leap_seconds.get(idx).filter(|ls| ls.announced_by_iers)?;
+        let prev = leap_seconds[..idx]
+            .last()
+            .filter(|ls| ls.announced_by_iers)?;
+
+        let utc_start = next.timestamp_tai - before;
+        let tai_start = utc_start + Self::delta_at_duration(prev.delta_at);
+        let utc_len = before + after;
+        let tai_len = utc_len + Self::delta_at_duration(next.delta_at - prev.delta_at);
+        if duration >= if utc { utc_start } else { tai_start } {
+            Some((utc_start, tai_start, utc_len, tai_len))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    /// Returns this time in a Duration past J1900 counted in UTC as smeared with the provided model, using the provided leap second provider.
+    ///
+    /// Outside of the smear windows, this is the same as the UTC duration.
+    pub fn to_smeared_utc_duration_with<L: LeapSecondProvider>(
+        &self,
+        model: SmearModel,
+        provider: L,
+    ) -> Duration {
+        let tai = self.duration_since_j1900_tai;
+        match Self::smear_window(tai, false, model, &provider) {
+            Some((utc_start, tai_start, utc_len, tai_len)) => {
+                utc_start + scale_duration(tai - tai_start, utc_len, tai_len)
+            }
+            None => {
+                tai - Self::delta_at_duration(
+                    self.leap_seconds_with(false, &provider).unwrap_or(0.0),
+                )
+            }
+        }
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since 1900 January 01 at midnight counted in UTC as smeared with the provided model,
+    /// e.g. a timestamp of a host whose clock is smeared, using the provided leap second provider.
+    ///
+    /// A smeared clock ticks fewer times than TAI during the smear of a positive leap second, so converting a smeared duration to an
+    /// Epoch and back is exact, but converting an Epoch to a smeared duration and back may differ by one nanosecond.
+    pub fn from_smeared_utc_duration_with<L: LeapSecondProvider>(
+        duration: Duration,
+        model: SmearModel,
+        provider: L,
+    ) -> Self {
+        let tai = match Self::smear_window(duration, true, model, &provider) {
+            Some((utc_start, tai_start, utc_len, tai_len)) => {
+                tai_start + scale_duration(duration - utc_start, tai_len, utc_len)
+            }
+            None => duration + Self::delta_at_utc_with(duration, &provider),
+        };
+        let mut e = Self::from_tai_duration(tai);
+        e.time_scale = TimeScale::UTC;
+        e
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since 1900 January 01 at midnight counted in UTC as smeared with the provided model,
+    /// e.g. a timestamp of a host whose clock is smeared.
+    ///
+    /// The leap seconds registered with `leap_seconds::register_leap_seconds` are used if any, else the latest leap seconds.
+    pub fn from_smeared_utc_duration(duration: Duration, model: SmearModel) -> Self {
+        match registered_leap_seconds() {
+            Some(table) => Self::from_smeared_utc_duration_with(duration, model, table),
+            None => {
+                Self::from_smeared_utc_duration_with(duration, model, LatestLeapSeconds::default())
+            }
+        }
+    }
+
+    /// Returns the provided TAI - UTC offset in seconds as a duration rounded to the nanosecond, since the drifting offsets are not whole seconds.
+    fn delta_at_duration(delta_at: f64) -> Duration {
+        Duration::from_truncated_nanoseconds((delta_at * 1e9).round() as i64)
     }
 
     /// Makes a copy of self and sets the duration and time scale appropriately given the new duration
@@ -273,6 +632,9 @@ impl Epoch {
             TimeScale::GPST => Self::from_gpst_duration(new_duration),
             TimeScale::GST => Self::from_gst_duration(new_duration),
             TimeScale::BDT => Self::from_bdt_duration(new_duration),
+            TimeScale::TCB => Self::from_tcb_duration(new_duration),
+            TimeScale::TCG => Self::from_tcg_duration(new_duration),
+            TimeScale::UT1 => Self::from_ut1_duration_registered(new_duration),
         }
     }
 
@@ -319,7 +681,7 @@ impl Epoch {
         // We have the time in TAI. But we were given UTC.
         // Hence, we need to _add_ the leap seconds to get the actual TAI time.
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        e.duration_since_j1900_tai += e.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
+        e.duration_since_j1900_tai += Self::delta_at_utc(duration);
         e.time_scale = TimeScale::UTC;
         e
     }
@@ -373,7 +735,7 @@ impl Epoch {
         // always refer to TAI/mjd
         let mut e = Self::from_mjd_tai(days);
         if time_scale.uses_leap_seconds() {
-            e.duration_since_j1900_tai += e.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
+            e.duration_since_j1900_tai += Self::delta_at_utc(e.duration_since_j1900_tai);
         }
         e.time_scale = time_scale;
         e
@@ -409,7 +771,7 @@ impl Epoch {
         // always refer to TAI/jde
         let mut e = Self::from_jde_tai(days);
         if time_scale.uses_leap_seconds() {
-            e.duration_since_j1900_tai += e.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
+            e.duration_since_j1900_tai += Self::delta_at_utc(e.duration_since_j1900_tai);
         }
         e.time_scale = time_scale;
         e
@@ -471,20 +833,26 @@ impl Epoch {
     /// In order to match SPICE, the as_et_duration() function will manually get rid of that difference.
     #[must_use]
     pub fn from_et_duration(duration_since_j2000: Duration) -> Self {
+        Self::from_et_duration_with(duration_since_j2000, &DeltetConstants::default())
+    }
+
+    /// Initializes an Epoch from the duration between J2000 and the current epoch in Ephemeris Time, using the `DELTET` constants of
+    /// the provided leap second kernel instead of the default NAIF values. Refer to [Epoch::from_et_duration] for the limitations.
+    #[must_use]
+    pub fn from_et_duration_with(duration_since_j2000: Duration, deltet: &DeltetConstants) -> Self {
         // Run a Newton Raphston to convert find the correct value of the
         let mut seconds_j2000 = duration_since_j2000.to_seconds();
         for _ in 0..5 {
-            seconds_j2000 += -NAIF_K
-                * (NAIF_M0
-                    + NAIF_M1 * seconds_j2000
-                    + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds_j2000).sin())
+            seconds_j2000 += -deltet.k
+                * (deltet.m0
+                    + deltet.m1 * seconds_j2000
+                    + deltet.eb * (deltet.m0 + deltet.m1 * seconds_j2000).sin())
                 .sin();
         }
 
         // At this point, we have a good estimate of the number of seconds of this epoch.
         // Reverse the algorithm:
-        let delta_et_tai =
-            Self::delta_et_tai(seconds_j2000 - (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
+        let delta_et_tai = Self::delta_et_tai(seconds_j2000 - deltet.delta_t_a, deltet);
 
         // Match SPICE by changing the UTC definition.
         Self {
@@ -495,6 +863,28 @@ impl Epoch {
         }
     }
 
+    #[must_use]
+    /// Returns the duration between J2000 and the current epoch in Ephemeris Time, using the `DELTET` constants of the provided
+    /// leap second kernel instead of the default NAIF values.
+    pub fn to_et_duration_with(&self, deltet: &DeltetConstants) -> Duration {
+        // Run a Newton Raphston to convert find the correct value of the
+        let mut seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds();
+        for _ in 0..5 {
+            seconds -= -deltet.k
+                * (deltet.m0
+                    + deltet.m1 * seconds
+                    + deltet.eb * (deltet.m0 + deltet.m1 * seconds).sin())
+                .sin();
+        }
+
+        // At this point, we have a good estimate of the number of seconds of this epoch.
+        // Reverse the algorithm:
+        let delta_et_tai = Self::delta_et_tai(seconds + deltet.delta_t_a, deltet);
+
+        // Match SPICE by changing the UTC definition.
+        self.duration_since_j1900_tai + delta_et_tai * Unit::Second - J2000_TO_J1900_DURATION
+    }
+
     #[must_use]
     /// Initialize an Epoch from Dynamic Barycentric Time (TDB) seconds past 2000 JAN 01 midnight (difference than SPICE)
     /// NOTE: This uses the ESA algorithm, which is a notch more complicated than the SPICE algorithm, but more precise.
@@ -522,6 +912,49 @@ impl Epoch {
         }
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the Barycentric Coordinate Time (TCB) seconds past 2000 JAN 01 noon, like TDB.
+    pub fn from_tcb_seconds(seconds_j2000: f64) -> Epoch {
+        assert!(
+            seconds_j2000.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration(seconds_j2000 * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize from Barycentric Coordinate Time (TCB) whose epoch is 2000 JAN 01 noon, like TDB.
+    /// TCB is converted to TDB using the linear relation of IAU 2006 Resolution B3: TDB = TCB - L_B × (TCB - T_0) + TDB_0.
+    pub fn from_tcb_duration(duration_since_j2000: Duration) -> Epoch {
+        let t0 = IAU_T0_J1900 - J2000_TO_J1900_DURATION;
+        let delta_tcb_tdb = IAU_L_B * (duration_since_j2000 - t0).to_seconds() - IAU_TDB0_S;
+
+        let mut me = Self::from_tdb_duration(duration_since_j2000 - delta_tcb_tdb * Unit::Second);
+        me.time_scale = TimeScale::TCB;
+        me
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the Geocentric Coordinate Time (TCG) seconds past 1900 JAN 01, like TT.
+    pub fn from_tcg_seconds(seconds: f64) -> Epoch {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize from Geocentric Coordinate Time (TCG) whose epoch is 1900 JAN 01, like TT.
+    /// TCG is converted to TT using the linear relation of IAU 2000 Resolution B1.9: TT = TCG - L_G × (TCG - T_0).
+    pub fn from_tcg_duration(duration: Duration) -> Epoch {
+        let delta_tcg_tt = IAU_L_G * (duration - IAU_T0_J1900).to_seconds();
+
+        let mut me = Self::from_tt_duration(duration - delta_tcg_tt * Unit::Second);
+        me.time_scale = TimeScale::TCG;
+        me
+    }
+
     #[must_use]
     /// Initialize from the JDE days
     pub fn from_jde_et(days: f64) -> Self {
@@ -631,19 +1064,132 @@ impl Epoch {
     #[must_use]
     /// Initialize an Epoch from the provided duration since UTC midnight 1970 January 01.
     pub fn from_unix_duration(duration: Duration) -> Self {
-        Self::from_utc_duration(UNIX_REF_EPOCH.to_utc_duration() + duration)
+        Self::from_utc_duration(UNIX_REF_EPOCH_UTC_DURATION + duration)
     }
 
     #[must_use]
     /// Initialize an Epoch from the provided UNIX second timestamp since UTC midnight 1970 January 01.
     pub fn from_unix_seconds(seconds: f64) -> Self {
-        Self::from_utc_duration(UNIX_REF_EPOCH.to_utc_duration() + seconds * Unit::Second)
+        Self::from_utc_duration(UNIX_REF_EPOCH_UTC_DURATION + seconds * Unit::Second)
     }
 
     #[must_use]
     /// Initialize an Epoch from the provided UNIX millisecond timestamp since UTC midnight 1970 January 01.
     pub fn from_unix_milliseconds(millisecond: f64) -> Self {
-        Self::from_utc_duration(UNIX_REF_EPOCH.to_utc_duration() + millisecond * Unit::Millisecond)
+        Self::from_utc_duration(UNIX_REF_EPOCH_UTC_DURATION + millisecond * Unit::Millisecond)
+    }
+
+    /// Initialize an Epoch from the provided TAI64 label, i.e. the TAI seconds since 1970 January 01 TAI offset by 2^62, in big endian.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::ValueError` if the label is reserved, i.e. 2^63 or more;
+    /// + `Errors::Overflow` if the epoch is beyond the bounds of Epoch.
+    pub fn from_tai64(label: [u8; 8]) -> Result<Self, Errors> {
+        Self::from_tai64_parts(u64::from_be_bytes(label), 0)
+    }
+
+    /// Initialize an Epoch from the provided TAI64N label, i.e. a TAI64 label followed by the nanoseconds in the second, in big endian.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::ValueError` if the label is reserved or the nanoseconds exceed 999,999,999;
+    /// + `Errors::Overflow` if the epoch is beyond the bounds of Epoch.
+    pub fn from_tai64n(label: [u8; 12]) -> Result<Self, Errors> {
+        let (seconds, nanoseconds) = label.split_at(8);
+        Self::from_tai64_parts(
+            u64::from_be_bytes(seconds.try_into().unwrap()),
+            u32::from_be_bytes(nanoseconds.try_into().unwrap()),
+        )
+    }
+
+    /// Initialize an Epoch from the provided TAI64NA label, i.e. a TAI64N label followed by the attoseconds in the nanosecond, in big endian.
+    /// The attoseconds are truncated, since an Epoch has a nanosecond resolution.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::ValueError` if the label is reserved, or the nanoseconds or attoseconds exceed 999,999,999;
+    /// + `Errors::Overflow` if the epoch is beyond the bounds of Epoch.
+    pub fn from_tai64na(label: [u8; 16]) -> Result<Self, Errors> {
+        let (tai64n, attoseconds) = label.split_at(12);
+        if u32::from_be_bytes(attoseconds.try_into().unwrap()) >= NANOSECONDS_PER_SECOND_U32 {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        Self::from_tai64n(tai64n.try_into().unwrap())
+    }
+
+    /// Initialize an Epoch from the provided TAI64, TAI64N or TAI64NA label in external form, i.e. `@` followed by 16, 24 or 32
+    /// hexadecimal digits, e.g. `@4000000037c219bf2ef02e94`.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::ValueError` if the string is not a label, or as per [Epoch::from_tai64na].
+    pub fn from_tai64_str(s_in: &str) -> Result<Self, Errors> {
+        let digits = s_in
+            .trim()
+            .strip_prefix('@')
+            .ok_or(Errors::ParseError(ParsingErrors::ValueError))?;
+        if !matches!(digits.len(), 16 | 24 | 32) || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+
+        // Missing nanoseconds and attoseconds are zero.
+        let mut label = [0_u8; 16];
+        for (octet, pair) in label.iter_mut().zip(digits.as_bytes().chunks(2)) {
+            // These are ASCII hexadecimal digits.
+            *octet = u8::from_str_radix(core::str::from_utf8(pair).unwrap(), 16).unwrap();
+        }
+        Self::from_tai64na(label)
+    }
+
+    /// Initialize an Epoch from the seconds and nanoseconds of a TAI64N label.
+    fn from_tai64_parts(seconds: u64, nanoseconds: u32) -> Result<Self, Errors> {
+        // Labels from 2^63 are reserved for future extensions.
+        if seconds >= 2 * TAI64_OFFSET || nanoseconds >= NANOSECONDS_PER_SECOND_U32 {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+        let elapsed = i128::from(seconds as i64 - TAI64_OFFSET as i64)
+            * i128::from(NANOSECONDS_PER_SECOND)
+            + i128::from(nanoseconds);
+        if !(Duration::MIN.total_nanoseconds()..=Duration::MAX.total_nanoseconds())
+            .contains(&elapsed)
+        {
+            return Err(Errors::Overflow);
+        }
+        Ok(Self::from_tai_duration(
+            TAI64_REF_EPOCH
+                .duration_since_j1900_tai
+                .checked_add(Duration::from_total_nanoseconds(elapsed))?,
+        ))
+    }
+
+    /// Returns the TAI64 label of this epoch, i.e. the TAI seconds since 1970 January 01 TAI offset by 2^62, in big endian.
+    /// The fraction of the second is truncated.
+    pub fn to_tai64(&self) -> [u8; 8] {
+        self.tai64_parts().0.to_be_bytes()
+    }
+
+    /// Returns the TAI64N label of this epoch, i.e. its TAI64 label followed by the nanoseconds in the second, in big endian.
+    pub fn to_tai64n(&self) -> [u8; 12] {
+        let (seconds, nanoseconds) = self.tai64_parts();
+        let mut label = [0_u8; 12];
+        label[..8].copy_from_slice(&seconds.to_be_bytes());
+        label[8..].copy_from_slice(&nanoseconds.to_be_bytes());
+        label
+    }
+
+    /// Returns the TAI64NA label of this epoch, i.e. its TAI64N label followed by zero attoseconds.
+    pub fn to_tai64na(&self) -> [u8; 16] {
+        let mut label = [0_u8; 16];
+        label[..12].copy_from_slice(&self.to_tai64n());
+        label
+    }
+
+    /// Returns the seconds and nanoseconds of the TAI64N label of this epoch.
+    pub(crate) fn tai64_parts(&self) -> (u64, u32) {
+        let elapsed = (self.duration_since_j1900_tai - TAI64_REF_EPOCH.duration_since_j1900_tai)
+            .total_nanoseconds();
+        let nanoseconds_per_second = i128::from(NANOSECONDS_PER_SECOND);
+        (
+            (elapsed.div_euclid(nanoseconds_per_second) as i64 + TAI64_OFFSET as i64) as u64,
+            elapsed.rem_euclid(nanoseconds_per_second) as u32,
+        )
     }
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in TAI.
@@ -670,6 +1216,8 @@ impl Epoch {
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in the provided time scale.
     /// NOTE: If the time scale is TDB, this function assumes that the SPICE format is used
+    /// NOTE: In UTC, second 60 is the inserted leap second and is only valid at the end of a day when a leap second was inserted,
+    /// including the leap seconds registered with `register_leap_seconds`. In other time scales, it is only valid at the end of the days of the IERS leap seconds.
     #[allow(clippy::too_many_arguments)]
     pub fn maybe_from_gregorian(
         year: i32,
@@ -681,45 +1229,22 @@ impl Epoch {
         nanos: u32,
         time_scale: TimeScale,
     ) -> Result<Self, Errors> {
-        if !is_gregorian_valid(year, month, day, hour, minute, second, nanos) {
+        if !is_gregorian_valid(year, month, day, hour, minute, second, nanos)
+            || (second == 60
+                && time_scale != TimeScale::UTC
+                && !is_iers_leap_second_day(year, month, day))
+        {
             return Err(Errors::Carry);
         }
 
-        let years_since_1900 = year - 1900;
-        let mut duration_wrt_1900 = Unit::Day * i64::from(365 * years_since_1900);
-
-        // count leap years
-        if years_since_1900 > 0 {
-            // we don't count the leap year in 1904, since jan 1904 hasn't had the leap yet,
-            // so we push it back to 1905, same for all other leap years
-            let years_after_1900 = years_since_1900 - 1;
-            duration_wrt_1900 += Unit::Day * i64::from(years_after_1900 / 4);
-            duration_wrt_1900 -= Unit::Day * i64::from(years_after_1900 / 100);
-            // every 400 years we correct our correction. The first one after 1900 is 2000 (years_since_1900 = 100)
-            // so we add 300 to correct the offset
-            duration_wrt_1900 += Unit::Day * i64::from((years_after_1900 + 300) / 400);
-        } else {
-            // we don't need to fix the offset, since jan 1896 has had the leap, when counting back from 1900
-            duration_wrt_1900 += Unit::Day * i64::from(years_since_1900 / 4);
-            duration_wrt_1900 -= Unit::Day * i64::from(years_since_1900 / 100);
-            // every 400 years we correct our correction. The first one before 1900 is 1600 (years_since_1900 = -300)
-            // so we subtract 100 to correct the offset
-            duration_wrt_1900 += Unit::Day * i64::from((years_since_1900 - 100) / 400);
-        };
-
-        // Add the seconds for the months prior to the current month
-        duration_wrt_1900 += Unit::Day * i64::from(CUMULATIVE_DAYS_FOR_MONTH[(month - 1) as usize]);
-
-        if is_leap_year(year) && month > 2 {
-            // NOTE: If on 29th of February, then the day is not finished yet, and therefore
-            // the extra seconds are added below as per a normal day.
-            duration_wrt_1900 += Unit::Day;
-        }
-        duration_wrt_1900 += Unit::Day * i64::from(day - 1)
-            + Unit::Hour * i64::from(hour)
-            + Unit::Minute * i64::from(minute)
-            + Unit::Second * i64::from(second)
-            + Unit::Nanosecond * i64::from(nanos);
+        let days = days_from_civil(year, month, day);
+        let mut duration_wrt_1900 = Duration::from_total_nanoseconds(
+            i128::from(days) * i128::from(NANOSECONDS_PER_DAY)
+                + i128::from(hour) * i128::from(NANOSECONDS_PER_HOUR)
+                + i128::from(minute) * i128::from(NANOSECONDS_PER_MINUTE)
+                + i128::from(second) * i128::from(NANOSECONDS_PER_SECOND)
+                + i128::from(nanos),
+        );
         if second == 60 {
             // Herein lies the whole ambiguity of leap seconds. Two different UTC dates exist at the
             // same number of second afters J1900.0.
@@ -727,7 +1252,7 @@ impl Epoch {
         }
 
         // NOTE: For ET and TDB, we make sure to offset the duration back to J2000 since those functions expect a J2000 input.
-        Ok(match time_scale {
+        let mut epoch = match time_scale {
             TimeScale::TAI => Self::from_tai_duration(duration_wrt_1900),
             TimeScale::TT => Self::from_tt_duration(duration_wrt_1900),
             TimeScale::ET => Self::from_et_duration(duration_wrt_1900 - J2000_TO_J1900_DURATION),
@@ -742,7 +1267,21 @@ impl Epoch {
             TimeScale::BDT => {
                 Self::from_bdt_duration(duration_wrt_1900 - BDT_REF_EPOCH.to_tai_duration())
             }
-        })
+            TimeScale::TCB => Self::from_tcb_duration(duration_wrt_1900 - J2000_TO_J1900_DURATION),
+            TimeScale::TCG => Self::from_tcg_duration(duration_wrt_1900),
+            TimeScale::UT1 => Self::from_ut1_duration_registered(duration_wrt_1900),
+        };
+
+        if second == 60 && time_scale == TimeScale::UTC {
+            // The leap second starts one second after 23:59:59 UTC, which shares its number of seconds since J1900.
+            epoch.duration_since_j1900_tai += Unit::Second;
+            if epoch.utc_leap_second().is_none() {
+                // No leap second was inserted at the end of this day.
+                return Err(Errors::Carry);
+            }
+        }
+
+        Ok(epoch)
     }
 
     #[must_use]
@@ -799,15 +1338,16 @@ impl Epoch {
         second: u8,
         nanos: u32,
     ) -> Result<Self, Errors> {
-        let mut if_tai =
-            Self::maybe_from_gregorian_tai(year, month, day, hour, minute, second, nanos)?;
-        // Compute the TAI to UTC offset at this time.
-        // We have the time in TAI. But we were given UTC.
-        // Hence, we need to _add_ the leap seconds to get the actual TAI time.
-        // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        if_tai.duration_since_j1900_tai += if_tai.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
-        if_tai.time_scale = TimeScale::UTC;
-        Ok(if_tai)
+        Self::maybe_from_gregorian(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos,
+            TimeScale::UTC,
+        )
     }
 
     #[must_use]
@@ -1041,7 +1581,12 @@ impl Epoch {
             ts,
         );
 
-        Ok(epoch? + tz)
+        if tz == Duration::ZERO {
+            // Adding a zero offset in UTC would lose a leap second.
+            epoch
+        } else {
+            Ok(epoch? + tz)
+        }
     }
 
     /// Initializes an Epoch from the provided Format.
@@ -1058,28 +1603,75 @@ impl Epoch {
 
     #[cfg(feature = "ut1")]
     #[must_use]
-    /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight
-    ///
-    /// # Warning
-    /// The time scale of this Epoch will be set to TAI! This is to ensure that no additional computations will change the duration since it's stored in TAI.
-    /// However, this also means that calling `to_duration()` on this Epoch will return the TAI duration and not the UT1 duration!
-    pub fn from_ut1_duration(duration: Duration, provider: Ut1Provider) -> Self {
+    /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight, using the provided UT1 source.
+    /// If the source does not cover this epoch, UT1 is assumed to be equal to UTC, which is within 0.9 s of UT1.
+    pub fn from_ut1_duration<S: Ut1Source + ?Sized>(duration: Duration, provider: &S) -> Self {
+        Self::from_ut1_duration_with(duration, |epoch| provider.delta_tai_minus_ut1(epoch))
+    }
+
+    /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight, where `delta_tai_minus_ut1` returns TAI - UT1 at a given epoch,
+    /// or None if UT1 is to be assumed equal to UTC.
+    fn from_ut1_duration_with<F: Fn(&Epoch) -> Option<Duration>>(
+        duration: Duration,
+        delta_tai_minus_ut1: F,
+    ) -> Self {
+        // The offset is provided as offset = TAI - UT1 <=> TAI = UT1 + offset, but it is tabulated in TAI.
+        // Hence, we first compute the offset using the UT1 duration as a TAI duration, and refine it once.
         let mut e = Self::from_tai_duration(duration);
-        // Compute the TAI to UT1 offset at this time.
-        // We have the time in TAI. But we were given UT1.
-        // The offset is provided as offset = TAI - UT1 <=> TAI = UT1 + offset
-        e.duration_since_j1900_tai += e.ut1_offset(provider).unwrap_or(Duration::ZERO);
-        e.time_scale = TimeScale::TAI;
+        for _ in 0..2 {
+            e.duration_since_j1900_tai = duration + ut1_offset_or_utc(&e, delta_tai_minus_ut1(&e));
+        }
+        e.time_scale = TimeScale::UT1;
         e
     }
 
-    fn delta_et_tai(seconds: f64) -> f64 {
+    #[cfg(feature = "ut1")]
+    /// Get the accumulated offset between this epoch and UT1 (i.e. TAI - UT1) from the provided UT1 source.
+    pub fn ut1_offset<S: Ut1Source + ?Sized>(&self, provider: &S) -> Option<Duration> {
+        provider.delta_tai_minus_ut1(self)
+    }
+
+    #[cfg(feature = "ut1")]
+    #[must_use]
+    /// Returns this time in a Duration past J1900 counted in UT1, using the provided UT1 source.
+    /// If the source does not cover this epoch, UT1 is assumed to be equal to UTC, which is within 0.9 s of UT1.
+    pub fn to_ut1_duration<S: Ut1Source + ?Sized>(&self, provider: &S) -> Duration {
+        // TAI = UT1 + offset <=> UT1 = TAI - offset
+        self.duration_since_j1900_tai - ut1_offset_or_utc(self, self.ut1_offset(provider))
+    }
+
+    #[cfg(feature = "ut1")]
+    #[must_use]
+    /// Returns a TAI epoch whose TAI duration is this time counted in UT1, using the provided UT1 source.
+    /// If the source does not cover this epoch, UT1 is assumed to be equal to UTC, which is within 0.9 s of UT1.
+    ///
+    /// # Warning
+    /// The returned epoch is _not_ the same instant as this epoch. Use `in_time_scale(TimeScale::UT1)` with a registered UT1 source to keep the same instant.
+    pub fn to_ut1<S: Ut1Source + ?Sized>(&self, provider: &S) -> Self {
+        let mut me = *self;
+        me.duration_since_j1900_tai = self.to_ut1_duration(provider);
+        me.time_scale = TimeScale::TAI;
+        me
+    }
+
+    /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight, using the registered UT1 source, or UTC if it does not cover this epoch.
+    fn from_ut1_duration_registered(duration: Duration) -> Self {
+        Self::from_ut1_duration_with(duration, registered_ut1_offset)
+    }
+
+    /// Returns this time in a Duration past J1900 counted in UT1 using the registered UT1 source, or in UTC if it does not cover this epoch.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_ut1_duration_registered(&self) -> Duration {
+        self.duration_since_j1900_tai - ut1_offset_or_utc(self, registered_ut1_offset(self))
+    }
+
+    fn delta_et_tai(seconds: f64, deltet: &DeltetConstants) -> f64 {
         // Calculate M, the mean anomaly.4
-        let m = NAIF_M0 + seconds * NAIF_M1;
+        let m = deltet.m0 + seconds * deltet.m1;
         // Calculate eccentric anomaly
-        let e = m + NAIF_EB * m.sin();
+        let e = m + deltet.eb * m.sin();
 
-        (TT_OFFSET_MS * Unit::Millisecond).to_seconds() + NAIF_K * e.sin()
+        deltet.delta_t_a + deltet.k * e.sin()
     }
 
     fn inner_g(seconds: f64) -> f64 {
@@ -1090,121 +1682,23 @@ impl Epoch {
     }
 
     pub(crate) fn compute_gregorian(duration_j1900: Duration) -> (i32, u8, u8, u8, u8, u8, u32) {
-        let (sign, days, hours, minutes, seconds, milliseconds, microseconds, nanos) =
-            duration_j1900.decompose();
+        // The nanoseconds of a duration are always positive and a century is exactly 36525 days,
+        // so the days since J1900 and the time within that day follow from integer arithmetic only.
+        let days = i64::from(duration_j1900.centuries) * DAYS_PER_CENTURY_I64
+            + (duration_j1900.nanoseconds / NANOSECONDS_PER_DAY) as i64;
+        let nanos_in_day = duration_j1900.nanoseconds % NANOSECONDS_PER_DAY;
 
-        let days_f64 = if sign < 0 {
-            -(days as f64)
-        } else {
-            days as f64
-        };
-
-        let (mut year, mut days_in_year) = div_rem_f64(days_f64, DAYS_PER_YEAR_NLD);
-        // TAI is defined at 1900, so a negative time is before 1900 and positive is after 1900.
-        year += 1900;
-
-        // Base calculation was on 365 days, so we need to remove one day in seconds per leap year
-        // between 1900 and `year`
-        if year >= 1900 {
-            for year in 1900..year {
-                if is_leap_year(year) {
-                    days_in_year -= 1.0;
-                }
-            }
-        } else {
-            for year in year..1900 {
-                if is_leap_year(year) {
-                    days_in_year += 1.0;
-                }
-            }
-        }
-
-        // Get the month from the exact number of seconds between the start of the year and now
-        let mut month = 1;
-        let mut day;
-
-        let mut days_so_far = 0.0;
-        loop {
-            let mut days_next_month = usual_days_per_month(month - 1) as f64;
-            if month == 2 && is_leap_year(year) {
-                days_next_month += 1.0;
-            }
+        let (year, month, day) = civil_from_days(days);
 
-            if days_so_far + days_next_month > days_in_year || month == 12 {
-                // We've found the month and can calculate the days
-                day = if sign >= 0 {
-                    days_in_year - days_so_far + 1.0
-                } else {
-                    days_in_year - days_so_far
-                };
-                break;
-            }
-
-            // Otherwise, count up the number of days this year so far and keep track of the month.
-            days_so_far += days_next_month;
-            month += 1;
-        }
-
-        if day <= 0.0 || days_in_year < 0.0 {
-            // We've overflowed backward
-            month = 12;
-            year -= 1;
-            // NOTE: Leap year is already accounted for in the TAI duration when counting backward.
-            day = if days_in_year < 0.0 {
-                days_in_year + usual_days_per_month(11) as f64 + 1.0
-            } else {
-                usual_days_per_month(11) as f64
-            };
-        }
-
-        if sign < 0 {
-            let time = Duration::compose(
-                sign,
-                0,
-                hours,
-                minutes,
-                seconds,
-                milliseconds,
-                microseconds,
-                nanos,
-            );
-
-            // Last check on the validity of the Gregorian date
-
-            if time == Duration::ZERO || month == 12 && day == 32.0 {
-                // We've underflowed since we're before 1900.
-                year += 1;
-                month = 1;
-                day = 1.0;
-            }
-
-            let (_, _, hours, minutes, seconds, milliseconds, microseconds, nanos) =
-                (24 * Unit::Hour + time).decompose();
-
-            (
-                year,
-                month,
-                day as u8,
-                hours as u8,
-                minutes as u8,
-                seconds as u8,
-                (nanos
-                    + microseconds * NANOSECONDS_PER_MICROSECOND
-                    + milliseconds * NANOSECONDS_PER_MILLISECOND) as u32,
-            )
-        } else {
-            (
-                year,
-                month,
-                day as u8,
-                hours as u8,
-                minutes as u8,
-                seconds as u8,
-                (nanos
-                    + microseconds * NANOSECONDS_PER_MICROSECOND
-                    + milliseconds * NANOSECONDS_PER_MILLISECOND) as u32,
-            )
-        }
+        (
+            year,
+            month,
+            day,
+            (nanos_in_day / NANOSECONDS_PER_HOUR) as u8,
+            (nanos_in_day % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE) as u8,
+            (nanos_in_day % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND) as u8,
+            (nanos_in_day % NANOSECONDS_PER_SECOND) as u32,
+        )
     }
 
     /// Builds an Epoch from given `week`: elapsed weeks counter into the desired Time scale, and the amount of nanoseconds within that week.
@@ -1253,19 +1747,20 @@ impl Epoch {
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    ///
+    /// The leap seconds registered with `leap_seconds::register_leap_seconds` are used if any, else the latest leap seconds.
     pub fn leap_seconds(&self, iers_only: bool) -> Option<f64> {
-        self.leap_seconds_with(iers_only, LatestLeapSeconds::default())
+        match registered_leap_seconds() {
+            Some(table) => self.leap_seconds_with(iers_only, table),
+            None => self.leap_seconds_with(iers_only, LatestLeapSeconds::default()),
+        }
     }
 
-    #[cfg(feature = "ut1")]
-    /// Get the accumulated offset between this epoch and UT1, assuming that the provider includes all data.
-    pub fn ut1_offset(&self, provider: Ut1Provider) -> Option<Duration> {
-        for delta_tai_ut1 in provider.rev() {
-            if self > &delta_tai_ut1.epoch {
-                return Some(delta_tai_ut1.delta_tai_minus_ut1);
-            }
-        }
-        None
+    #[cfg(feature = "python")]
+    #[pyo3(name = "ut1_offset")]
+    /// Get the accumulated offset between this epoch and UT1 (i.e. TAI - UT1) from the provided UT1 provider.
+    fn py_ut1_offset(&self, provider: PyRef<Ut1Provider>) -> Option<Duration> {
+        self.ut1_offset(&*provider)
     }
 
     /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
@@ -1282,6 +1777,17 @@ impl Epoch {
         self.leap_seconds_with(iers_only, provider)
     }
 
+    /// Get the accumulated number of leap seconds up to this Epoch from the provided NAIF leap second kernel.
+    /// Returns None if the epoch is before 1972, year of the first leap second of these kernels.
+    #[cfg(feature = "python")]
+    pub fn leap_seconds_with_kernel(
+        &self,
+        iers_only: bool,
+        provider: LeapSecondsKernel,
+    ) -> Option<f64> {
+        self.leap_seconds_with(iers_only, provider)
+    }
+
     #[cfg(feature = "python")]
     #[classmethod]
     /// Creates a new Epoch from a Duration as the time difference between this epoch and TAI reference epoch.
@@ -1317,6 +1823,17 @@ impl Epoch {
         Self::from_utc_seconds(seconds)
     }
 
+    #[cfg(feature = "python")]
+    #[classmethod]
+    /// Initialize an Epoch from the provided duration since 1900 January 01 at midnight counted in UTC as smeared with the provided model
+    fn init_from_smeared_utc_duration(
+        _cls: &PyType,
+        duration: Duration,
+        model: SmearModel,
+    ) -> Self {
+        Self::from_smeared_utc_duration(duration, model)
+    }
+
     #[cfg(feature = "python")]
     #[classmethod]
     /// Initialize an Epoch from the provided UTC days since 1900 January 01 at midnight
@@ -1396,6 +1913,34 @@ impl Epoch {
         Self::from_tdb_duration(duration_since_j2000)
     }
 
+    #[cfg(feature = "python")]
+    #[classmethod]
+    /// Initialize an Epoch from the Barycentric Coordinate Time (TCB) seconds past 2000 JAN 01 noon, like TDB.
+    fn init_from_tcb_seconds(_cls: &PyType, seconds_j2000: f64) -> Epoch {
+        Self::from_tcb_seconds(seconds_j2000)
+    }
+
+    #[cfg(feature = "python")]
+    #[classmethod]
+    /// Initialize from Barycentric Coordinate Time (TCB) whose epoch is 2000 JAN 01 noon, like TDB.
+    fn init_from_tcb_duration(_cls: &PyType, duration_since_j2000: Duration) -> Epoch {
+        Self::from_tcb_duration(duration_since_j2000)
+    }
+
+    #[cfg(feature = "python")]
+    #[classmethod]
+    /// Initialize an Epoch from the Geocentric Coordinate Time (TCG) seconds past 1900 JAN 01, like TT.
+    fn init_from_tcg_seconds(_cls: &PyType, seconds: f64) -> Epoch {
+        Self::from_tcg_seconds(seconds)
+    }
+
+    #[cfg(feature = "python")]
+    #[classmethod]
+    /// Initialize from Geocentric Coordinate Time (TCG) whose epoch is 1900 JAN 01, like TT.
+    fn init_from_tcg_duration(_cls: &PyType, duration: Duration) -> Epoch {
+        Self::from_tcg_duration(duration)
+    }
+
     #[cfg(feature = "python")]
     #[classmethod]
     /// Initialize from the JDE days
@@ -1726,6 +2271,9 @@ impl Epoch {
             TimeScale::GPST => self.to_gpst_duration(),
             TimeScale::BDT => self.to_bdt_duration(),
             TimeScale::GST => self.to_gst_duration(),
+            TimeScale::TCB => self.to_tcb_duration(),
+            TimeScale::TCG => self.to_tcg_duration(),
+            TimeScale::UT1 => self.to_ut1_duration_registered(),
         }
     }
 
@@ -1761,9 +2309,53 @@ impl Epoch {
             TimeScale::GPST => self.to_gpst_duration() + GPST_REF_EPOCH.to_tai_duration(),
             TimeScale::GST => self.to_gst_duration() + GST_REF_EPOCH.to_tai_duration(),
             TimeScale::BDT => self.to_bdt_duration() + BDT_REF_EPOCH.to_tai_duration(),
+            TimeScale::TCB => self.to_tcb_duration_since_j1900(),
+            TimeScale::TCG => self.to_tcg_duration(),
+            TimeScale::UT1 => self.to_ut1_duration_registered(),
         }
     }
 
+    /// Returns this epoch shifted by the provided duration in its time scale like the `+` operator, or an overflow error instead of saturating
+    /// if the result, or the duration of this epoch in its time scale, is at the bounds of Duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Epoch, Errors, Unit};
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(epoch.checked_add(Unit::Day * 1), Ok(epoch + Unit::Day));
+    /// assert_eq!(epoch.checked_add(Duration::MAX), Err(Errors::Overflow));
+    /// ```
+    pub fn checked_add(&self, duration: Duration) -> Result<Self, Errors> {
+        self.checked_set(self.to_duration().checked_add(duration)?)
+    }
+
+    /// Returns this epoch shifted back by the provided duration in its time scale like the `-` operator, or an overflow error instead of saturating
+    /// if the result, or the duration of this epoch in its time scale, is at the bounds of Duration.
+    pub fn checked_sub(&self, duration: Duration) -> Result<Self, Errors> {
+        self.checked_set(self.to_duration().checked_sub(duration)?)
+    }
+
+    /// Returns this epoch shifted by the provided duration in its time scale, saturating at the bounds of Duration. This is the same as the `+` operator.
+    pub fn saturating_add(&self, duration: Duration) -> Self {
+        self.set(self.to_duration().saturating_add(duration))
+    }
+
+    /// Returns this epoch shifted back by the provided duration in its time scale, saturating at the bounds of Duration. This is the same as the `-` operator.
+    pub fn saturating_sub(&self, duration: Duration) -> Self {
+        self.set(self.to_duration().saturating_sub(duration))
+    }
+
+    /// Returns this epoch shifted by the provided duration in its time scale, wrapping around the bounds of Duration.
+    pub fn wrapping_add(&self, duration: Duration) -> Self {
+        self.set(self.to_duration().wrapping_add(duration))
+    }
+
+    /// Returns this epoch shifted back by the provided duration in its time scale, wrapping around the bounds of Duration.
+    pub fn wrapping_sub(&self, duration: Duration) -> Self {
+        self.set(self.to_duration().wrapping_sub(duration))
+    }
+
     /// Makes a copy of self and sets the duration and time scale appropriately given the new duration
     #[must_use]
     pub fn set(&self, new_duration: Duration) -> Self {
@@ -1776,6 +2368,9 @@ impl Epoch {
             TimeScale::GPST => Self::from_gpst_duration(new_duration),
             TimeScale::GST => Self::from_gst_duration(new_duration),
             TimeScale::BDT => Self::from_bdt_duration(new_duration),
+            TimeScale::TCB => Self::from_tcb_duration(new_duration),
+            TimeScale::TCG => Self::from_tcg_duration(new_duration),
+            TimeScale::UT1 => Self::from_ut1_duration_registered(new_duration),
         }
     }
 
@@ -1815,11 +2410,24 @@ impl Epoch {
         self.to_utc(Unit::Second)
     }
 
+    #[must_use]
+    /// Returns this time in a Duration past J1900 counted in UTC as smeared with the provided model, e.g. to compare with the
+    /// timestamps of a host whose clock is smeared. Outside of the smear windows, this is the same as the UTC duration.
+    ///
+    /// The leap seconds registered with `leap_seconds::register_leap_seconds` are used if any, else the latest leap seconds.
+    pub fn to_smeared_utc_duration(&self, model: SmearModel) -> Duration {
+        match registered_leap_seconds() {
+            Some(table) => self.to_smeared_utc_duration_with(model, table),
+            None => self.to_smeared_utc_duration_with(model, LatestLeapSeconds::default()),
+        }
+    }
+
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UTC
     pub fn to_utc_duration(&self) -> Duration {
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        self.duration_since_j1900_tai - self.leap_seconds(true).unwrap_or(0.0) * Unit::Second
+        self.duration_since_j1900_tai
+            - Self::delta_at_duration(self.leap_seconds(false).unwrap_or(0.0))
     }
 
     #[must_use]
@@ -2048,7 +2656,7 @@ impl Epoch {
     #[must_use]
     /// Returns the Duration since the UNIX epoch UTC midnight 01 Jan 1970.
     fn to_unix_duration(&self) -> Duration {
-        self.to_duration_in_time_scale(TimeScale::UTC) - UNIX_REF_EPOCH.to_utc_duration()
+        self.to_duration_in_time_scale(TimeScale::UTC) - UNIX_REF_EPOCH_UTC_DURATION
     }
 
     #[must_use]
@@ -2098,21 +2706,7 @@ impl Epoch {
     ///
     /// In order to match SPICE, the as_et_duration() function will manually get rid of that difference.
     pub fn to_et_duration(&self) -> Duration {
-        // Run a Newton Raphston to convert find the correct value of the
-        let mut seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds();
-        for _ in 0..5 {
-            seconds -= -NAIF_K
-                * (NAIF_M0 + NAIF_M1 * seconds + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds).sin())
-                    .sin();
-        }
-
-        // At this point, we have a good estimate of the number of seconds of this epoch.
-        // Reverse the algorithm:
-        let delta_et_tai =
-            Self::delta_et_tai(seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
-
-        // Match SPICE by changing the UTC definition.
-        self.duration_since_j1900_tai + delta_et_tai * Unit::Second - J2000_TO_J1900_DURATION
+        self.to_et_duration_with(&DeltetConstants::default())
     }
 
     #[must_use]
@@ -2165,6 +2759,46 @@ impl Epoch {
         self.to_tdb_duration() + J2000_TO_J1900_DURATION
     }
 
+    #[must_use]
+    /// Returns the Barycentric Coordinate Time (TCB) as a high precision Duration whose epoch is 2000 JAN 01 noon, like TDB.
+    /// This uses the linear relation of IAU 2006 Resolution B3 between TDB and TCB.
+    pub fn to_tcb_duration(&self) -> Duration {
+        let tdb = self.to_tdb_duration();
+        let t0 = IAU_T0_J1900 - J2000_TO_J1900_DURATION;
+        let delta_tcb_tdb = (IAU_L_B * (tdb - t0).to_seconds() - IAU_TDB0_S) / (1.0 - IAU_L_B);
+
+        tdb + delta_tcb_tdb * Unit::Second
+    }
+
+    #[must_use]
+    /// Returns the Barycentric Coordinate Time (TCB) seconds past 2000 JAN 01 noon, like TDB.
+    pub fn to_tcb_seconds(&self) -> f64 {
+        self.to_tcb_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns the Barycentric Coordinate Time (TCB) as a high precision Duration with reference epoch of 1900 JAN 01 at noon.
+    /// **Only** use this if the subsequent computation expect J1900 seconds.
+    pub fn to_tcb_duration_since_j1900(&self) -> Duration {
+        self.to_tcb_duration() + J2000_TO_J1900_DURATION
+    }
+
+    #[must_use]
+    /// Returns the Geocentric Coordinate Time (TCG) as a high precision Duration past the TAI epoch, like TT.
+    /// This uses the linear relation of IAU 2000 Resolution B1.9 between TT and TCG.
+    pub fn to_tcg_duration(&self) -> Duration {
+        let tt = self.to_tt_duration();
+        let delta_tcg_tt = IAU_L_G * (tt - IAU_T0_J1900).to_seconds() / (1.0 - IAU_L_G);
+
+        tt + delta_tcg_tt * Unit::Second
+    }
+
+    #[must_use]
+    /// Returns the Geocentric Coordinate Time (TCG) seconds past the TAI epoch, like TT.
+    pub fn to_tcg_seconds(&self) -> f64 {
+        self.to_tcg_duration().to_seconds()
+    }
+
     #[must_use]
     /// Returns the Ephemeris Time JDE past epoch
     pub fn to_jde_et_days(&self) -> f64 {
@@ -2241,7 +2875,24 @@ impl Epoch {
     /// assert_eq!("2017-01-14T00:31:55 UTC", dt.as_gregorian_utc_str().to_owned());
     /// ```
     pub fn to_gregorian_utc(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
-        Self::compute_gregorian(self.to_utc_duration())
+        match self.utc_leap_second() {
+            Some((midnight, elapsed)) => {
+                // The inserted leap second is the sixtieth second of the last minute of the day.
+                let (y, mm, dd, hh, min, s, _) = Self::compute_gregorian(midnight - Unit::Second);
+                let (_, _, _, _, seconds, milliseconds, microseconds, nanoseconds) =
+                    elapsed.decompose();
+                (
+                    y,
+                    mm,
+                    dd,
+                    hh,
+                    min,
+                    s + 1 + seconds as u8,
+                    (milliseconds * 1_000_000 + microseconds * 1_000 + nanoseconds) as u32,
+                )
+            }
+            None => Self::compute_gregorian(self.to_utc_duration()),
+        }
     }
 
     #[must_use]
@@ -2264,23 +2915,18 @@ impl Epoch {
         Self::compute_gregorian(self.to_tai_duration())
     }
 
-    #[cfg(feature = "ut1")]
-    #[must_use]
-    /// Returns this time in a Duration past J1900 counted in UT1
-    pub fn to_ut1_duration(&self, provider: Ut1Provider) -> Duration {
-        // TAI = UT1 + offset <=> UTC = TAI - offset
-        self.duration_since_j1900_tai - self.ut1_offset(provider).unwrap_or(Duration::ZERO)
+    #[cfg(feature = "python")]
+    #[pyo3(name = "to_ut1_duration")]
+    /// Returns this time in a Duration past J1900 counted in UT1, using the provided UT1 provider.
+    fn py_to_ut1_duration(&self, provider: PyRef<Ut1Provider>) -> Duration {
+        self.to_ut1_duration(&*provider)
     }
 
-    #[cfg(feature = "ut1")]
-    #[must_use]
-    /// Returns this time in a Duration past J1900 counted in UT1
-    pub fn to_ut1(&self, provider: Ut1Provider) -> Self {
-        let mut me = *self;
-        // TAI = UT1 + offset <=> UTC = TAI - offset
-        me.duration_since_j1900_tai -= self.ut1_offset(provider).unwrap_or(Duration::ZERO);
-        me.time_scale = TimeScale::TAI;
-        me
+    #[cfg(feature = "python")]
+    #[pyo3(name = "to_ut1")]
+    /// Returns a TAI epoch whose TAI duration is this time counted in UT1, using the provided UT1 provider.
+    fn py_to_ut1(&self, provider: PyRef<Ut1Provider>) -> Self {
+        self.to_ut1(&*provider)
     }
 
     #[must_use]
@@ -2657,6 +3303,94 @@ impl Epoch {
         )
     }
 
+    /// Returns this epoch shifted by the provided number of months (possibly negative) in the Gregorian calendar of the provided time scale,
+    /// keeping the time of day. The returned epoch is in the time scale of this epoch.
+    ///
+    /// The policy defines how a day which does not exist in the resulting month is handled, e.g. January 31 plus one month.
+    /// Returns `Errors::Overflow` if the resulting date is beyond the range of Duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, MonthEndPolicy, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2023, 1, 31, 12, 0, 0);
+    /// assert_eq!(
+    ///     e.add_months(1, MonthEndPolicy::Clamp, TimeScale::UTC).unwrap(),
+    ///     Epoch::from_gregorian_utc_hms(2023, 2, 28, 12, 0, 0)
+    /// );
+    /// assert_eq!(
+    ///     e.add_months(1, MonthEndPolicy::Overflow, TimeScale::UTC).unwrap(),
+    ///     Epoch::from_gregorian_utc_hms(2023, 3, 3, 12, 0, 0)
+    /// );
+    /// assert!(e.add_months(1, MonthEndPolicy::Strict, TimeScale::UTC).is_err());
+    /// ```
+    pub fn add_months(
+        &self,
+        months: i32,
+        policy: MonthEndPolicy,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        self.add_calendar(i64::from(months), 0, policy, time_scale)
+    }
+
+    /// Returns this epoch shifted by the provided number of years (possibly negative) in the Gregorian calendar of the provided time scale,
+    /// keeping the time of day. The returned epoch is in the time scale of this epoch.
+    ///
+    /// The policy defines how February 29 is handled when the resulting year is not a leap year.
+    /// Returns `Errors::Overflow` if the resulting date is beyond the range of Duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, MonthEndPolicy, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_tai_at_midnight(2024, 2, 29);
+    /// assert_eq!(
+    ///     e.add_years(1, MonthEndPolicy::Clamp, TimeScale::TAI).unwrap(),
+    ///     Epoch::from_gregorian_tai_at_midnight(2025, 2, 28)
+    /// );
+    /// assert_eq!(
+    ///     e.add_years(4, MonthEndPolicy::Strict, TimeScale::TAI).unwrap(),
+    ///     Epoch::from_gregorian_tai_at_midnight(2028, 2, 29)
+    /// );
+    /// ```
+    pub fn add_years(
+        &self,
+        years: i32,
+        policy: MonthEndPolicy,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        self.add_calendar(i64::from(years) * 12, 0, policy, time_scale)
+    }
+
+    /// Returns this epoch shifted by the provided calendar duration in the Gregorian calendar of the provided time scale,
+    /// keeping the time of day. The years and months are applied first (with the provided policy), then the days.
+    /// The returned epoch is in the time scale of this epoch. Returns `Errors::Overflow` if the resulting date is beyond the range of Duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{CalendarDuration, Epoch, MonthEndPolicy, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_noon(2023, 1, 31);
+    /// assert_eq!(
+    ///     e.add_calendar_duration(CalendarDuration::new(1, 1, 1), MonthEndPolicy::Clamp, TimeScale::UTC)
+    ///         .unwrap(),
+    ///     Epoch::from_gregorian_utc_at_noon(2024, 3, 1)
+    /// );
+    /// ```
+    pub fn add_calendar_duration(
+        &self,
+        duration: CalendarDuration,
+        policy: MonthEndPolicy,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        self.add_calendar(
+            duration.total_months(),
+            i64::from(duration.days),
+            policy,
+            time_scale,
+        )
+    }
+
     pub fn month_name(&self) -> MonthName {
         let month = Self::compute_gregorian(self.to_duration()).1;
         month.into()
@@ -2742,16 +3476,21 @@ impl Epoch {
     #[must_use]
     /// Converts the Epoch to Gregorian in the provided time scale and in the ISO8601 format with the time scale appended to the string
     pub fn to_gregorian_str(&self, time_scale: TimeScale) -> String {
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(match time_scale {
-            TimeScale::TT => self.to_tt_duration(),
-            TimeScale::TAI => self.to_tai_duration(),
-            TimeScale::ET => self.to_et_duration_since_j1900(),
-            TimeScale::TDB => self.to_tdb_duration_since_j1900(),
-            TimeScale::UTC => self.to_utc_duration(),
-            TimeScale::GPST => self.to_utc_duration(),
-            TimeScale::GST => self.to_utc_duration(),
-            TimeScale::BDT => self.to_utc_duration(),
-        });
+        let (y, mm, dd, hh, min, s, nanos) = match time_scale {
+            TimeScale::UTC => self.to_gregorian_utc(),
+            _ => Self::compute_gregorian(match time_scale {
+                TimeScale::TT => self.to_tt_duration(),
+                TimeScale::TAI => self.to_tai_duration(),
+                TimeScale::ET => self.to_et_duration_since_j1900(),
+                TimeScale::TDB => self.to_tdb_duration_since_j1900(),
+                TimeScale::TCB => self.to_tcb_duration_since_j1900(),
+                TimeScale::TCG => self.to_tcg_duration(),
+                TimeScale::UT1 => self.to_ut1_duration_registered(),
+                TimeScale::UTC | TimeScale::GPST | TimeScale::GST | TimeScale::BDT => {
+                    self.to_utc_duration()
+                }
+            }),
+        };
 
         if nanos == 0 {
             format!(
@@ -2769,7 +3508,7 @@ impl Epoch {
     #[cfg(feature = "std")]
     /// Returns this epoch in UTC in the RFC3339 format
     pub fn to_rfc3339(&self) -> String {
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(self.to_utc_duration());
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
         if nanos == 0 {
             format!(
                 "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}+00:00",
@@ -2837,6 +3576,11 @@ impl Epoch {
         let duration = crate::system_time::duration_since_unix_epoch()?;
         Ok(Self::from_unix_duration(duration))
     }
+
+    /// Returns this epoch in the provided time zone, which provides its local wall-clock time, offset from UTC, and abbreviation.
+    pub fn to_local(&self, time_zone: &TimeZone) -> LocalEpoch {
+        LocalEpoch::new(*self, time_zone)
+    }
 }
 
 #[cfg(not(kani))]
@@ -2924,8 +3668,7 @@ impl FromStr for Epoch {
 impl fmt::Debug for Epoch {
     /// Print this epoch in Gregorian in the time scale used at initialization
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_since_j1900());
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian();
         if nanos == 0 {
             write!(
                 f,
@@ -2946,7 +3689,7 @@ impl fmt::Display for Epoch {
     /// The default format of an epoch is in UTC
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let ts = TimeScale::UTC;
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(self.to_utc_duration());
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
         if nanos == 0 {
             write!(
                 f,
@@ -3064,7 +3807,8 @@ impl fmt::Octal for Epoch {
 }
 
 #[must_use]
-/// Returns true if the provided Gregorian date is valid. Leap second days may have 60 seconds.
+/// Returns true if the provided Gregorian date is valid. The last minute of any day may have 60 seconds, since a leap second may be
+/// registered at the end of any day: `Epoch::maybe_from_gregorian` checks whether one was inserted.
 pub const fn is_gregorian_valid(
     year: i32,
     month: u8,
@@ -3074,16 +3818,7 @@ pub const fn is_gregorian_valid(
     second: u8,
     nanos: u32,
 ) -> bool {
-    let max_seconds = if (month == 12 || month == 6)
-        && day == usual_days_per_month(month - 1)
-        && hour == 23
-        && minute == 59
-        && ((month == 6 && july_years(year)) || (month == 12 && january_years(year + 1)))
-    {
-        60
-    } else {
-        59
-    };
+    let max_seconds = if hour == 23 && minute == 59 { 60 } else { 59 };
     // General incorrect date times
     if month == 0
         || month > 12
@@ -3103,22 +3838,69 @@ pub const fn is_gregorian_valid(
     true
 }
 
+/// Returns whether an IERS leap second was inserted at the end of the provided day.
+const fn is_iers_leap_second_day(year: i32, month: u8, day: u8) -> bool {
+    (month == 6 && day == 30 && july_years(year))
+        || (month == 12 && day == 31 && january_years(year + 1))
+}
+
 /// `is_leap_year` returns whether the provided year is a leap year or not.
 /// Tests for this function are part of the Datetime tests.
-const fn is_leap_year(year: i32) -> bool {
+pub(crate) const fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
-fn div_rem_f64(me: f64, rhs: f64) -> (i32, f64) {
-    ((div_euclid_f64(me, rhs) as i32), rem_euclid_f64(me, rhs))
+/// Returns the number of days in the provided month (one indexed) of the provided year.
+pub(crate) const fn days_in_month(year: i32, month: u8) -> u8 {
+    if month == 2 && is_leap_year(year) {
+        29
+    } else {
+        usual_days_per_month(month - 1)
+    }
 }
 
-fn div_euclid_f64(lhs: f64, rhs: f64) -> f64 {
-    let q = (lhs / rhs).trunc();
-    if lhs % rhs < 0.0 {
-        return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
-    }
-    q
+/// Number of days from 0000 March 01 to 1900 January 01 in the proleptic Gregorian calendar.
+const DAYS_FROM_0000_03_01_TO_J1900: i64 = 693_901;
+
+/// Number of days in a 400 year cycle of the Gregorian calendar.
+const DAYS_PER_GREGORIAN_ERA: i64 = 146_097;
+
+/// Returns the number of days from 1900 January 01 to the provided date of the proleptic Gregorian calendar.
+///
+/// This is the `days_from_civil` algorithm of H. Hinnant (<https://howardhinnant.github.io/date_algorithms.html>):
+/// years start in March so that the leap day is the last day of the year, and the 400 year eras repeat exactly.
+/// The month and day must be valid, e.g. as checked by `is_gregorian_valid`.
+pub(crate) const fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    // Year of era, in [0, 399]
+    let yoe = year - era * 400;
+    // Day of the year starting on March 01, in [0, 365]
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    // Day of era, in [0, 146096]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * DAYS_PER_GREGORIAN_ERA + doe - DAYS_FROM_0000_03_01_TO_J1900
+}
+
+/// Returns the (year, month, day) of the proleptic Gregorian calendar which is the provided number of days after 1900 January 01.
+///
+/// This is the `civil_from_days` algorithm of H. Hinnant, the inverse of `days_from_civil`.
+pub(crate) const fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + DAYS_FROM_0000_03_01_TO_J1900;
+    let era = days.div_euclid(DAYS_PER_GREGORIAN_ERA);
+    // Day of era, in [0, 146096]
+    let doe = days - era * DAYS_PER_GREGORIAN_ERA;
+    // Year of era, in [0, 399]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    // Day of the year starting on March 01, in [0, 365]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    // Month starting in March, in [0, 11]
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month as u8, day as u8)
 }
 
 fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
@@ -3130,17 +3912,56 @@ fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
     }
 }
 
+/// Scales the provided non-negative duration by the ratio `num / den` of positive durations, rounded to the nearest nanosecond.
+fn scale_duration(duration: Duration, num: Duration, den: Duration) -> Duration {
+    let den = den.total_nanoseconds();
+    let scaled = duration.total_nanoseconds() * num.total_nanoseconds();
+    Duration::from_total_nanoseconds((2 * scaled + den) / (2 * den))
+}
+
 #[test]
-fn div_rem_f64_test() {
-    assert_eq!(div_rem_f64(24.0, 6.0), (4, 0.0));
-    assert_eq!(div_rem_f64(25.0, 6.0), (4, 1.0));
-    assert_eq!(div_rem_f64(6.0, 6.0), (1, 0.0));
-    assert_eq!(div_rem_f64(5.0, 6.0), (0, 5.0));
-    assert_eq!(div_rem_f64(3540.0, 3600.0), (0, 3540.0));
-    assert_eq!(div_rem_f64(3540.0, 60.0), (59, 0.0));
-    assert_eq!(div_rem_f64(24.0, -6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, 6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, -6.0), (4, 0.0));
+fn civil_days() {
+    assert_eq!(days_from_civil(1900, 1, 1), 0);
+    assert_eq!(civil_from_days(0), (1900, 1, 1));
+    assert_eq!(days_from_civil(1899, 12, 31), -1);
+    assert_eq!(days_from_civil(1970, 1, 1), 25_567);
+    assert_eq!(days_from_civil(2000, 1, 1), 36_524);
+    assert_eq!(days_from_civil(2000, 2, 29), 36_583);
+    assert_eq!(days_from_civil(2000, 3, 1), 36_584);
+    assert_eq!(days_from_civil(1600, 2, 29), -109_514);
+    // Julian day zero starts at noon on 4714 BC November 24, i.e. year -4713, in the proleptic Gregorian calendar.
+    assert_eq!(days_from_civil(-4713, 11, 24), -2_415_021);
+    assert_eq!(civil_from_days(-2_415_021), (-4713, 11, 24));
+
+    // Reciprocity across the whole range of a Duration, i.e. +/- 32768 centuries around J1900.
+    let max_days = 32_768 * DAYS_PER_CENTURY_I64;
+    let mut days = -max_days;
+    while days <= max_days {
+        let (year, month, day) = civil_from_days(days);
+        assert!(is_gregorian_valid(year, month, day, 0, 0, 0, 0));
+        assert_eq!(days_from_civil(year, month, day), days);
+        days += 997;
+    }
+
+    // Every day of the first 800 years after J1900 (two eras) follows the previous one.
+    let mut prev = civil_from_days(-1);
+    for days in 0..(2 * DAYS_PER_GREGORIAN_ERA) {
+        let (year, month, day) = civil_from_days(days);
+        if day == 1 {
+            assert_eq!(
+                usual_days_per_month(prev.1 - 1) + u8::from(prev.1 == 2 && is_leap_year(prev.0)),
+                prev.2
+            );
+            if month == 1 {
+                assert_eq!((prev.0 + 1, prev.1), (year, 12));
+            } else {
+                assert_eq!((prev.0, prev.1 + 1), (year, month));
+            }
+        } else {
+            assert_eq!((prev.0, prev.1, prev.2 + 1), (year, month, day));
+        }
+        prev = (year, month, day);
+    }
 }
 
 #[test]
@@ -3175,14 +3996,6 @@ fn leap_year() {
     }
 }
 
-#[test]
-fn cumulative_days_for_month() {
-    assert_eq!(
-        CUMULATIVE_DAYS_FOR_MONTH,
-        [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334]
-    )
-}
-
 #[test]
 #[cfg(feature = "serde")]
 fn test_serdes() {
@@ -3193,6 +4006,55 @@ fn test_serdes() {
     assert_eq!(e, parsed);
 }
 
+#[cfg(kani)]
+#[kani::proof]
+fn formal_civil_days_reciprocity() {
+    // Range of days covered by a Duration, i.e. +/- 32768 centuries around J1900.
+    let max_days = 32_768 * DAYS_PER_CENTURY_I64;
+    let days: i64 = kani::any();
+    kani::assume(days >= -max_days && days <= max_days);
+
+    let (year, month, day) = civil_from_days(days);
+    assert!(is_gregorian_valid(year, month, day, 0, 0, 0, 0));
+    assert_eq!(days_from_civil(year, month, day), days);
+}
+
+#[cfg(kani)]
+#[kani::proof]
+fn formal_gregorian_reciprocity() {
+    let duration: Duration = kani::any();
+
+    let (year, month, day, hour, minute, second, nanos) = Epoch::compute_gregorian(duration);
+    let epoch =
+        Epoch::maybe_from_gregorian_tai(year, month, day, hour, minute, second, nanos).unwrap();
+    assert_eq!(epoch.to_tai_duration(), duration);
+}
+
+#[cfg(kani)]
+#[kani::proof]
+fn formal_epoch_checked_add_sub() {
+    let duration: Duration = kani::any();
+    let shift: Duration = kani::any();
+    let epoch = Epoch::from_tai_duration(duration);
+
+    // When it does not overflow, shifting an epoch is exact and equal to the operators.
+    if let Ok(shifted) = epoch.checked_add(shift) {
+        assert_eq!(
+            shifted.to_tai_duration().total_nanoseconds(),
+            duration.total_nanoseconds() + shift.total_nanoseconds()
+        );
+        assert_eq!(shifted, epoch + shift);
+        assert_eq!(shifted.checked_sub(shift), Ok(epoch));
+    }
+    if let Ok(shifted) = epoch.checked_sub(shift) {
+        assert_eq!(
+            shifted.to_tai_duration().total_nanoseconds(),
+            duration.total_nanoseconds() - shift.total_nanoseconds()
+        );
+        assert_eq!(shifted, epoch - shift);
+    }
+}
+
 #[cfg(kani)]
 #[kani::proof]
 fn formal_epoch_reciprocity_tai() {
@@ -18,9 +18,11 @@ use std::error::Error;
 #[cfg(feature = "std")]
 use std::io::ErrorKind as IOError;
 
-#[cfg(feature = "ut1")]
+#[cfg(feature = "ut1-download")]
 use reqwest::StatusCode;
 
+#[cfg(feature = "std")]
+use crate::Epoch;
 use crate::Weekday;
 
 /// Errors handles all oddities which may occur in this library.
@@ -62,9 +64,33 @@ pub enum ParsingErrors {
         found: Weekday,
         expected: Weekday,
     },
+    /// The time zone is unknown, does not match the time zone used for parsing, or is not UTC when parsing without a time zone
+    UnknownTimeZone,
+    /// The P-field of a CCSDS time code is invalid or does not identify the expected time code
+    InvalidPField,
+    /// The buffer is shorter than the CCSDS time code
+    BufferTooSmall,
+    /// The TZif data of a time zone is truncated or invalid
+    #[cfg(feature = "std")]
+    TimeZoneFileCorrupted,
+    /// The local time occurs twice in the time zone, e.g. when the clocks are set back at the end of daylight saving time
+    #[cfg(feature = "std")]
+    AmbiguousLocalTime,
+    /// The local time is skipped in the time zone, e.g. when the clocks are set forward at the start of daylight saving time
+    #[cfg(feature = "std")]
+    NonexistentLocalTime,
     #[cfg(feature = "std")]
     IOError(IOError),
-    #[cfg(feature = "ut1")]
+    /// The leap seconds file expired at the provided epoch, so it may lack the leap seconds announced since then
+    #[cfg(feature = "std")]
+    LeapSecondsFileExpired(Epoch),
+    /// The SHA-1 hash of the leap seconds file is missing or does not match its data
+    #[cfg(feature = "std")]
+    LeapSecondsFileCorrupted,
+    /// The expiration date (`#@` line) of the leap seconds file is missing
+    #[cfg(feature = "std")]
+    LeapSecondsFileExpirationMissing,
+    #[cfg(feature = "ut1-download")]
     DownloadError(StatusCode),
 }
 
@@ -0,0 +1,561 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::{Duration, Epoch, TimeSeries};
+use core::cmp::Ordering;
+use core::fmt;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// An interval of time between two Epochs, e.g. a visibility window or an eclipse period, where each bound may be inclusive or exclusive.
+///
+/// Epochs are compared in TAI, so the bounds may be in any time scale.
+/// The interval is empty if the start is after the end, or if both are equal and either bound is exclusive.
+/// Note that equality compares the bounds, so two empty intervals with different bounds are not equal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+pub struct Interval {
+    pub start: Epoch,
+    pub end: Epoch,
+    pub start_inclusive: bool,
+    pub end_inclusive: bool,
+}
+
+impl Interval {
+    /// Builds a new interval between the provided epochs, with the provided inclusivity of each bound.
+    pub const fn new(start: Epoch, end: Epoch, start_inclusive: bool, end_inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        }
+    }
+
+    /// Builds a new interval, inclusive on start **and** on end, i.e. `[start, end]`.
+    pub const fn inclusive(start: Epoch, end: Epoch) -> Self {
+        Self::new(start, end, true, true)
+    }
+
+    /// Builds a new interval, **inclusive** on start and **exclusive** on end, i.e. `[start, end)`.
+    pub const fn exclusive(start: Epoch, end: Epoch) -> Self {
+        Self::new(start, end, true, false)
+    }
+
+    /// Returns whether this interval is disconnected from the provided one: neither overlapping nor adjacent.
+    fn is_disconnected_from(&self, other: &Self) -> bool {
+        !self.overlaps(*other)
+            && !(self.end == other.start && (self.end_inclusive || other.start_inclusive))
+            && !(other.end == self.start && (other.end_inclusive || self.start_inclusive))
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Interval {
+    /// Returns whether this interval contains no epoch at all.
+    pub fn is_empty(&self) -> bool {
+        match self.start.cmp(&self.end) {
+            Ordering::Less => false,
+            Ordering::Equal => !(self.start_inclusive && self.end_inclusive),
+            Ordering::Greater => true,
+        }
+    }
+
+    /// Returns the duration of this interval, which is zero if it is empty.
+    pub fn duration(&self) -> Duration {
+        if self.is_empty() {
+            Duration::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// Returns whether the provided epoch is within this interval.
+    pub fn contains(&self, epoch: Epoch) -> bool {
+        (self.start < epoch || (self.start_inclusive && self.start == epoch))
+            && (epoch < self.end || (self.end_inclusive && epoch == self.end))
+    }
+
+    /// Returns whether this interval shares at least one epoch with the provided interval.
+    pub fn overlaps(&self, other: Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the interval of the epochs in both this interval and the provided one, or None if they do not overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval};
+    ///
+    /// let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let t1 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 2);
+    /// let t2 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 3);
+    /// let t3 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 4);
+    ///
+    /// let visibility = Interval::inclusive(t0, t2);
+    /// let eclipse = Interval::exclusive(t1, t3);
+    /// assert_eq!(visibility.intersection(eclipse), Some(Interval::inclusive(t1, t2)));
+    /// assert_eq!(Interval::exclusive(t0, t1).intersection(eclipse), None);
+    /// ```
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        let (start, start_inclusive) = match self.start.cmp(&other.start) {
+            Ordering::Less => (other.start, other.start_inclusive),
+            Ordering::Equal => (self.start, self.start_inclusive && other.start_inclusive),
+            Ordering::Greater => (self.start, self.start_inclusive),
+        };
+        let (end, end_inclusive) = match self.end.cmp(&other.end) {
+            Ordering::Less => (self.end, self.end_inclusive),
+            Ordering::Equal => (self.end, self.end_inclusive && other.end_inclusive),
+            Ordering::Greater => (other.end, other.end_inclusive),
+        };
+        let intersection = Self::new(start, end, start_inclusive, end_inclusive);
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Returns the interval of the epochs in this interval or the provided one.
+    /// Returns None if the union is not a single interval because they are neither overlapping nor adjacent: use an `IntervalSet` instead.
+    pub fn union(&self, other: Self) -> Option<Self> {
+        if self.is_empty() {
+            return (!other.is_empty()).then_some(other);
+        } else if other.is_empty() {
+            return Some(*self);
+        } else if self.is_disconnected_from(&other) {
+            return None;
+        }
+
+        let (start, start_inclusive) = match self.start.cmp(&other.start) {
+            Ordering::Less => (self.start, self.start_inclusive),
+            Ordering::Equal => (self.start, self.start_inclusive || other.start_inclusive),
+            Ordering::Greater => (other.start, other.start_inclusive),
+        };
+        let (end, end_inclusive) = match self.end.cmp(&other.end) {
+            Ordering::Less => (other.end, other.end_inclusive),
+            Ordering::Equal => (self.end, self.end_inclusive || other.end_inclusive),
+            Ordering::Greater => (self.end, self.end_inclusive),
+        };
+        Some(Self::new(start, end, start_inclusive, end_inclusive))
+    }
+
+    /// Returns the epochs of this interval which are not in the provided interval, as the part before and the part after the provided interval.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval, Unit};
+    ///
+    /// let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let visibility = Interval::exclusive(t0, t0 + Unit::Hour * 6);
+    /// let eclipse = Interval::inclusive(t0 + Unit::Hour * 2, t0 + Unit::Hour * 3);
+    /// assert_eq!(
+    ///     visibility.difference(eclipse),
+    ///     (
+    ///         Some(Interval::exclusive(t0, t0 + Unit::Hour * 2)),
+    ///         Some(Interval::new(t0 + Unit::Hour * 3, t0 + Unit::Hour * 6, false, false))
+    ///     )
+    /// );
+    /// ```
+    pub fn difference(&self, other: Self) -> (Option<Self>, Option<Self>) {
+        if other.is_empty() {
+            return ((!self.is_empty()).then_some(*self), None);
+        }
+        let before = Self::new(
+            self.start,
+            other.start,
+            self.start_inclusive,
+            !other.start_inclusive,
+        );
+        let after = Self::new(
+            other.end,
+            self.end,
+            !other.end_inclusive,
+            self.end_inclusive,
+        );
+        (before.intersection(*self), after.intersection(*self))
+    }
+
+    /// Returns a time series of the epochs in this interval, from its start and spaced by the provided step.
+    /// If the start is exclusive, the series starts one step after it.
+    pub fn time_series(&self, step: Duration) -> TimeSeries {
+        if self.is_empty() {
+            return TimeSeries::exclusive(self.start, self.start, step);
+        }
+        let mut series = if self.end_inclusive {
+            TimeSeries::inclusive(self.start, self.end, step)
+        } else {
+            TimeSeries::exclusive(self.start, self.end, step)
+        };
+        if !self.start_inclusive {
+            series.next();
+        }
+        series
+    }
+
+    #[cfg(feature = "python")]
+    #[new]
+    fn new_py(start: Epoch, end: Epoch, start_inclusive: bool, end_inclusive: bool) -> Self {
+        Self::new(start, end, start_inclusive, end_inclusive)
+    }
+
+    #[cfg(feature = "python")]
+    fn __contains__(&self, epoch: Epoch) -> bool {
+        self.contains(epoch)
+    }
+
+    #[cfg(feature = "python")]
+    fn __eq__(&self, other: Self) -> bool {
+        *self == other
+    }
+
+    #[cfg(feature = "python")]
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    #[cfg(feature = "python")]
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl fmt::Display for Interval {
+    /// Prints this interval in the mathematical notation, e.g. `[2023-01-01T00:00:00 UTC, 2023-01-02T00:00:00 UTC)`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}, {}{}",
+            if self.start_inclusive { '[' } else { '(' },
+            self.start,
+            self.end,
+            if self.end_inclusive { ']' } else { ')' }
+        )
+    }
+}
+
+/// A set of epochs defined as the union of many intervals, e.g. all of the visibility windows of a ground station.
+///
+/// The intervals are normalized: they are sorted, non-empty, and overlapping or adjacent intervals are merged.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<Interval>", into = "Vec<Interval>")
+)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+#[cfg(feature = "std")]
+impl IntervalSet {
+    /// Builds a new empty set
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Returns the normalized intervals of this set, sorted by start epoch.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns an iterator over the normalized intervals of this set.
+    pub fn iter(&self) -> core::slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+
+    /// Returns the number of disjoint intervals in this set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether this set contains no epoch at all.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Adds the provided interval to this set, merging it with the intervals it overlaps or is adjacent to.
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = core::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::from(intervals);
+    }
+
+    /// Returns whether the provided epoch is within any interval of this set.
+    pub fn contains(&self, epoch: Epoch) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.start <= epoch);
+        idx > 0 && self.intervals[idx - 1].contains(epoch)
+    }
+
+    /// Returns the total duration of this set.
+    pub fn duration(&self) -> Duration {
+        self.intervals
+            .iter()
+            .fold(Duration::ZERO, |total, interval| {
+                total + interval.duration()
+            })
+    }
+
+    /// Returns the set of the epochs in this set or in the provided one.
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    /// Returns the set of the epochs in both this set and the provided one.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (lhs, rhs) = (self.intervals[i], other.intervals[j]);
+            if let Some(intersection) = lhs.intersection(rhs) {
+                intervals.push(intersection);
+            }
+            // Move on from the interval which ends first, as it cannot overlap with any other interval of the other set.
+            if lhs.end < rhs.end || (lhs.end == rhs.end && !lhs.end_inclusive) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // Intersections of normalized sets are sorted and disjoint, but may be adjacent.
+        Self::from(intervals)
+    }
+
+    /// Returns the set of the epochs in this set which are not in the provided one.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval, IntervalSet, Unit};
+    ///
+    /// let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let visibility: IntervalSet = [
+    ///     Interval::exclusive(t0, t0 + Unit::Hour * 2),
+    ///     Interval::exclusive(t0 + Unit::Hour * 4, t0 + Unit::Hour * 6),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let eclipses: IntervalSet = [Interval::exclusive(t0 + Unit::Hour * 1, t0 + Unit::Hour * 5)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let sunlit = visibility.difference(&eclipses);
+    /// assert_eq!(sunlit.len(), 2);
+    /// assert_eq!(sunlit.duration(), Unit::Hour * 2);
+    /// assert!(sunlit.contains(t0 + Unit::Hour * 5));
+    /// assert!(!sunlit.contains(t0 + Unit::Hour * 1));
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for interval in &self.intervals {
+            let mut remaining = Some(*interval);
+            for removed in &other.intervals {
+                let Some(current) = remaining else {
+                    break;
+                };
+                if removed.start > current.end {
+                    break;
+                }
+                let (before, after) = current.difference(*removed);
+                if let Some(before) = before {
+                    intervals.push(before);
+                }
+                remaining = after;
+            }
+            if let Some(current) = remaining {
+                intervals.push(current);
+            }
+        }
+        Self { intervals }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Vec<Interval>> for IntervalSet {
+    /// Builds a normalized set from the provided intervals, in any order.
+    fn from(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        // Sort by start, with inclusive starts first on ties.
+        intervals.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then(b.start_inclusive.cmp(&a.start_inclusive))
+        });
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut().and_then(|last| last.union(interval)) {
+                Some(union) => *merged.last_mut().unwrap() = union,
+                None => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<IntervalSet> for Vec<Interval> {
+    fn from(set: IntervalSet) -> Self {
+        set.intervals
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<Interval>>())
+    }
+}
+
+#[cfg(feature = "std")]
+impl IntoIterator for IntervalSet {
+    type Item = Interval;
+    type IntoIter = std::vec::IntoIter<Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = core::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for IntervalSet {
+    /// Prints this set in the mathematical notation, e.g. `{[2023-01-01T00:00:00 UTC, 2023-01-02T00:00:00 UTC)}`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{interval}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl IntervalSet {
+    #[new]
+    /// Builds a normalized set from the provided intervals, in any order.
+    fn new_py(intervals: Vec<Interval>) -> Self {
+        Self::from(intervals)
+    }
+
+    #[pyo3(name = "intervals")]
+    fn intervals_py(&self) -> Vec<Interval> {
+        self.intervals.clone()
+    }
+
+    #[pyo3(name = "insert")]
+    fn insert_py(&mut self, interval: Interval) {
+        self.insert(interval)
+    }
+
+    #[pyo3(name = "is_empty")]
+    fn is_empty_py(&self) -> bool {
+        self.is_empty()
+    }
+
+    #[pyo3(name = "contains")]
+    fn contains_py(&self, epoch: Epoch) -> bool {
+        self.contains(epoch)
+    }
+
+    #[pyo3(name = "duration")]
+    fn duration_py(&self) -> Duration {
+        self.duration()
+    }
+
+    #[pyo3(name = "union")]
+    fn union_py(&self, other: PyRef<'_, Self>) -> Self {
+        self.union(&other)
+    }
+
+    #[pyo3(name = "intersection")]
+    fn intersection_py(&self, other: PyRef<'_, Self>) -> Self {
+        self.intersection(&other)
+    }
+
+    #[pyo3(name = "difference")]
+    fn difference_py(&self, other: PyRef<'_, Self>) -> Self {
+        self.difference(&other)
+    }
+
+    fn __contains__(&self, epoch: Epoch) -> bool {
+        self.contains(epoch)
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __eq__(&self, other: PyRef<'_, Self>) -> bool {
+        *self == *other
+    }
+
+    fn __str__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serdes() {
+    let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let interval = Interval::exclusive(t0, t0 + crate::Unit::Hour);
+    let content = serde_json::to_string(&interval).unwrap();
+    assert_eq!(interval, serde_json::from_str(&content).unwrap());
+
+    // Sets are normalized when deserialized.
+    let content = serde_json::to_string(&vec![
+        interval,
+        Interval::inclusive(t0 - crate::Unit::Hour, t0),
+    ])
+    .unwrap();
+    let set: IntervalSet = serde_json::from_str(&content).unwrap();
+    assert_eq!(
+        set.intervals(),
+        &[Interval::exclusive(
+            t0 - crate::Unit::Hour,
+            t0 + crate::Unit::Hour
+        )]
+    );
+    assert_eq!(
+        serde_json::to_string(&set)
+            .unwrap()
+            .matches("start")
+            .count(),
+        2
+    );
+}
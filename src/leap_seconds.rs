@@ -12,78 +12,213 @@
 use pyo3::prelude::*;
 
 #[cfg(feature = "std")]
-pub use super::leap_seconds_file::LeapSecondsFile;
+pub use super::leap_seconds_file::{LeapSecondsFile, LeapSecondsValidation};
+
+#[cfg(feature = "std")]
+pub use super::leap_seconds_kernel::LeapSecondsKernel;
 
 use core::ops::Index;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{
+    Duration, Unit, J1900_OFFSET, NANOSECONDS_PER_CENTURY, NANOSECONDS_PER_SECOND, SECONDS_PER_DAY,
+};
 
-pub trait LeapSecondProvider: DoubleEndedIterator<Item = LeapSecond> + Index<usize> {}
+/// A provider of leap seconds, e.g. the leap seconds built into hifitime or those of a leap seconds file.
+pub trait LeapSecondProvider {
+    /// Returns the leap seconds of this provider, which must be sorted chronologically.
+    fn leap_seconds(&self) -> &[LeapSecond];
+}
+
+impl<L: LeapSecondProvider + ?Sized> LeapSecondProvider for &L {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        (**self).leap_seconds()
+    }
+}
 
 /// A structure representing a leap second
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct LeapSecond {
-    /// Timestamp in TAI seconds for this leap second, e.g. `2_272_060_800.0` for the first IERS leap second.
-    pub timestamp_tai_s: f64,
+    /// Timestamp in TAI of this leap second as a duration since J1900, e.g. `2_272_060_800` seconds for the first IERS leap second.
+    pub timestamp_tai: Duration,
     /// ΔAT is the accumulated time offset after this leap second has past.
     pub delta_at: f64,
     /// Whether or not this leap second was announced by the IERS.
     pub announced_by_iers: bool,
+    /// Reference date in UTC MJD of the drift of ΔAT, as in the `drift` table of SOFA's `iauDat`. Only used for the 1960 to 1972 offsets.
+    pub drift_reference_mjd: f64,
+    /// Drift rate of ΔAT in seconds per UTC day since the reference date, or zero for the leap seconds announced by the IERS.
+    pub drift_rate: f64,
 }
 
 impl LeapSecond {
+    /// Builds a leap second from its timestamp in TAI seconds since J1900, rounded to the nanosecond.
+    ///
+    /// # Panics
+    /// If the timestamp is negative or beyond the range of a Duration, which fails the compilation of constant tables.
     pub const fn new(timestamp_tai_s: f64, delta_at: f64, announced: bool) -> Self {
+        assert!(timestamp_tai_s >= 0.0, "leap second timestamp before J1900");
+        let seconds = timestamp_tai_s as u64;
+        let nanoseconds = ((timestamp_tai_s - seconds as f64) * 1e9 + 0.5) as u64;
+        Self::from_tai_parts(seconds, nanoseconds, delta_at, announced)
+    }
+
+    /// Builds a leap second from its timestamp in whole TAI seconds since J1900, e.g. `2_272_060_800` for the first IERS leap second.
+    ///
+    /// # Panics
+    /// If the timestamp is beyond the range of a Duration, which fails the compilation of constant tables.
+    pub const fn from_tai_seconds(timestamp_tai_s: u64, delta_at: f64, announced: bool) -> Self {
+        Self::from_tai_parts(timestamp_tai_s, 0, delta_at, announced)
+    }
+
+    /// Builds a leap second from its timestamp in whole TAI seconds and nanoseconds (up to one second) since J1900.
+    const fn from_tai_parts(
+        seconds: u64,
+        nanoseconds: u64,
+        delta_at: f64,
+        announced: bool,
+    ) -> Self {
+        const SECONDS_PER_CENTURY: u64 = 36_525 * 86_400;
+        let mut centuries = seconds / SECONDS_PER_CENTURY;
+        let mut nanoseconds =
+            (seconds % SECONDS_PER_CENTURY) * NANOSECONDS_PER_SECOND + nanoseconds;
+        if nanoseconds >= NANOSECONDS_PER_CENTURY {
+            centuries += 1;
+            nanoseconds -= NANOSECONDS_PER_CENTURY;
+        }
+        assert!(
+            centuries <= i16::MAX as u64,
+            "leap second timestamp beyond the range of Duration"
+        );
         Self {
-            timestamp_tai_s,
+            timestamp_tai: Duration {
+                centuries: centuries as i16,
+                nanoseconds,
+            },
             delta_at,
             announced_by_iers: announced,
+            drift_reference_mjd: 0.0,
+            drift_rate: 0.0,
+        }
+    }
+
+    /// Sets the drift of ΔAT of this offset, i.e. ΔAT is `delta_at + (MJD - drift_reference_mjd) * drift_rate` where MJD is the UTC date.
+    /// This is how UTC was defined from 1960 to 1972, before the introduction of leap seconds.
+    pub const fn with_drift(mut self, drift_reference_mjd: f64, drift_rate: f64) -> Self {
+        self.drift_reference_mjd = drift_reference_mjd;
+        self.drift_rate = drift_rate;
+        self
+    }
+
+    /// Returns the timestamp in TAI seconds since J1900 of this leap second.
+    pub fn timestamp_tai_s(&self) -> f64 {
+        self.timestamp_tai.to_seconds()
+    }
+
+    /// Returns ΔAT, i.e. TAI - UTC in seconds, at the provided UTC duration since J1900, including the drift of ΔAT if any.
+    pub fn delta_at_utc(&self, utc_duration: Duration) -> f64 {
+        if self.drift_rate == 0.0 {
+            self.delta_at
+        } else {
+            self.delta_at + self.drift_days(utc_duration) * self.drift_rate
+        }
+    }
+
+    /// Returns ΔAT, i.e. TAI - UTC in seconds, at the provided TAI duration since J1900, including the drift of ΔAT if any.
+    pub fn delta_at_tai(&self, tai_duration: Duration) -> f64 {
+        if self.drift_rate == 0.0 {
+            self.delta_at
+        } else {
+            // ΔAT is defined at the UTC date, i.e. ΔAT = delta_at + (TAI - ΔAT - ref) * rate, hence:
+            (self.delta_at + self.drift_days(tai_duration) * self.drift_rate)
+                / (1.0 + self.drift_rate / SECONDS_PER_DAY)
         }
     }
+
+    /// Returns the number of days between the drift reference date and the provided duration since J1900.
+    fn drift_days(&self, duration: Duration) -> f64 {
+        (duration - (self.drift_reference_mjd - J1900_OFFSET) * Unit::Day).to_unit(Unit::Day)
+    }
 }
 
-const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
-    LeapSecond::new(1_893_369_600.0, 1.417818, false), // SOFA: 01 Jan 1960
-    LeapSecond::new(1_924_992_000.0, 1.422818, false), // SOFA: 01 Jan 1961
-    LeapSecond::new(1_943_308_800.0, 1.372818, false), // SOFA: 01 Aug 1961
-    LeapSecond::new(1_956_528_000.0, 1.845858, false), // SOFA: 01 Jan 1962
-    LeapSecond::new(2_014_329_600.0, 1.945858, false), // SOFA: 01 Jan 1963
-    LeapSecond::new(2_019_600_000.0, 3.24013, false),  // SOFA: 01 Jan 1964
-    LeapSecond::new(2_027_462_400.0, 3.34013, false),  // SOFA: 01 Apr 1964
-    LeapSecond::new(2_040_681_600.0, 3.44013, false),  // SOFA: 01 Sep 1964
-    LeapSecond::new(2_051_222_400.0, 3.54013, false),  // SOFA: 01 Jan 1965
-    LeapSecond::new(2_056_320_000.0, 3.64013, false),  // SOFA: 01 Mar 1965
-    LeapSecond::new(2_066_860_800.0, 3.74013, false),  // SOFA: 01 Jul 1965
-    LeapSecond::new(2_072_217_600.0, 3.84013, false),  // SOFA: 01 Sep 1965
-    LeapSecond::new(2_082_758_400.0, 4.31317, false),  // SOFA: 01 Jan 1966
-    LeapSecond::new(2_148_508_800.0, 4.21317, false),  // SOFA: 01 Feb 1968
-    LeapSecond::new(2_272_060_800.0, 10.0, true),      // IERS: 01 Jan 1972
-    LeapSecond::new(2_287_785_600.0, 11.0, true),      // IERS: 01 Jul 1972
-    LeapSecond::new(2_303_683_200.0, 12.0, true),      // IERS: 01 Jan 1973
-    LeapSecond::new(2_335_219_200.0, 13.0, true),      // IERS: 01 Jan 1974
-    LeapSecond::new(2_366_755_200.0, 14.0, true),      // IERS: 01 Jan 1975
-    LeapSecond::new(2_398_291_200.0, 15.0, true),      // IERS: 01 Jan 1976
-    LeapSecond::new(2_429_913_600.0, 16.0, true),      // IERS: 01 Jan 1977
-    LeapSecond::new(2_461_449_600.0, 17.0, true),      // IERS: 01 Jan 1978
-    LeapSecond::new(2_492_985_600.0, 18.0, true),      // IERS: 01 Jan 1979
-    LeapSecond::new(2_524_521_600.0, 19.0, true),      // IERS: 01 Jan 1980
-    LeapSecond::new(2_571_782_400.0, 20.0, true),      // IERS: 01 Jul 1981
-    LeapSecond::new(2_603_318_400.0, 21.0, true),      // IERS: 01 Jul 1982
-    LeapSecond::new(2_634_854_400.0, 22.0, true),      // IERS: 01 Jul 1983
-    LeapSecond::new(2_698_012_800.0, 23.0, true),      // IERS: 01 Jul 1985
-    LeapSecond::new(2_776_982_400.0, 24.0, true),      // IERS: 01 Jan 1988
-    LeapSecond::new(2_840_140_800.0, 25.0, true),      // IERS: 01 Jan 1990
-    LeapSecond::new(2_871_676_800.0, 26.0, true),      // IERS: 01 Jan 1991
-    LeapSecond::new(2_918_937_600.0, 27.0, true),      // IERS: 01 Jul 1992
-    LeapSecond::new(2_950_473_600.0, 28.0, true),      // IERS: 01 Jul 1993
-    LeapSecond::new(2_982_009_600.0, 29.0, true),      // IERS: 01 Jul 1994
-    LeapSecond::new(3_029_443_200.0, 30.0, true),      // IERS: 01 Jan 1996
-    LeapSecond::new(3_076_704_000.0, 31.0, true),      // IERS: 01 Jul 1997
-    LeapSecond::new(3_124_137_600.0, 32.0, true),      // IERS: 01 Jan 1999
-    LeapSecond::new(3_345_062_400.0, 33.0, true),      // IERS: 01 Jan 2006
-    LeapSecond::new(3_439_756_800.0, 34.0, true),      // IERS: 01 Jan 2009
-    LeapSecond::new(3_550_089_600.0, 35.0, true),      // IERS: 01 Jul 2012
-    LeapSecond::new(3_644_697_600.0, 36.0, true),      // IERS: 01 Jul 2015
-    LeapSecond::new(3_692_217_600.0, 37.0, true),      // IERS: 01 Jan 2017
+/// The models used by smeared clocks to spread a leap second over a window of time instead of inserting (or removing) it at once.
+///
+/// During the window, the smeared clock ticks slightly slower (or faster) than UTC such that it matches UTC again at the end of the window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum SmearModel {
+    /// Linear smear over the 24 hours from noon UTC before the leap second to noon UTC after it, as used by Google and AWS.
+    Linear24h,
+    /// UTC-SLS (Smoothed Leap Seconds): linear smear over the last 1000 seconds of UTC before the leap second.
+    UtcSls,
+}
+
+impl SmearModel {
+    /// Returns the UTC durations of the smear window before and after the UTC midnight at which the leap second occurs.
+    pub(crate) const fn window(&self) -> (Duration, Duration) {
+        const HALF_DAY: Duration = Duration {
+            centuries: 0,
+            nanoseconds: 43_200 * NANOSECONDS_PER_SECOND,
+        };
+        match self {
+            Self::Linear24h => (HALF_DAY, HALF_DAY),
+            Self::UtcSls => (
+                Duration {
+                    centuries: 0,
+                    nanoseconds: 1_000 * NANOSECONDS_PER_SECOND,
+                },
+                Duration::ZERO,
+            ),
+        }
+    }
+}
+
+static LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
+    LeapSecond::from_tai_seconds(1_893_369_600, 1.417818, false).with_drift(37300.0, 0.001296), // SOFA: 01 Jan 1960
+    LeapSecond::from_tai_seconds(1_924_992_000, 1.422818, false).with_drift(37300.0, 0.001296), // SOFA: 01 Jan 1961
+    LeapSecond::from_tai_seconds(1_943_308_800, 1.372818, false).with_drift(37300.0, 0.001296), // SOFA: 01 Aug 1961
+    LeapSecond::from_tai_seconds(1_956_528_000, 1.845858, false).with_drift(37665.0, 0.0011232), // SOFA: 01 Jan 1962
+    LeapSecond::from_tai_seconds(2_014_329_600, 1.945858, false).with_drift(37665.0, 0.0011232), // SOFA: 01 Nov 1963
+    LeapSecond::from_tai_seconds(2_019_600_000, 3.24013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Jan 1964
+    LeapSecond::from_tai_seconds(2_027_462_400, 3.34013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Apr 1964
+    LeapSecond::from_tai_seconds(2_040_681_600, 3.44013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Sep 1964
+    LeapSecond::from_tai_seconds(2_051_222_400, 3.54013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Jan 1965
+    LeapSecond::from_tai_seconds(2_056_320_000, 3.64013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Mar 1965
+    LeapSecond::from_tai_seconds(2_066_860_800, 3.74013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Jul 1965
+    LeapSecond::from_tai_seconds(2_072_217_600, 3.84013, false).with_drift(38761.0, 0.001296), // SOFA: 01 Sep 1965
+    LeapSecond::from_tai_seconds(2_082_758_400, 4.31317, false).with_drift(39126.0, 0.002592), // SOFA: 01 Jan 1966
+    LeapSecond::from_tai_seconds(2_148_508_800, 4.21317, false).with_drift(39126.0, 0.002592), // SOFA: 01 Feb 1968
+    LeapSecond::from_tai_seconds(2_272_060_800, 10.0, true), // IERS: 01 Jan 1972
+    LeapSecond::from_tai_seconds(2_287_785_600, 11.0, true), // IERS: 01 Jul 1972
+    LeapSecond::from_tai_seconds(2_303_683_200, 12.0, true), // IERS: 01 Jan 1973
+    LeapSecond::from_tai_seconds(2_335_219_200, 13.0, true), // IERS: 01 Jan 1974
+    LeapSecond::from_tai_seconds(2_366_755_200, 14.0, true), // IERS: 01 Jan 1975
+    LeapSecond::from_tai_seconds(2_398_291_200, 15.0, true), // IERS: 01 Jan 1976
+    LeapSecond::from_tai_seconds(2_429_913_600, 16.0, true), // IERS: 01 Jan 1977
+    LeapSecond::from_tai_seconds(2_461_449_600, 17.0, true), // IERS: 01 Jan 1978
+    LeapSecond::from_tai_seconds(2_492_985_600, 18.0, true), // IERS: 01 Jan 1979
+    LeapSecond::from_tai_seconds(2_524_521_600, 19.0, true), // IERS: 01 Jan 1980
+    LeapSecond::from_tai_seconds(2_571_782_400, 20.0, true), // IERS: 01 Jul 1981
+    LeapSecond::from_tai_seconds(2_603_318_400, 21.0, true), // IERS: 01 Jul 1982
+    LeapSecond::from_tai_seconds(2_634_854_400, 22.0, true), // IERS: 01 Jul 1983
+    LeapSecond::from_tai_seconds(2_698_012_800, 23.0, true), // IERS: 01 Jul 1985
+    LeapSecond::from_tai_seconds(2_776_982_400, 24.0, true), // IERS: 01 Jan 1988
+    LeapSecond::from_tai_seconds(2_840_140_800, 25.0, true), // IERS: 01 Jan 1990
+    LeapSecond::from_tai_seconds(2_871_676_800, 26.0, true), // IERS: 01 Jan 1991
+    LeapSecond::from_tai_seconds(2_918_937_600, 27.0, true), // IERS: 01 Jul 1992
+    LeapSecond::from_tai_seconds(2_950_473_600, 28.0, true), // IERS: 01 Jul 1993
+    LeapSecond::from_tai_seconds(2_982_009_600, 29.0, true), // IERS: 01 Jul 1994
+    LeapSecond::from_tai_seconds(3_029_443_200, 30.0, true), // IERS: 01 Jan 1996
+    LeapSecond::from_tai_seconds(3_076_704_000, 31.0, true), // IERS: 01 Jul 1997
+    LeapSecond::from_tai_seconds(3_124_137_600, 32.0, true), // IERS: 01 Jan 1999
+    LeapSecond::from_tai_seconds(3_345_062_400, 33.0, true), // IERS: 01 Jan 2006
+    LeapSecond::from_tai_seconds(3_439_756_800, 34.0, true), // IERS: 01 Jan 2009
+    LeapSecond::from_tai_seconds(3_550_089_600, 35.0, true), // IERS: 01 Jul 2012
+    LeapSecond::from_tai_seconds(3_644_697_600, 36.0, true), // IERS: 01 Jul 2015
+    LeapSecond::from_tai_seconds(3_692_217_600, 37.0, true), // IERS: 01 Jan 2017
 ];
 
 /// List of leap seconds from https://www.ietf.org/timezones/data/leap-seconds.list .
@@ -92,7 +227,7 @@ const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Clone, Debug)]
 pub struct LatestLeapSeconds {
-    data: [LeapSecond; 42],
+    data: &'static [LeapSecond],
     iter_pos: usize,
 }
 
@@ -112,7 +247,7 @@ impl LatestLeapSeconds {
 impl Default for LatestLeapSeconds {
     fn default() -> Self {
         Self {
-            data: LATEST_LEAP_SECONDS,
+            data: &LATEST_LEAP_SECONDS,
             iter_pos: 0,
         }
     }
@@ -146,7 +281,114 @@ impl Index<usize> for LatestLeapSeconds {
     }
 }
 
-impl LeapSecondProvider for LatestLeapSeconds {}
+impl LeapSecondProvider for LatestLeapSeconds {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        self.data
+    }
+}
+
+/// A table of leap seconds sorted chronologically, which may be registered with [register_leap_seconds] to be used by all UTC computations.
+///
+/// For example, this allows simulating hypothetical future leap seconds, including negative ones.
+/// ```
+/// use hifitime::leap_seconds::{register_leap_seconds, unregister_leap_seconds, LeapSecond, LeapSecondsTable};
+/// use hifitime::{Epoch, Unit};
+///
+/// // The 2017 leap second, followed by a hypothetical negative leap second on 2035 January 01.
+/// static TABLE: LeapSecondsTable = LeapSecondsTable::new(&[
+///     LeapSecond::from_tai_seconds(3_692_217_600, 37.0, true),
+///     LeapSecond::from_tai_seconds(4_260_211_200, 36.0, true),
+/// ]);
+///
+/// register_leap_seconds(&TABLE);
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2035, 1, 2);
+/// assert_eq!(epoch.to_tai_duration() - epoch.to_utc_duration(), 36 * Unit::Second);
+/// unregister_leap_seconds();
+/// assert_eq!(epoch.to_tai_duration() - epoch.to_utc_duration(), 37 * Unit::Second);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LeapSecondsTable {
+    data: &'static [LeapSecond],
+    iter_pos: usize,
+}
+
+impl LeapSecondsTable {
+    /// Builds a table from the provided leap seconds, which must be sorted chronologically.
+    pub const fn new(data: &'static [LeapSecond]) -> Self {
+        Self { data, iter_pos: 0 }
+    }
+
+    /// Collects the leap seconds of the provided provider into a table which lives until the end of the program, e.g. to register it.
+    ///
+    /// # Warning
+    /// The memory of the table is never freed, so this should only be called a handful of times.
+    #[cfg(feature = "std")]
+    pub fn leak<L: LeapSecondProvider>(provider: L) -> &'static Self {
+        let data: Vec<LeapSecond> = provider.leap_seconds().to_vec();
+        Box::leak(Box::new(Self::new(data.leak())))
+    }
+}
+
+impl Iterator for LeapSecondsTable {
+    type Item = LeapSecond;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter_pos += 1;
+        self.data.get(self.iter_pos - 1).copied()
+    }
+}
+
+impl DoubleEndedIterator for LeapSecondsTable {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter_pos == self.data.len() {
+            None
+        } else {
+            self.iter_pos += 1;
+            self.data.get(self.data.len() - self.iter_pos).copied()
+        }
+    }
+}
+
+impl Index<usize> for LeapSecondsTable {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl LeapSecondProvider for LeapSecondsTable {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        self.data
+    }
+}
+
+/// Leap seconds table used by all UTC computations instead of the latest leap seconds, if any.
+static REGISTERED_LEAP_SECONDS: AtomicPtr<LeapSecondsTable> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers the leap seconds table used by all UTC computations of this program instead of the latest leap seconds:
+/// initialization from and conversion to UTC, Gregorian dates in UTC, formatting and parsing.
+///
+/// # Warning
+/// This applies to all threads. Only `Epoch::leap_seconds_with` is unaffected, as it uses the provided leap seconds.
+pub fn register_leap_seconds(table: &'static LeapSecondsTable) {
+    REGISTERED_LEAP_SECONDS.store(
+        table as *const LeapSecondsTable as *mut LeapSecondsTable,
+        Ordering::Release,
+    );
+}
+
+/// Unregisters the leap seconds table, so that UTC computations use the latest leap seconds again.
+pub fn unregister_leap_seconds() {
+    REGISTERED_LEAP_SECONDS.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Returns the registered leap seconds table, if any.
+pub(crate) fn registered_leap_seconds() -> Option<&'static LeapSecondsTable> {
+    let table = REGISTERED_LEAP_SECONDS.load(Ordering::Acquire);
+    // SAFETY: only references with a static lifetime are registered, and the table is never mutated.
+    unsafe { table.as_ref() }
+}
 
 #[test]
 fn leap_second_fetch() {
@@ -154,10 +396,21 @@ fn leap_second_fetch() {
 
     assert_eq!(
         leap_seconds[0],
-        LeapSecond::new(1_893_369_600.0, 1.417818, false),
+        LeapSecond::new(1_893_369_600.0, 1.417818, false).with_drift(37300.0, 0.001296),
     );
     assert_eq!(
         leap_seconds[41],
         LeapSecond::new(3_692_217_600.0, 37.0, true)
     );
+    assert_eq!(
+        leap_seconds[41],
+        LeapSecond::from_tai_seconds(3_692_217_600, 37.0, true)
+    );
+    assert_eq!(leap_seconds[41].timestamp_tai_s(), 3_692_217_600.0);
+
+    // The lookup relies on a binary search, so the table must be sorted.
+    assert!(leap_seconds
+        .leap_seconds()
+        .windows(2)
+        .all(|pair| pair[0].timestamp_tai < pair[1].timestamp_tai));
 }
@@ -17,9 +17,20 @@ use core::ops::Index;
 
 use crate::{
     leap_seconds::{LeapSecond, LeapSecondProvider},
-    Errors, ParsingErrors,
+    Epoch, Errors, ParsingErrors,
 };
 
+/// How strictly the integrity of a leap seconds file is checked when it is loaded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LeapSecondsValidation {
+    /// The SHA-1 hash and the expiration date of the file must be present, the hash must match its data, and the file must not have expired at the reference epoch.
+    Strict,
+    /// The SHA-1 hash is only verified if present, and files which have expired or lack an expiration date are accepted:
+    /// use [LeapSecondsFile::is_expired_at] to check the expiration.
+    #[default]
+    Lenient,
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Clone, Debug, Default)]
@@ -27,11 +38,47 @@ use crate::{
 pub struct LeapSecondsFile {
     data: Vec<LeapSecond>,
     iter_pos: usize,
+    expires_at: Option<Epoch>,
 }
 
 impl LeapSecondsFile {
     /// Builds a leap second provider from the provided Leap Seconds file in IERS format as found on <https://www.ietf.org/timezones/data/leap-seconds.list> .
+    ///
+    /// The file is validated leniently: refer to [LeapSecondsFile::from_path_with] to reject expired files or files without a hash.
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Errors> {
+        Self::load(path, LeapSecondsValidation::Lenient)
+    }
+
+    /// Builds a leap second provider from the provided Leap Seconds file in IERS format, checking its integrity as requested.
+    /// In strict validation, the file must not have expired at the provided epoch, e.g. `Epoch::now()?`, which is unused in lenient validation.
+    ///
+    /// The SHA-1 hash (`#h` line) is computed over the update date (`#$` line), the expiration date (`#@` line), and the data lines,
+    /// excluding the comments and the white spaces.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::LeapSecondsFileCorrupted` if the hash does not match the data, or if it is missing in strict validation;
+    /// + `ParsingErrors::LeapSecondsFileExpirationMissing` if the expiration date is missing in strict validation;
+    /// + `ParsingErrors::LeapSecondsFileExpired` if the file has expired at the provided epoch in strict validation.
+    pub fn from_path_with<P: AsRef<Path>>(
+        path: P,
+        validation: LeapSecondsValidation,
+        at: Epoch,
+    ) -> Result<Self, Errors> {
+        let me = Self::load(path, validation)?;
+
+        if let Some(expires_at) = me.expires_at {
+            if validation == LeapSecondsValidation::Strict && me.is_expired_at(at) {
+                return Err(Errors::ParseError(ParsingErrors::LeapSecondsFileExpired(
+                    expires_at,
+                )));
+            }
+        }
+
+        Ok(me)
+    }
+
+    /// Loads the provided Leap Seconds file and checks its hash and expiration date as requested, but not whether it has expired.
+    fn load<P: AsRef<Path>>(path: P, validation: LeapSecondsValidation) -> Result<Self, Errors> {
         let mut f = match File::open(path) {
             Ok(f) => f,
             Err(e) => return Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
@@ -44,8 +91,36 @@ impl LeapSecondsFile {
 
         let mut me = Self::default();
 
+        // Data covered by the hash, without any white space.
+        let mut hashed_data = String::new();
+        let mut expires_at = None;
+        let mut hash = None;
+
         for line in contents.lines() {
-            if let Some(first_char) = line.chars().next() {
+            if let Some(updated_at) = line.strip_prefix("#$") {
+                hashed_data.extend(updated_at.split_whitespace());
+            } else if let Some(ntp_s) = line.strip_prefix("#@") {
+                let ntp_s = ntp_s.trim();
+                let expiration_ntp_s: u64 = match lexical_core::parse(ntp_s.as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+                };
+                // NTP timestamps are UTC seconds since 1900.
+                expires_at = Some(Epoch::from_utc_seconds(expiration_ntp_s as f64));
+                hashed_data.push_str(ntp_s);
+            } else if let Some(words) = line.strip_prefix("#h") {
+                let mut digest = [0_u32; 5];
+                let mut words = words.split_whitespace();
+                for word in digest.iter_mut() {
+                    *word = match words.next().map(|w| u32::from_str_radix(w, 16)) {
+                        Some(Ok(val)) => val,
+                        _ => {
+                            return Err(Errors::ParseError(ParsingErrors::LeapSecondsFileCorrupted))
+                        }
+                    };
+                }
+                hash = Some(digest);
+            } else if let Some(first_char) = line.chars().next() {
                 if first_char == '#' {
                     continue;
                 } else {
@@ -65,17 +140,109 @@ impl LeapSecondsFile {
                         Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
                     };
 
-                    me.data.push(LeapSecond {
-                        timestamp_tai_s: (timestamp_tai_s as f64),
-                        delta_at: (delta_at as f64),
-                        announced_by_iers: true,
-                    });
+                    // Trailing comments are not part of the hashed data.
+                    let values = line.split('#').next().unwrap_or_default();
+                    hashed_data.extend(values.split_whitespace());
+
+                    me.data.push(LeapSecond::from_tai_seconds(
+                        timestamp_tai_s,
+                        delta_at as f64,
+                        true,
+                    ));
                 }
             }
         }
 
+        if expires_at.is_none() && validation == LeapSecondsValidation::Strict {
+            return Err(Errors::ParseError(
+                ParsingErrors::LeapSecondsFileExpirationMissing,
+            ));
+        }
+        me.expires_at = expires_at;
+
+        match hash {
+            Some(hash) if hash != sha1(hashed_data.as_bytes()) => {
+                return Err(Errors::ParseError(ParsingErrors::LeapSecondsFileCorrupted))
+            }
+            None if validation == LeapSecondsValidation::Strict => {
+                return Err(Errors::ParseError(ParsingErrors::LeapSecondsFileCorrupted))
+            }
+            _ => {}
+        }
+
+        // Leap seconds are looked up with a binary search, so they must be sorted chronologically.
+        me.data.sort_by_key(|leap_second| leap_second.timestamp_tai);
+
         Ok(me)
     }
+
+    /// Returns the epoch at which this leap seconds file expires: a leap second may have been announced after that date.
+    /// Returns None if the file has no expiration date, which is only accepted in lenient validation.
+    pub fn expires_at(&self) -> Option<Epoch> {
+        self.expires_at
+    }
+
+    /// Returns whether this leap seconds file has expired at the provided epoch, which is never the case without an expiration date.
+    pub fn is_expired_at(&self, epoch: Epoch) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| epoch >= expires_at)
+    }
+}
+
+/// Computes the SHA-1 digest (FIPS 180-1) of the provided data, as five 32-bit words.
+fn sha1(data: &[u8]) -> [u32; 5] {
+    let mut state: [u32; 5] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+    ];
+
+    // Pad with a one bit, zeros, and the length in bits, to a multiple of 64 bytes.
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+        let mut w = [0_u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, wi) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*wi);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    state
 }
 
 #[cfg(feature = "python")]
@@ -89,6 +256,19 @@ impl LeapSecondsFile {
     fn __repr__(&self) -> String {
         format!("{self:?}")
     }
+
+    #[pyo3(name = "expires_at")]
+    /// Returns the epoch at which this leap seconds file expires: a leap second may have been announced after that date.
+    /// Returns None if the file has no expiration date, which is only accepted in lenient validation.
+    fn py_expires_at(&self) -> Option<Epoch> {
+        self.expires_at()
+    }
+
+    #[pyo3(name = "is_expired_at")]
+    /// Returns whether this leap seconds file has expired at the provided epoch.
+    fn py_is_expired_at(&self, epoch: Epoch) -> bool {
+        self.is_expired_at(epoch)
+    }
 }
 
 impl Iterator for LeapSecondsFile {
@@ -119,7 +299,11 @@ impl Index<usize> for LeapSecondsFile {
     }
 }
 
-impl LeapSecondProvider for LeapSecondsFile {}
+impl LeapSecondProvider for LeapSecondsFile {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.data
+    }
+}
 
 #[test]
 fn leap_second_fetch() {
@@ -143,8 +327,31 @@ fn leap_second_fetch() {
         LeapSecond::new(3_692_217_600.0, 37.0, true)
     );
 
+    // File expires on:  28 June 2023
+    assert_eq!(
+        leap_seconds.expires_at(),
+        Some(crate::Epoch::from_gregorian_utc_at_midnight(2023, 6, 28))
+    );
+
     for (lsi, leap_second) in leap_seconds.enumerate() {
         // The index offset is because the latest leap seconds include those not announced by the IERS, but the IERS file does not.
         assert_eq!(leap_second, latest_leap_seconds[lsi + 14]);
     }
 }
+
+#[test]
+fn sha1_digest() {
+    // Test vectors of FIPS 180-1
+    assert_eq!(
+        sha1(b"abc"),
+        [0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d]
+    );
+    assert_eq!(
+        sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        [0x84983e44, 0x1c3bd26e, 0xbaae4aa1, 0xf95129e5, 0xe54670f1]
+    );
+    assert_eq!(
+        sha1(b""),
+        [0xda39a3ee, 0x5e6b4b0d, 0x3255bfef, 0x95601890, 0xafd80709]
+    );
+}
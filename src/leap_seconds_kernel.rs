@@ -0,0 +1,333 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use std::{fs::File, io::Read, path::Path};
+
+use core::ops::Index;
+use core::str::FromStr;
+
+use crate::{
+    leap_seconds::{LeapSecond, LeapSecondProvider},
+    DeltetConstants, Epoch, Errors, MonthName, ParsingErrors,
+};
+
+#[repr(C)]
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug, Default)]
+/// A leap second provider that uses a NAIF leap second kernel (LSK), e.g. `naif0012.txt`.
+///
+/// The kernel also defines the `DELTET` constants used to compute Ephemeris Time, which are available with [LeapSecondsKernel::deltet].
+pub struct LeapSecondsKernel {
+    data: Vec<LeapSecond>,
+    iter_pos: usize,
+    deltet: DeltetConstants,
+}
+
+impl LeapSecondsKernel {
+    /// Builds a leap second provider from the provided NAIF leap second kernel, as found on <https://naif.jpl.nasa.gov/pub/naif/generic_kernels/lsk/> .
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Errors> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => return Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(Errors::ParseError(ParsingErrors::IOError(e.kind())));
+        }
+
+        Self::from_kernel_data(&contents)
+    }
+
+    /// Builds a leap second provider from the contents of a NAIF leap second kernel.
+    ///
+    /// Only the `\begindata` sections of the text kernel are read, and these must define `DELTET/DELTA_T_A`, `DELTET/K`, `DELTET/EB`, `DELTET/M`
+    /// and `DELTET/DELTA_AT`. The latter lists the pairs of the accumulated leap seconds and the date at which they apply, e.g. `37, @2017-JAN-1`.
+    pub fn from_kernel_data(contents: &str) -> Result<Self, Errors> {
+        let variables = parse_variables(contents)?;
+        let variable = |name: &str| -> Result<&[&str], Errors> {
+            match variables.iter().find(|(var_name, _)| *var_name == name) {
+                Some((_, values)) => Ok(values),
+                None => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+            }
+        };
+        let scalar = |name: &str| -> Result<f64, Errors> {
+            match variable(name)? {
+                [value] => parse_number(value),
+                _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+            }
+        };
+
+        let (m0, m1) = match variable("DELTET/M")? {
+            [m0, m1] => (parse_number(m0)?, parse_number(m1)?),
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+
+        let mut me = Self {
+            deltet: DeltetConstants {
+                delta_t_a: scalar("DELTET/DELTA_T_A")?,
+                k: scalar("DELTET/K")?,
+                eb: scalar("DELTET/EB")?,
+                m0,
+                m1,
+            },
+            ..Default::default()
+        };
+
+        let delta_at = variable("DELTET/DELTA_AT")?;
+        if delta_at.len() % 2 != 0 {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+
+        for pair in delta_at.chunks(2) {
+            me.data.push(LeapSecond {
+                timestamp_tai: parse_date(pair[1])?.to_tai_duration(),
+                delta_at: parse_number(pair[0])?,
+                announced_by_iers: true,
+                drift_reference_mjd: 0.0,
+                drift_rate: 0.0,
+            });
+        }
+        // Leap seconds are looked up with a binary search, so they must be sorted chronologically.
+        me.data.sort_by_key(|leap_second| leap_second.timestamp_tai);
+
+        Ok(me)
+    }
+
+    /// Returns the `DELTET` constants of this kernel, to be used with [Epoch::to_et_duration_with] and [Epoch::from_et_duration_with].
+    pub fn deltet(&self) -> DeltetConstants {
+        self.deltet
+    }
+}
+
+/// Returns the name and values of each variable assigned in the data sections of the provided text kernel, in order.
+/// Values added with `+=` are appended to the previous values of that variable.
+fn parse_variables(contents: &str) -> Result<Vec<(&str, Vec<&str>)>, Errors> {
+    let mut in_data = false;
+    let mut tokens = Vec::new();
+    for line in contents.lines() {
+        match line.trim() {
+            "\\begindata" => in_data = true,
+            "\\begintext" => in_data = false,
+            _ if in_data => {
+                // Parentheses and assignments may not be separated from the names and values.
+                let mut token_start = None;
+                for (idx, c) in line.char_indices() {
+                    let separator = c.is_whitespace() || c == ',';
+                    let delimiter = c == '(' || c == ')' || c == '=';
+                    if separator || delimiter {
+                        if let Some(start) = token_start.take() {
+                            tokens.push(&line[start..idx]);
+                        }
+                        if delimiter {
+                            tokens.push(&line[idx..idx + 1]);
+                        }
+                    } else if token_start.is_none() {
+                        token_start = Some(idx);
+                    }
+                }
+                if let Some(start) = token_start {
+                    tokens.push(&line[start..]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut variables: Vec<(&str, Vec<&str>)> = Vec::new();
+    let mut tokens = tokens.into_iter();
+    while let Some(mut name) = tokens.next() {
+        // The `+` of `+=` is attached to the name when not separated by a space.
+        let append = match name.strip_suffix('+') {
+            Some(stripped) => {
+                name = stripped;
+                true
+            }
+            None => false,
+        };
+        let append = match (tokens.next(), append) {
+            (Some("+"), false) => matches!(tokens.next(), Some("=")),
+            (Some("="), append) => append,
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+
+        let mut values = Vec::new();
+        match tokens.next() {
+            Some("(") => loop {
+                match tokens.next() {
+                    Some(")") => break,
+                    Some(value) => values.push(value),
+                    None => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                }
+            },
+            Some(value) => values.push(value),
+            None => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        }
+
+        match variables.iter_mut().find(|(var_name, _)| *var_name == name) {
+            Some((_, prev_values)) if append => prev_values.extend(values),
+            Some((_, prev_values)) => *prev_values = values,
+            None => variables.push((name, values)),
+        }
+    }
+
+    Ok(variables)
+}
+
+/// Parses a number of a text kernel, whose exponent may be written with a `D` as in Fortran, e.g. `1.657D-3`.
+fn parse_number(value: &str) -> Result<f64, Errors> {
+    let value = value.replace(['D', 'd'], "E");
+    match lexical_core::parse(value.as_bytes()) {
+        Ok(val) => Ok(val),
+        Err(_) => Err(Errors::ParseError(ParsingErrors::ValueError)),
+    }
+}
+
+/// Parses a date literal of a leap second kernel, e.g. `@1972-JAN-1`, as the TAI epoch of that date at midnight.
+fn parse_date(value: &str) -> Result<Epoch, Errors> {
+    let date = match value.strip_prefix('@') {
+        Some(date) => date,
+        None => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+    };
+
+    let mut parts = date.split('-');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(year), Some(month), Some(day), None) => {
+            let year: i32 = match lexical_core::parse(year.as_bytes()) {
+                Ok(val) => val,
+                Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+            };
+            let month = MonthName::from_str(month).map_err(Errors::ParseError)? as u8 + 1;
+            let day: u8 = match lexical_core::parse(day.as_bytes()) {
+                Ok(val) => val,
+                Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+            };
+            Epoch::maybe_from_gregorian_tai(year, month, day, 0, 0, 0, 0)
+        }
+        _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl LeapSecondsKernel {
+    #[new]
+    pub fn __new__(path: String) -> Result<Self, Errors> {
+        Self::from_path(&path)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl Iterator for LeapSecondsKernel {
+    type Item = LeapSecond;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter_pos += 1;
+        self.data.get(self.iter_pos - 1).copied()
+    }
+}
+
+impl DoubleEndedIterator for LeapSecondsKernel {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter_pos == self.data.len() {
+            None
+        } else {
+            self.iter_pos += 1;
+            self.data.get(self.data.len() - self.iter_pos).copied()
+        }
+    }
+}
+
+impl Index<usize> for LeapSecondsKernel {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl LeapSecondProvider for LeapSecondsKernel {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.data
+    }
+}
+
+#[test]
+fn leap_seconds_kernel() {
+    use crate::leap_seconds::LatestLeapSeconds;
+    use std::env;
+    use std::path::PathBuf;
+    let latest_leap_seconds = LatestLeapSeconds::default();
+
+    // Load the NAIF kernel
+    let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("naif0012.txt");
+    let kernel = LeapSecondsKernel::from_path(path).unwrap();
+
+    assert_eq!(kernel.deltet(), DeltetConstants::default());
+    assert_eq!(kernel[0], LeapSecond::new(2_272_060_800.0, 10.0, true));
+
+    let mut count = 0;
+    for (lsi, leap_second) in kernel.enumerate() {
+        // The kernel only includes the leap seconds announced by the IERS.
+        assert_eq!(leap_second, latest_leap_seconds[lsi + 14]);
+        count += 1;
+    }
+    assert_eq!(count, 28);
+
+    // Assignments may be appended to and the delimiters may be attached to the values.
+    let kernel = LeapSecondsKernel::from_kernel_data(
+        "Comments are ignored, even DELTET/K = 1
+\\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K=(1.658D-3)
+DELTET/EB = 1.671d-2
+DELTET/M = (6.239996D0 1.99096871D-7)
+DELTET/DELTA_AT = (10, @1972-JAN-1)
+DELTET/DELTA_AT += (11, @1972-JUL-1)
+\\begintext",
+    )
+    .unwrap();
+    assert_eq!(kernel.deltet().k, 1.658e-3);
+    assert_eq!(kernel[1], LeapSecond::new(2_287_785_600.0, 11.0, true));
+
+    // The leap seconds are sorted chronologically.
+    let kernel = LeapSecondsKernel::from_kernel_data(
+        "\\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K = 1.657D-3
+DELTET/EB = 1.671D-2
+DELTET/M = (6.239996D0 1.99096871D-7)
+DELTET/DELTA_AT = (11, @1972-JUL-1, 10, @1972-JAN-1)",
+    )
+    .unwrap();
+    assert_eq!(kernel[0], LeapSecond::new(2_272_060_800.0, 10.0, true));
+    assert_eq!(kernel[1], LeapSecond::new(2_287_785_600.0, 11.0, true));
+
+    // Missing constants or malformed dates are rejected.
+    assert!(LeapSecondsKernel::from_kernel_data(
+        "\\begindata\nDELTET/DELTA_AT = (10, @1972-JAN-1)"
+    )
+    .is_err());
+    assert!(LeapSecondsKernel::from_kernel_data(
+        "\\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K = 1.657D-3
+DELTET/EB = 1.671D-2
+DELTET/M = (6.239996D0 1.99096871D-7)
+DELTET/DELTA_AT = (10, @1972-JNA-1)"
+    )
+    .is_err());
+}
@@ -88,6 +88,8 @@ pub const J2000_REF_EPOCH_TDB: Epoch = Epoch {
 pub mod efmt;
 mod parser;
 
+pub mod ccsds;
+
 pub mod errors;
 pub use errors::{Errors, ParsingErrors};
 
@@ -113,11 +115,25 @@ pub use weekday::*;
 mod month;
 pub use month::*;
 
+mod calendar;
+pub use calendar::*;
+
+mod interval;
+pub use interval::*;
+
 pub mod leap_seconds;
 
 #[cfg(feature = "std")]
 mod leap_seconds_file;
 
+#[cfg(feature = "std")]
+mod timezone;
+#[cfg(feature = "std")]
+pub use timezone::*;
+
+#[cfg(feature = "std")]
+mod leap_seconds_kernel;
+
 #[cfg(feature = "ut1")]
 pub mod ut1;
 
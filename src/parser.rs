@@ -23,12 +23,16 @@ pub(crate) enum Token {
     OffsetMinutes,
     Timescale,
     DayOfYearInteger,
+    OrdinalDay,
     DayOfYear,
     Weekday,
     WeekdayShort,
     WeekdayDecimal,
     MonthName,
     MonthNameShort,
+    TimeZoneAbbreviation,
+    TimeZoneName,
+    Tai64n,
 }
 
 impl Default for Token {
@@ -92,13 +96,28 @@ impl Token {
                     Ok(())
                 }
             }
+            Self::OrdinalDay => {
+                if !(1..=366).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                } else {
+                    Ok(())
+                }
+            }
             Self::WeekdayDecimal => {
-                Ok(()) // We modulo it anyway
+                // C89 counts from Sunday as zero, and ISO 8601 uses seven for Sunday: we modulo it anyway.
+                if !(0..=7).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                } else {
+                    Ok(())
+                }
             }
             Self::Weekday
             | Self::WeekdayShort
             | Self::MonthName
             | Self::MonthNameShort
+            | Self::TimeZoneAbbreviation
+            | Self::TimeZoneName
+            | Self::Tai64n
             | Self::DayOfYear => {
                 // These cannot be parsed as integers
                 Err(Errors::ParseError(ParsingErrors::ValueError))
@@ -221,6 +240,9 @@ impl Token {
                 | Token::WeekdayShort
                 | Token::MonthName
                 | Token::MonthNameShort
+                | Token::TimeZoneAbbreviation
+                | Token::TimeZoneName
+                | Token::Tai64n
         )
     }
 }
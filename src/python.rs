@@ -11,8 +11,9 @@
 use pyo3::{exceptions::PyException, prelude::*};
 
 use crate::prelude::*;
+use crate::{CalendarDuration, Interval, IntervalSet, MonthEndPolicy};
 
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondsFile};
+use crate::leap_seconds::{LatestLeapSeconds, LeapSecondsFile, LeapSecondsKernel, SmearModel};
 
 use crate::ut1::Ut1Provider;
 
@@ -31,6 +32,12 @@ fn hifitime(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Unit>()?;
     m.add_class::<LatestLeapSeconds>()?;
     m.add_class::<LeapSecondsFile>()?;
+    m.add_class::<LeapSecondsKernel>()?;
+    m.add_class::<SmearModel>()?;
+    m.add_class::<CalendarDuration>()?;
+    m.add_class::<MonthEndPolicy>()?;
+    m.add_class::<Interval>()?;
+    m.add_class::<IntervalSet>()?;
     m.add_class::<Ut1Provider>()?;
     Ok(())
 }
@@ -57,12 +57,18 @@ pub const BDT_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
 pub const SECONDS_BDT_TAI_OFFSET: f64 = 3_345_062_433.0;
 pub const SECONDS_BDT_TAI_OFFSET_I64: i64 = 3_345_062_433;
 
-/// The UNIX reference epoch of 1970-01-01 in TAI duration, accounting only for IERS leap seconds.
+/// The UNIX reference epoch of 1970-01-01 at midnight UTC in TAI duration, when TAI - UTC was 8.000082 seconds as computed by SOFA's `iauDat`.
 pub const UNIX_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
     centuries: 0,
-    nanoseconds: 2_208_988_800_000_000_000,
+    nanoseconds: 2_208_988_808_000_082_000,
 });
 
+/// The UNIX reference epoch of 1970-01-01 at midnight UTC as a UTC duration since J1900, which does not depend on the leap seconds in use.
+pub const UNIX_REF_EPOCH_UTC_DURATION: Duration = Duration {
+    centuries: 0,
+    nanoseconds: 2_208_988_800_000_000_000,
+};
+
 /// Enum of the different time systems available
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "python", pyclass)]
@@ -84,6 +90,13 @@ pub enum TimeScale {
     GST,
     /// BeiDou Time scale
     BDT,
+    /// Barycentric Coordinate Time (TCB), the coordinate time of the Barycentric Celestial Reference System (IAU 2006 Resolution B3)
+    TCB,
+    /// Geocentric Coordinate Time (TCG), the coordinate time of the Geocentric Celestial Reference System (IAU 2000 Resolution B1.9)
+    TCG,
+    /// Universal Time (UT1), the rotation angle of the Earth, computed from the registered UT1 source (cf. the `ut1` module).
+    /// If no UT1 source is registered, or if it does not cover the epoch, UT1 is assumed to be equal to UTC, which is within 0.9 s of UT1.
+    UT1,
 }
 
 #[cfg(kani)]
@@ -112,7 +125,14 @@ impl TimeScale {
     pub(crate) const fn formatted_len(&self) -> usize {
         match &self {
             Self::GPST => 4,
-            Self::TAI | Self::TDB | Self::UTC | Self::GST | Self::BDT => 3,
+            Self::TAI
+            | Self::TDB
+            | Self::UTC
+            | Self::GST
+            | Self::BDT
+            | Self::TCB
+            | Self::TCG
+            | Self::UT1 => 3,
             Self::ET | Self::TT => 2,
         }
     }
@@ -129,9 +149,10 @@ impl TimeScale {
             Self::GST => GST_REF_EPOCH,
             Self::BDT => BDT_REF_EPOCH,
             Self::ET => J2000_REF_EPOCH_ET,
-            Self::TDB => J2000_REF_EPOCH_TDB,
+            // TCB is expressed with respect to J2000, like TDB which it is linearly related to.
+            Self::TDB | Self::TCB => J2000_REF_EPOCH_TDB,
             // Explicit on purpose in case more time scales end up being supported.
-            Self::TT | Self::TAI | Self::UTC => J1900_REF_EPOCH,
+            Self::TT | Self::TAI | Self::UTC | Self::TCG | Self::UT1 => J1900_REF_EPOCH,
         }
     }
 }
@@ -148,6 +169,9 @@ impl fmt::Display for TimeScale {
             Self::GPST => write!(f, "GPST"),
             Self::GST => write!(f, "GST"),
             Self::BDT => write!(f, "BDT"),
+            Self::TCB => write!(f, "TCB"),
+            Self::TCG => write!(f, "TCG"),
+            Self::UT1 => write!(f, "UT1"),
         }
     }
 }
@@ -174,7 +198,7 @@ impl TimeScale {
 }
 
 /// Allows conversion of a TimeSystem into a u8
-/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7;
+/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7; TCB: 8; TCG: 9; UT1: 10;
 impl From<TimeScale> for u8 {
     fn from(ts: TimeScale) -> Self {
         match ts {
@@ -186,12 +210,15 @@ impl From<TimeScale> for u8 {
             TimeScale::GPST => 5,
             TimeScale::GST => 6,
             TimeScale::BDT => 7,
+            TimeScale::TCB => 8,
+            TimeScale::TCG => 9,
+            TimeScale::UT1 => 10,
         }
     }
 }
 
 /// Allows conversion of a u8 into a TimeSystem.
-/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; anything else: TAI
+/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; 8: TCB; 9: TCG; 10: UT1; anything else: TAI
 impl From<u8> for TimeScale {
     fn from(val: u8) -> Self {
         match val {
@@ -202,6 +229,9 @@ impl From<u8> for TimeScale {
             5 => Self::GPST,
             6 => Self::GST,
             7 => Self::BDT,
+            8 => Self::TCB,
+            9 => Self::TCG,
+            10 => Self::UT1,
             _ => Self::TAI,
         }
     }
@@ -228,6 +258,12 @@ impl FromStr for TimeScale {
             Ok(Self::GST)
         } else if val == "BDT" || val == "BDS" {
             Ok(Self::BDT)
+        } else if val == "TCB" {
+            Ok(Self::TCB)
+        } else if val == "TCG" {
+            Ok(Self::TCG)
+        } else if val == "UT1" {
+            Ok(Self::UT1)
         } else {
             Err(Errors::ParseError(ParsingErrors::TimeSystem))
         }
@@ -250,7 +286,7 @@ fn test_ts() {
         let ts = TimeScale::from(ts_u8);
         let ts_u8_back: u8 = ts.into();
         // If the u8 is greater than 5, it isn't valid and necessarily encoded as TAI.
-        if ts_u8 < 8 {
+        if ts_u8 < 11 {
             assert_eq!(ts_u8_back, ts_u8, "got {ts_u8_back} want {ts_u8}");
         } else {
             assert_eq!(ts, TimeScale::TAI);
@@ -12,11 +12,11 @@ use super::{Duration, Epoch};
 
 use core::fmt;
 
-#[cfg(not(feature = "std"))]
-use num_traits::Float;
-
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+
+#[cfg(feature = "python")]
+use pyo3::types::PyType;
 /*
 
 NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/itertools_num/linspace.rs.html#78-93 .
@@ -24,13 +24,22 @@ NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/iter
 */
 
 /// An iterator of a sequence of evenly spaced Epochs.
+///
+/// The offset of each Epoch from the start is computed in integer nanoseconds from its index, so there is no accumulated drift,
+/// and the series can be iterated from either end, has an exact length, and can be indexed in constant time with `nth`.
+/// The step may be negative, in which case the end should be before the start.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "python", pyclass)]
 pub struct TimeSeries {
     start: Epoch,
     duration: Duration,
     step: Duration,
-    cur: i64,
+    /// Number of intervals between the start and the end of a linspace, or zero if the Epochs are spaced by the step.
+    intervals: i128,
+    /// Index of the next Epoch returned from the front
+    front: i128,
+    /// Index after the next Epoch returned from the back
+    back: i128,
     incl: bool,
 }
 
@@ -51,14 +60,7 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn exclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
-        // Start one step prior to start because next() just moves forward
-        Self {
-            start,
-            duration: end - start,
-            step,
-            cur: 0,
-            incl: false,
-        }
+        Self::stepped(start, end, step, false)
     }
 
     /// Return an iterator of evenly spaced Epochs, inclusive on start **and** on end.
@@ -77,15 +79,92 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn inclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
-        // Start one step prior to start because next() just moves forward
+        Self::stepped(start, end, step, true)
+    }
+
+    /// Return an iterator of `num` evenly spaced Epochs from start to end, where the first Epoch is exactly start and the last is exactly end.
+    /// If `num` is one, only the start epoch is returned, and if it is zero, the iterator is empty.
+    /// ```
+    /// use hifitime::{Epoch, Unit, TimeSeries};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let end = start + Unit::Second * 1;
+    /// // One third of a second is not an integer number of nanoseconds.
+    /// let time_series = TimeSeries::linspace(start, end, 4);
+    /// assert_eq!(time_series.len(), 4);
+    /// let epochs: Vec<Epoch> = time_series.collect();
+    /// assert_eq!(epochs[0], start);
+    /// assert_eq!(epochs[1], start + Unit::Nanosecond * 333_333_333);
+    /// assert_eq!(epochs[2], start + Unit::Nanosecond * 666_666_666);
+    /// assert_eq!(epochs[3], end);
+    /// ```
+    pub fn linspace(start: Epoch, end: Epoch, num: usize) -> TimeSeries {
+        let duration = Self::duration_between(start, end);
+        let intervals = (num as i128 - 1).max(1);
         Self {
             start,
-            duration: end - start,
-            step,
-            cur: 0,
+            duration,
+            step: Duration::from_total_nanoseconds(duration.total_nanoseconds() / intervals),
+            intervals,
+            front: 0,
+            back: num as i128,
             incl: true,
         }
     }
+
+    /// Returns the step between consecutive Epochs, which is rounded down to the nanosecond for a linspace.
+    pub const fn step(&self) -> Duration {
+        self.step
+    }
+
+    fn stepped(start: Epoch, end: Epoch, step: Duration, incl: bool) -> Self {
+        let duration = Self::duration_between(start, end);
+        let duration_ns = duration.total_nanoseconds();
+        let step_ns = step.total_nanoseconds();
+
+        let len = if duration_ns == 0 {
+            i128::from(incl)
+        } else if duration_ns.signum() != step_ns.signum() {
+            // The end can never be reached.
+            0
+        } else {
+            let (quotient, remainder) = (duration_ns / step_ns, duration_ns % step_ns);
+            if incl {
+                quotient + 1
+            } else {
+                quotient + i128::from(remainder != 0)
+            }
+        };
+
+        Self {
+            start,
+            duration,
+            step,
+            intervals: 0,
+            front: 0,
+            back: len,
+            incl,
+        }
+    }
+
+    /// Epochs are stepped in the time scale of the start epoch, so that is where the duration is measured.
+    fn duration_between(start: Epoch, end: Epoch) -> Duration {
+        end.to_duration_in_time_scale(start.time_scale) - start.to_duration()
+    }
+
+    /// Returns the Epoch at the provided index from the start of the series
+    fn epoch_at(&self, index: i128) -> Epoch {
+        let offset_ns = if self.intervals == 0 {
+            self.step.total_nanoseconds() * index
+        } else {
+            self.duration.total_nanoseconds() * index / self.intervals
+        };
+        self.start + Duration::from_total_nanoseconds(offset_ns)
+    }
+
+    /// Returns the number of remaining Epochs, which may not fit in a usize.
+    fn remaining(&self) -> i128 {
+        self.back - self.front
+    }
 }
 
 impl fmt::Display for TimeSeries {
@@ -222,10 +301,21 @@ impl TimeSeries {
         }
     }
 
+    #[classmethod]
+    #[pyo3(name = "linspace")]
+    /// Return an iterator of `num` evenly spaced Epochs from start to end, where the first Epoch is exactly start and the last is exactly end.
+    fn linspace_py(_cls: &PyType, start: Epoch, end: Epoch, num: usize) -> Self {
+        Self::linspace(start, end, num)
+    }
+
     fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
 
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
     fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Epoch> {
         slf.next()
     }
@@ -244,59 +334,63 @@ impl Iterator for TimeSeries {
 
     #[inline]
     fn next(&mut self) -> Option<Epoch> {
-        let next_offset = self.cur * self.step;
-        if (!self.incl && next_offset >= self.duration)
-            || (self.incl && next_offset > self.duration)
-        {
-            None
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.epoch_at(self.front - 1))
         } else {
-            self.cur += 1;
-            Some(self.start + next_offset)
+            None
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.len(), Some(self.len() + 1))
+        match usize::try_from(self.remaining()) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Epoch> {
+        self.front = (self.front + n as i128).min(self.back);
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Epoch> {
+        self.next_back()
     }
 }
 
 impl DoubleEndedIterator for TimeSeries {
     #[inline]
     fn next_back(&mut self) -> Option<Epoch> {
-        // Offset from the end of the iterator
-        self.cur += 1;
-        let offset = self.cur * self.step;
-        // if offset < -self.duration - self.step {
-        if (!self.incl && offset > self.duration)
-            || (self.incl && offset > self.duration + self.step)
-        {
-            None
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.epoch_at(self.back))
         } else {
-            Some(self.start + self.duration - offset)
+            None
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Epoch> {
+        self.back = (self.back - n as i128).max(self.front);
+        self.next_back()
+    }
 }
 
-impl ExactSizeIterator for TimeSeries
-where
-    TimeSeries: Iterator,
-{
+impl ExactSizeIterator for TimeSeries {
+    /// Returns the number of remaining Epochs, saturating at usize::MAX
     fn len(&self) -> usize {
-        let approx = (self.duration.to_seconds() / self.step.to_seconds()).abs();
-        if self.incl {
-            if approx.ceil() >= usize::MAX as f64 {
-                usize::MAX
-            } else {
-                approx.ceil() as usize
-            }
-        } else if approx.floor() >= usize::MAX as f64 {
-            usize::MAX
-        } else {
-            approx.floor() as usize
-        }
+        usize::try_from(self.remaining()).unwrap_or(usize::MAX)
     }
 }
 
+impl core::iter::FusedIterator for TimeSeries {}
+
 #[cfg(test)]
 mod tests {
     use crate::{Epoch, TimeSeries, Unit};
@@ -351,13 +445,13 @@ mod tests {
         let steps = 1_000_000_000;
         let end = start + steps * step; // This is 500 ms later
         let times = TimeSeries::exclusive(start, end, step);
-        // For an _exclusive_ time series, we skip the last item, so it's steps minus one
-        assert_eq!(times.len(), steps as usize - 1);
+        // For an _exclusive_ time series, we skip the end, so it's the steps count
+        assert_eq!(times.len(), steps as usize);
         assert_eq!(times.len(), times.size_hint().0);
 
-        // For an _inclusive_ time series, we skip the last item, so it's the steps count
+        // For an _inclusive_ time series, we also include the end, so it's one more than the steps count
         let times = TimeSeries::inclusive(start, end, step);
-        assert_eq!(times.len(), steps as usize);
+        assert_eq!(times.len(), steps as usize + 1);
         assert_eq!(times.len(), times.size_hint().0);
     }
 
@@ -246,20 +246,9 @@ impl Mul<i64> for Unit {
         };
 
         match q.checked_mul(factor) {
-            Some(total_ns) => {
-                if total_ns.abs() < i64::MAX {
-                    Duration::from_truncated_nanoseconds(total_ns)
-                } else {
-                    Duration::from_total_nanoseconds(i128::from(total_ns))
-                }
-            }
-            None => {
-                if q.is_negative() {
-                    Duration::MIN
-                } else {
-                    Duration::MAX
-                }
-            }
+            Some(total_ns) => Duration::from_truncated_nanoseconds(total_ns),
+            // Beyond the range of an i64, which is about 2.9 centuries, but not necessarily beyond the range of a Duration.
+            None => Duration::from_total_nanoseconds(i128::from(q) * i128::from(factor)),
         }
     }
 }
@@ -0,0 +1,944 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::{
+    env, fs,
+    path::{Component, Path, PathBuf},
+};
+
+use core::fmt;
+
+use crate::efmt::consts::RFC3339_ZONED;
+use crate::efmt::{Format, ZonedFormatter};
+use crate::epoch::{civil_from_days, days_from_civil, days_in_month, is_leap_year};
+use crate::{
+    Duration, Epoch, Errors, ParsingErrors, TimeScale, Unit, Weekday, SECONDS_PER_DAY_I64,
+    UNIX_REF_EPOCH_UTC_DURATION,
+};
+
+/// Directory of the IANA time zone database on most Unix systems, used unless the `TZDIR` environment variable is set.
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// Number of days from 1900 January 01 to the UNIX epoch 1970 January 01.
+const UNIX_EPOCH_DAYS: i64 = days_from_civil(1970, 1, 1);
+
+/// Defines how a local wall-clock time is resolved when the offset of the time zone changes around it.
+///
+/// When the clocks are set back (e.g. at the end of daylight saving time), a local time occurs twice and is ambiguous.
+/// When the clocks are set forward (e.g. at the start of daylight saving time), a local time is skipped and does not exist.
+/// In both cases, the local time can be read with the offset before or after the change, which gives two instants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocalTimePolicy {
+    /// Use the earliest instant, e.g. the first occurrence of an ambiguous time (still in daylight saving time),
+    /// and a skipped time is moved back by the length of the gap.
+    Earliest,
+    /// Use the latest instant, e.g. the second occurrence of an ambiguous time (back in standard time),
+    /// and a skipped time is moved forward by the length of the gap.
+    Latest,
+    /// Use the earliest instant of an ambiguous time, and move a skipped time forward by the length of the gap.
+    /// This is what RFC 5545 (iCalendar) and most calendar software do.
+    Compatible,
+    /// Return `ParsingErrors::AmbiguousLocalTime` or `ParsingErrors::NonexistentLocalTime`.
+    Reject,
+}
+
+/// A local time type of a time zone, e.g. Mountain Daylight Time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalTimeType {
+    /// Offset from UTC, positive east of Greenwich
+    pub offset: Duration,
+    /// Whether this is daylight saving time
+    pub is_dst: bool,
+    /// Abbreviation of this local time, e.g. `MDT`
+    pub abbreviation: String,
+}
+
+impl LocalTimeType {
+    fn new(offset_s: i64, is_dst: bool, abbreviation: &str) -> Self {
+        Self {
+            offset: offset_s * Unit::Second,
+            is_dst,
+            abbreviation: abbreviation.to_string(),
+        }
+    }
+
+    /// Returns the offset in whole seconds.
+    fn offset_s(&self) -> i64 {
+        unix_seconds_of(self.offset)
+    }
+}
+
+/// A time zone, defined by its changes of offset from UTC (e.g. daylight saving time) as in the IANA time zone database.
+///
+/// Time zones are read from TZif files (RFC 8536), either from the time zone database of the system (e.g. `/usr/share/zoneinfo/America/Denver`),
+/// or from a snapshot of the database embedded in the program with `include_bytes!`. The rule of the TZif file is used after its last transition.
+/// Only the usual TZif files are supported, not those which count leap seconds (e.g. from `/usr/share/zoneinfo/right`).
+///
+/// # Example
+/// ```
+/// use hifitime::prelude::*;
+/// use hifitime::{LocalTimePolicy, TimeZone};
+///
+/// let denver = TimeZone::from_tzif(
+///     "America/Denver",
+///     include_bytes!("../data/tzif/America/Denver"),
+/// )
+/// .unwrap();
+///
+/// let epoch = Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0);
+/// let local = epoch.to_local(&denver);
+/// assert_eq!(local.abbreviation(), "MDT");
+/// assert_eq!(local.offset(), -6 * Unit::Hour);
+/// assert_eq!(format!("{local}"), "2023-07-04T12:00:00-06:00 MDT");
+///
+/// // 01:30 occurs twice on 2023 November 05, when the clocks are set back from 02:00 MDT to 01:00 MST.
+/// let wall_clock = Epoch::from_gregorian_utc_hms(2023, 11, 5, 1, 30, 0);
+/// assert_eq!(
+///     denver.resolve(wall_clock, LocalTimePolicy::Earliest).unwrap(),
+///     Epoch::from_gregorian_utc_hms(2023, 11, 5, 7, 30, 0)
+/// );
+/// assert_eq!(
+///     denver.resolve(wall_clock, LocalTimePolicy::Latest).unwrap(),
+///     Epoch::from_gregorian_utc_hms(2023, 11, 5, 8, 30, 0)
+/// );
+/// assert!(denver.resolve(wall_clock, LocalTimePolicy::Reject).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeZone {
+    name: String,
+    /// UNIX seconds of each transition, in increasing order
+    transitions: Vec<i64>,
+    /// Index of the local time type after each transition
+    transition_types: Vec<usize>,
+    /// Local time types, where the first one is used before the first transition
+    types: Vec<LocalTimeType>,
+    /// Rule used after the last transition
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// Coordinated Universal Time.
+    pub fn utc() -> Self {
+        Self::fixed("UTC", Duration::ZERO)
+    }
+
+    /// Builds a time zone with a fixed offset from UTC, whose abbreviation is its name.
+    pub fn fixed(name: &str, offset: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            transitions: Vec::new(),
+            transition_types: Vec::new(),
+            types: vec![LocalTimeType {
+                offset,
+                is_dst: false,
+                abbreviation: name.to_string(),
+            }],
+            rule: None,
+        }
+    }
+
+    /// Builds a time zone from a POSIX `TZ` rule, e.g. `MST7MDT,M3.2.0,M11.1.0`, which is also its name.
+    ///
+    /// Note that the offsets of POSIX rules are positive _west_ of Greenwich: `MST7` is seven hours behind UTC.
+    pub fn from_posix_rule(rule: &str) -> Result<Self, Errors> {
+        let rule = PosixRule::parse(rule).map_err(Errors::ParseError)?;
+        Ok(Self {
+            name: rule.to_string(),
+            transitions: Vec::new(),
+            transition_types: Vec::new(),
+            types: vec![rule.std.clone()],
+            rule: Some(rule),
+        })
+    }
+
+    /// Builds a time zone from the provided TZif data (RFC 8536), e.g. embedded with `include_bytes!`, and names it as provided.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::TimeZoneFileCorrupted` if the data is truncated or invalid;
+    /// + `ParsingErrors::UnsupportedTimeSystem` if the data counts leap seconds.
+    pub fn from_tzif(name: &str, data: &[u8]) -> Result<Self, Errors> {
+        let mut reader = TzifReader { data };
+        let mut header = reader.header()?;
+        let mut time_size = 4;
+        if header.version >= b'2' {
+            // Skip the version 1 data, which only supports 32 bit times.
+            reader.take(header.data_len(time_size))?;
+            header = reader.header()?;
+            time_size = 8;
+        }
+
+        if header.typecnt == 0
+            || header.charcnt == 0
+            || (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+            || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+        {
+            return Err(corrupted());
+        }
+
+        if header.leapcnt > 0 {
+            return Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem));
+        }
+
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let bytes = reader.take(time_size)?;
+            transitions.push(if time_size == 8 {
+                i64::from_be_bytes(bytes.try_into().unwrap())
+            } else {
+                i64::from(i32::from_be_bytes(bytes.try_into().unwrap()))
+            });
+        }
+
+        if transitions.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(corrupted());
+        }
+
+        let transition_types = reader
+            .take(header.timecnt)?
+            .iter()
+            .map(|&idx| usize::from(idx))
+            .collect::<Vec<usize>>();
+
+        if transition_types.iter().any(|&idx| idx >= header.typecnt) {
+            return Err(corrupted());
+        }
+
+        let mut raw_types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let bytes = reader.take(6)?;
+            let offset_s = i32::from_be_bytes(bytes[..4].try_into().unwrap());
+            if offset_s == i32::MIN {
+                return Err(corrupted());
+            }
+            raw_types.push((offset_s, bytes[4] != 0, usize::from(bytes[5])));
+        }
+
+        let designations = reader.take(header.charcnt)?;
+        let mut types = Vec::with_capacity(header.typecnt);
+        for (offset_s, is_dst, idx) in raw_types {
+            // Each abbreviation is a NUL terminated string in the designations.
+            let abbreviation = designations
+                .get(idx..)
+                .and_then(|chars| chars.split(|&c| c == 0).next())
+                .and_then(|chars| core::str::from_utf8(chars).ok())
+                .ok_or_else(corrupted)?;
+            types.push(LocalTimeType::new(
+                i64::from(offset_s),
+                is_dst,
+                abbreviation,
+            ));
+        }
+
+        // Skip the standard/wall and UT/local indicators, which are only needed for the rule of the version 1 data.
+        reader.take(header.isstdcnt + header.isutcnt)?;
+
+        let rule = if header.version >= b'2' {
+            // The footer is a POSIX TZ rule between two new lines, which may be empty.
+            let footer = reader.data;
+            if footer.len() < 2 || footer[0] != b'\n' || footer[footer.len() - 1] != b'\n' {
+                return Err(corrupted());
+            }
+            match core::str::from_utf8(&footer[1..footer.len() - 1]) {
+                Ok("") => None,
+                Ok(rule) => Some(PosixRule::parse(rule).map_err(|_| corrupted())?),
+                Err(_) => return Err(corrupted()),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            transitions,
+            transition_types,
+            types,
+            rule,
+        })
+    }
+
+    /// Builds a time zone from the TZif file at the provided path, and names it as provided.
+    pub fn from_path<P: AsRef<Path>>(name: &str, path: P) -> Result<Self, Errors> {
+        match fs::read(path) {
+            Ok(data) => Self::from_tzif(name, &data),
+            Err(e) => Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
+        }
+    }
+
+    /// Builds the time zone of the provided name (e.g. `America/Denver`) from the time zone database of the system,
+    /// which is in the directory of the `TZDIR` environment variable if set, or in `/usr/share/zoneinfo`.
+    pub fn from_zoneinfo(name: &str) -> Result<Self, Errors> {
+        match env::var_os("TZDIR") {
+            Some(dir) => Self::from_zoneinfo_dir(dir, name),
+            None => Self::from_zoneinfo_dir(ZONEINFO_DIR, name),
+        }
+    }
+
+    /// Builds the time zone of the provided name (e.g. `America/Denver`) from the time zone database in the provided directory.
+    ///
+    /// # Errors
+    /// + `ParsingErrors::UnknownTimeZone` if the name is not a relative path within the directory;
+    /// + `ParsingErrors::IOError` if the file of the time zone cannot be read.
+    pub fn from_zoneinfo_dir<P: AsRef<Path>>(dir: P, name: &str) -> Result<Self, Errors> {
+        let relative = Path::new(name);
+        if name.is_empty()
+            || relative
+                .components()
+                .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            return Err(Errors::ParseError(ParsingErrors::UnknownTimeZone));
+        }
+        let path: PathBuf = dir.as_ref().join(relative);
+        Self::from_path(name, path)
+    }
+
+    /// Returns the name of this time zone, e.g. `America/Denver`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the local time type in use at the provided epoch.
+    pub fn local_time_type(&self, epoch: Epoch) -> &LocalTimeType {
+        self.local_time_type_at(unix_seconds(epoch))
+    }
+
+    /// Returns the offset from UTC at the provided epoch, positive east of Greenwich.
+    pub fn offset(&self, epoch: Epoch) -> Duration {
+        self.local_time_type(epoch).offset
+    }
+
+    /// Returns the epoch of the provided local wall-clock time in this time zone, where the policy resolves ambiguous and skipped local times.
+    ///
+    /// The wall-clock time is provided as the UTC epoch of the same Gregorian date and time, e.g. `Epoch::from_gregorian_utc_hms(2023, 11, 5, 1, 30, 0)`
+    /// for 01:30 local time on 2023 November 05. Changes of offset less than a day apart are not supported.
+    ///
+    /// # Errors
+    /// `ParsingErrors::AmbiguousLocalTime` or `ParsingErrors::NonexistentLocalTime` if the policy rejects the local time.
+    pub fn resolve(&self, wall_clock: Epoch, policy: LocalTimePolicy) -> Result<Epoch, Errors> {
+        let [before, after] = self.candidates(wall_clock);
+        let (earliest, latest) = if before.0 <= after.0 {
+            (before.0, after.0)
+        } else {
+            (after.0, before.0)
+        };
+
+        match (before.2, after.2) {
+            (true, false) => Ok(before.0),
+            (false, true) => Ok(after.0),
+            (true, true) if earliest == latest => Ok(earliest),
+            (true, true) => match policy {
+                LocalTimePolicy::Earliest | LocalTimePolicy::Compatible => Ok(earliest),
+                LocalTimePolicy::Latest => Ok(latest),
+                LocalTimePolicy::Reject => {
+                    Err(Errors::ParseError(ParsingErrors::AmbiguousLocalTime))
+                }
+            },
+            (false, false) => match policy {
+                LocalTimePolicy::Earliest => Ok(earliest),
+                LocalTimePolicy::Latest | LocalTimePolicy::Compatible => Ok(latest),
+                LocalTimePolicy::Reject => {
+                    Err(Errors::ParseError(ParsingErrors::NonexistentLocalTime))
+                }
+            },
+        }
+    }
+
+    /// Returns the epoch of the provided local wall-clock time in this time zone, where the abbreviation of the local time type resolves ambiguous and skipped local times.
+    pub(crate) fn resolve_abbreviated(
+        &self,
+        wall_clock: Epoch,
+        abbreviation: &str,
+    ) -> Result<Epoch, Errors> {
+        self.candidates(wall_clock)
+            .iter()
+            .find(|(_, local_time_type, _)| {
+                local_time_type
+                    .abbreviation
+                    .eq_ignore_ascii_case(abbreviation)
+            })
+            .map(|(epoch, _, _)| *epoch)
+            .ok_or(Errors::ParseError(ParsingErrors::UnknownTimeZone))
+    }
+
+    /// Returns the epochs of the wall-clock time read with the local time types in use a day before and a day after it,
+    /// with these local time types and whether each epoch is indeed in that local time type.
+    fn candidates(&self, wall_clock: Epoch) -> [(Epoch, &LocalTimeType, bool); 2] {
+        let wall_clock_s = unix_seconds(wall_clock);
+        [
+            self.local_time_type_at(wall_clock_s - SECONDS_PER_DAY_I64),
+            self.local_time_type_at(wall_clock_s + SECONDS_PER_DAY_I64),
+        ]
+        .map(|local_time_type| {
+            let epoch = wall_clock - local_time_type.offset;
+            let valid = self.local_time_type(epoch).offset == local_time_type.offset;
+            (epoch, local_time_type, valid)
+        })
+    }
+
+    /// Returns the local time type in use at the provided UNIX seconds.
+    fn local_time_type_at(&self, unix_s: i64) -> &LocalTimeType {
+        match (self.transitions.last(), &self.rule) {
+            (Some(&last), Some(rule)) if unix_s > last => rule.local_time_type_at(unix_s),
+            (None, Some(rule)) => rule.local_time_type_at(unix_s),
+            _ => match self.transitions.partition_point(|&at| at <= unix_s) {
+                0 => &self.types[0],
+                idx => &self.types[self.transition_types[idx - 1]],
+            },
+        }
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// An epoch in a time zone, which provides its local wall-clock time. This is built by `Epoch::to_local`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalEpoch {
+    epoch: Epoch,
+    local_time_type: LocalTimeType,
+    zone: String,
+}
+
+impl LocalEpoch {
+    pub(crate) fn new(epoch: Epoch, time_zone: &TimeZone) -> Self {
+        Self {
+            epoch,
+            local_time_type: time_zone.local_time_type(epoch).clone(),
+            zone: time_zone.name.clone(),
+        }
+    }
+
+    /// Returns the epoch, in its original time scale.
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the offset of the local time from UTC, positive east of Greenwich.
+    pub fn offset(&self) -> Duration {
+        self.local_time_type.offset
+    }
+
+    /// Returns whether the local time is daylight saving time.
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type.is_dst
+    }
+
+    /// Returns the abbreviation of the local time, e.g. `MDT`.
+    pub fn abbreviation(&self) -> &str {
+        &self.local_time_type.abbreviation
+    }
+
+    /// Returns the name of the time zone, e.g. `America/Denver`.
+    pub fn zone_name(&self) -> &str {
+        &self.zone
+    }
+
+    /// Returns the local wall-clock time as the UTC epoch of the same Gregorian date and time, which is the input of `TimeZone::resolve`.
+    pub fn wall_clock(&self) -> Epoch {
+        self.epoch.in_time_scale(TimeScale::UTC) + self.offset()
+    }
+
+    /// Returns the local Gregorian date and time as (year, month, day, hour, minute, second, nanoseconds).
+    pub fn to_gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.wall_clock().to_gregorian_utc()
+    }
+
+    /// Returns the local weekday.
+    pub fn weekday(&self) -> Weekday {
+        self.wall_clock().weekday_utc()
+    }
+
+    /// Formats the local time with the provided format, where `%z` is the offset, `%Z` the abbreviation and `%K` the name of the time zone.
+    pub fn format(&self, format: Format) -> ZonedFormatter<'_> {
+        ZonedFormatter::new(
+            self.epoch.in_time_scale(TimeScale::UTC),
+            self.offset(),
+            self.abbreviation(),
+            self.zone_name(),
+            format,
+        )
+    }
+}
+
+impl fmt::Display for LocalEpoch {
+    /// Prints the local time as `2023-07-04T12:00:00-06:00 MDT`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format(RFC3339_ZONED))
+    }
+}
+
+/// Returns the whole UNIX seconds of the provided epoch, rounded down.
+fn unix_seconds(epoch: Epoch) -> i64 {
+    unix_seconds_of(epoch.to_utc_duration() - UNIX_REF_EPOCH_UTC_DURATION)
+}
+
+/// Returns the whole seconds of the provided duration, rounded down.
+fn unix_seconds_of(duration: Duration) -> i64 {
+    duration
+        .total_nanoseconds()
+        .div_euclid(i128::from(crate::NANOSECONDS_PER_SECOND)) as i64
+}
+
+fn corrupted() -> Errors {
+    Errors::ParseError(ParsingErrors::TimeZoneFileCorrupted)
+}
+
+/// The counts of the header of TZif data.
+struct TzifHeader {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl TzifHeader {
+    /// Returns the length of the data following this header, for the provided size of times.
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct TzifReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TzifReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Errors> {
+        if self.data.len() < len {
+            return Err(corrupted());
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn count(&mut self) -> Result<usize, Errors> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+    }
+
+    fn header(&mut self) -> Result<TzifHeader, Errors> {
+        if self.take(4)? != b"TZif" {
+            return Err(corrupted());
+        }
+        let version = self.take(1)?[0];
+        // Unused
+        self.take(15)?;
+        Ok(TzifHeader {
+            version,
+            isutcnt: self.count()?,
+            isstdcnt: self.count()?,
+            leapcnt: self.count()?,
+            timecnt: self.count()?,
+            typecnt: self.count()?,
+            charcnt: self.count()?,
+        })
+    }
+}
+
+/// A day of the year in a POSIX TZ rule.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RuleDay {
+    /// `Jn`: day of the year in [1, 365], where February 29 is never counted
+    Julian(u16),
+    /// `n`: zero based day of the year in [0, 365], where February 29 is counted
+    ZeroBased(u16),
+    /// `Mm.w.d`: weekday `d` (Sunday is zero) of week `w` in [1, 5] of month `m`, where week 5 is the last one of the month
+    MonthWeekday { month: u8, week: u8, weekday: u8 },
+}
+
+impl RuleDay {
+    /// Returns the number of days from the UNIX epoch to this day of the provided year.
+    fn unix_day(self, year: i32) -> i64 {
+        let january_first = days_from_civil(year, 1, 1) - UNIX_EPOCH_DAYS;
+        match self {
+            Self::Julian(day) => {
+                let leap_day = i64::from(is_leap_year(year) && day >= 60);
+                january_first + i64::from(day) - 1 + leap_day
+            }
+            Self::ZeroBased(day) => january_first + i64::from(day),
+            Self::MonthWeekday {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1) - UNIX_EPOCH_DAYS;
+                let last = first + i64::from(days_in_month(year, month)) - 1;
+                // 1970 January 01 was a Thursday, and Sunday is zero.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                while day > last {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+impl fmt::Display for RuleDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Julian(day) => write!(f, "J{day}"),
+            Self::ZeroBased(day) => write!(f, "{day}"),
+            Self::MonthWeekday {
+                month,
+                week,
+                weekday,
+            } => write!(f, "M{month}.{week}.{weekday}"),
+        }
+    }
+}
+
+/// The daylight saving time of a POSIX TZ rule, which starts and ends at the provided local times in seconds of the rule days.
+#[derive(Clone, Debug, PartialEq)]
+struct DstRule {
+    dst: LocalTimeType,
+    start: (RuleDay, i64),
+    end: (RuleDay, i64),
+}
+
+/// A POSIX TZ rule, as found in the footer of TZif files, e.g. `MST7MDT,M3.2.0,M11.1.0`.
+#[derive(Clone, Debug, PartialEq)]
+struct PosixRule {
+    std: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+impl PosixRule {
+    fn parse(rule: &str) -> Result<Self, ParsingErrors> {
+        let mut cursor = PosixCursor {
+            bytes: rule.as_bytes(),
+            pos: 0,
+        };
+
+        // Offsets are positive west of Greenwich.
+        let std_name = cursor.name()?;
+        let std_offset_s = -cursor.time()?;
+        let std = LocalTimeType::new(std_offset_s, false, std_name);
+        if cursor.is_done() {
+            return Ok(Self { std, dst: None });
+        }
+
+        let dst_name = cursor.name()?;
+        let dst_offset_s = match cursor.peek() {
+            None | Some(b',') => std_offset_s + 3600,
+            Some(_) => -cursor.time()?,
+        };
+        let dst = LocalTimeType::new(dst_offset_s, true, dst_name);
+
+        let (start, end) = if cursor.is_done() {
+            // The rule of the United States, which POSIX implementations use by default.
+            (
+                (
+                    RuleDay::MonthWeekday {
+                        month: 3,
+                        week: 2,
+                        weekday: 0,
+                    },
+                    7200,
+                ),
+                (
+                    RuleDay::MonthWeekday {
+                        month: 11,
+                        week: 1,
+                        weekday: 0,
+                    },
+                    7200,
+                ),
+            )
+        } else {
+            cursor.expect(b',')?;
+            let start = cursor.rule_day()?;
+            cursor.expect(b',')?;
+            let end = cursor.rule_day()?;
+            (start, end)
+        };
+
+        if !cursor.is_done() {
+            return Err(ParsingErrors::ValueError);
+        }
+
+        Ok(Self {
+            std,
+            dst: Some(DstRule { dst, start, end }),
+        })
+    }
+
+    /// Returns the local time type of this rule at the provided UNIX seconds.
+    fn local_time_type_at(&self, unix_s: i64) -> &LocalTimeType {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return &self.std,
+        };
+
+        let std_offset_s = self.std.offset_s();
+        let (year, _, _) = civil_from_days(
+            (unix_s + std_offset_s).div_euclid(SECONDS_PER_DAY_I64) + UNIX_EPOCH_DAYS,
+        );
+
+        // Daylight saving time starts in standard time and ends in daylight saving time.
+        let start = dst.start.0.unix_day(year) * SECONDS_PER_DAY_I64 + dst.start.1 - std_offset_s;
+        let end = dst.end.0.unix_day(year) * SECONDS_PER_DAY_I64 + dst.end.1 - dst.dst.offset_s();
+
+        let is_dst = if start <= end {
+            start <= unix_s && unix_s < end
+        } else {
+            // Southern hemisphere, where daylight saving time spans the new year.
+            unix_s < end || start <= unix_s
+        };
+
+        if is_dst {
+            &dst.dst
+        } else {
+            &self.std
+        }
+    }
+}
+
+impl fmt::Display for PosixRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let write_name = |f: &mut fmt::Formatter, name: &str| -> fmt::Result {
+            if name.chars().all(|c| c.is_ascii_alphabetic()) {
+                write!(f, "{name}")
+            } else {
+                write!(f, "<{name}>")
+            }
+        };
+        let write_time = |f: &mut fmt::Formatter, seconds: i64| -> fmt::Result {
+            if seconds < 0 {
+                write!(f, "-")?;
+            }
+            let seconds = seconds.abs();
+            write!(f, "{}", seconds / 3600)?;
+            if seconds % 3600 != 0 {
+                write!(f, ":{:02}", (seconds % 3600) / 60)?;
+                if seconds % 60 != 0 {
+                    write!(f, ":{:02}", seconds % 60)?;
+                }
+            }
+            Ok(())
+        };
+
+        write_name(f, &self.std.abbreviation)?;
+        write_time(f, -self.std.offset_s())?;
+        if let Some(dst) = &self.dst {
+            write_name(f, &dst.dst.abbreviation)?;
+            if dst.dst.offset_s() != self.std.offset_s() + 3600 {
+                write_time(f, -dst.dst.offset_s())?;
+            }
+            for (day, time) in [dst.start, dst.end] {
+                write!(f, ",{day}")?;
+                if time != 7200 {
+                    write!(f, "/")?;
+                    write_time(f, time)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct PosixCursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PosixCursor<'a> {
+    fn is_done(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParsingErrors> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(ParsingErrors::ValueError)
+        }
+    }
+
+    /// Reads while the predicate holds, and returns what was read.
+    fn read_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.pos += 1;
+        }
+        // Only ASCII is read, so this is a valid string.
+        core::str::from_utf8(&self.bytes[start..self.pos]).unwrap()
+    }
+
+    /// Reads an abbreviation, which is either alphabetic, or quoted as in `<+0530>`.
+    fn name(&mut self) -> Result<&'a str, ParsingErrors> {
+        let name = if self.peek() == Some(b'<') {
+            self.pos += 1;
+            let name = self.read_while(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'-');
+            self.expect(b'>')?;
+            name
+        } else {
+            self.read_while(|c| c.is_ascii_alphabetic())
+        };
+
+        if name.len() < 3 {
+            Err(ParsingErrors::ValueError)
+        } else {
+            Ok(name)
+        }
+    }
+
+    fn number(&mut self) -> Result<i64, ParsingErrors> {
+        let digits = self.read_while(|c| c.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return Err(ParsingErrors::ValueError);
+        }
+        digits.parse().map_err(|_| ParsingErrors::ValueError)
+    }
+
+    /// Reads a signed time as `[+-]hh[:mm[:ss]]` in seconds, where the hours are up to 167 as in RFC 8536.
+    fn time(&mut self) -> Result<i64, ParsingErrors> {
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.pos += 1;
+                1
+            }
+            _ => 1,
+        };
+
+        let hours = self.number()?;
+        let mut minutes = 0;
+        let mut seconds = 0;
+        if self.peek() == Some(b':') {
+            self.pos += 1;
+            minutes = self.number()?;
+            if self.peek() == Some(b':') {
+                self.pos += 1;
+                seconds = self.number()?;
+            }
+        }
+
+        if hours > 167 || minutes > 59 || seconds > 59 {
+            return Err(ParsingErrors::ValueError);
+        }
+
+        Ok(sign * (hours * 3600 + minutes * 60 + seconds))
+    }
+
+    /// Reads a rule day and its optional local time, which defaults to 02:00:00.
+    fn rule_day(&mut self) -> Result<(RuleDay, i64), ParsingErrors> {
+        let day = match self.peek() {
+            Some(b'J') => {
+                self.pos += 1;
+                match self.number()? {
+                    day @ 1..=365 => RuleDay::Julian(day as u16),
+                    _ => return Err(ParsingErrors::ValueError),
+                }
+            }
+            Some(b'M') => {
+                self.pos += 1;
+                let month = self.number()?;
+                self.expect(b'.')?;
+                let week = self.number()?;
+                self.expect(b'.')?;
+                let weekday = self.number()?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                    return Err(ParsingErrors::ValueError);
+                }
+                RuleDay::MonthWeekday {
+                    month: month as u8,
+                    week: week as u8,
+                    weekday: weekday as u8,
+                }
+            }
+            _ => match self.number()? {
+                day @ 0..=365 => RuleDay::ZeroBased(day as u16),
+                _ => return Err(ParsingErrors::ValueError),
+            },
+        };
+
+        let time = if self.peek() == Some(b'/') {
+            self.pos += 1;
+            self.time()?
+        } else {
+            7200
+        };
+
+        Ok((day, time))
+    }
+}
+
+#[test]
+fn test_posix_rule() {
+    for rule in [
+        "MST7MDT,M3.2.0,M11.1.0",
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+        "<+0530>-5:30",
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+        "IST-1GMT0,M10.5.0,M3.5.0/1",
+        "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1",
+        "EST5EDT,0/0,J365/25",
+    ] {
+        assert_eq!(PosixRule::parse(rule).unwrap().to_string(), rule);
+    }
+
+    // Default rules of daylight saving time
+    let rule = PosixRule::parse("EST5EDT").unwrap();
+    assert_eq!(rule.to_string(), "EST5EDT,M3.2.0,M11.1.0");
+
+    for rule in [
+        "",
+        "ES5",
+        "EST",
+        "EST5EDT,M3.2.0",
+        "EST5EDT,M13.2.0,M11.1.0",
+        "EST5,",
+    ] {
+        assert!(PosixRule::parse(rule).is_err(), "{rule}");
+    }
+
+    // Second Sunday of March 2023 is March 12, and first Sunday of November 2023 is November 5
+    let day = |year, month, day| days_from_civil(year, month, day) - UNIX_EPOCH_DAYS;
+    let march = RuleDay::MonthWeekday {
+        month: 3,
+        week: 2,
+        weekday: 0,
+    };
+    assert_eq!(march.unix_day(2023), day(2023, 3, 12));
+    // Last Sunday of October 2023 is October 29
+    let october = RuleDay::MonthWeekday {
+        month: 10,
+        week: 5,
+        weekday: 0,
+    };
+    assert_eq!(october.unix_day(2023), day(2023, 10, 29));
+    // J60 is always March 01, and 59 is February 29 in leap years
+    assert_eq!(RuleDay::Julian(60).unix_day(2024), day(2024, 3, 1));
+    assert_eq!(RuleDay::Julian(60).unix_day(2023), day(2023, 3, 1));
+    assert_eq!(RuleDay::ZeroBased(59).unix_day(2024), day(2024, 2, 29));
+}
@@ -11,77 +11,197 @@
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ut1-download")]
 use reqwest::{blocking::get, StatusCode};
 
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
+use std::sync::{Arc, RwLock};
 use std::{fs::File, io::Read};
 
 use core::fmt;
 use core::ops::Index;
+use core::str::FromStr;
 
 use crate::{Duration, Epoch, Errors, ParsingErrors, Unit};
 
+/// Base URL of the JPL Earth Orientation Parameters (EOP2) files.
+pub const JPL_EOP2_URL: &str = "https://eop2-external.jpl.nasa.gov/eop2/";
+
+/// A source of the offset between TAI and UT1, e.g. a table of Earth Orientation Parameters.
+///
+/// A source may either be provided explicitly to the UT1 conversion functions of `Epoch` (e.g. `Epoch::to_ut1_duration`),
+/// or registered with `register_ut1_source` to be used whenever an Epoch is converted to or from `TimeScale::UT1`,
+/// including when formatting and parsing.
+pub trait Ut1Source {
+    /// Returns the offset TAI - UT1 at the provided epoch, or None if this epoch is not covered by this source.
+    fn delta_tai_minus_ut1(&self, epoch: &Epoch) -> Option<Duration>;
+}
+
+/// The UT1 source used for all conversions to and from `TimeScale::UT1`.
+static REGISTERED_UT1_SOURCE: RwLock<Option<Arc<dyn Ut1Source + Send + Sync>>> = RwLock::new(None);
+
+/// Registers the provided UT1 source for all conversions to and from `TimeScale::UT1`, returning the previously registered source, if any.
+pub fn register_ut1_source<S: Ut1Source + Send + Sync + 'static>(
+    source: S,
+) -> Option<Arc<dyn Ut1Source + Send + Sync>> {
+    let mut registered = REGISTERED_UT1_SOURCE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.replace(Arc::new(source))
+}
+
+/// Unregisters the UT1 source, returning it if there was one. Conversions to and from `TimeScale::UT1` will then assume that UT1 is equal to UTC.
+pub fn unregister_ut1_source() -> Option<Arc<dyn Ut1Source + Send + Sync>> {
+    let mut registered = REGISTERED_UT1_SOURCE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.take()
+}
+
+/// Returns TAI - UT1 at the provided epoch from the registered UT1 source, if any.
+pub(crate) fn registered_delta_tai_minus_ut1(epoch: &Epoch) -> Option<Duration> {
+    let registered = REGISTERED_UT1_SOURCE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.as_ref()?.delta_tai_minus_ut1(epoch)
+}
+
+/// A record of Earth Orientation Parameters: the TAI-UT1 offset, and when available in the source data, the polar motion,
+/// the excess length of day and the celestial pole offsets.
 #[derive(Copy, Clone, Debug, Default, Tabled)]
 pub struct DeltaTaiUt1 {
     pub epoch: Epoch,
     pub delta_tai_minus_ut1: Duration,
+    /// Whether this value is a prediction (e.g. from IERS Bulletin A) instead of an observation.
+    pub predicted: bool,
+    /// Polar motion x_p, in arcseconds
+    #[tabled(display_with = "display_option")]
+    pub x_p: Option<f64>,
+    /// Polar motion y_p, in arcseconds
+    #[tabled(display_with = "display_option")]
+    pub y_p: Option<f64>,
+    /// Excess length of day (LOD), i.e. the difference between the duration of the day and 86400 SI seconds
+    #[tabled(display_with = "display_option")]
+    pub lod: Option<Duration>,
+    /// Celestial pole offset dX with respect to the IAU 2006/2000A precession-nutation model, in arcseconds
+    #[tabled(display_with = "display_option")]
+    pub dx: Option<f64>,
+    /// Celestial pole offset dY with respect to the IAU 2006/2000A precession-nutation model, in arcseconds
+    #[tabled(display_with = "display_option")]
+    pub dy: Option<f64>,
+}
+
+fn display_option<T: fmt::Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => format!("{value}"),
+        None => String::new(),
+    }
+}
+
+/// Interpolation of the TAI-UT1 offset between the tabulated records of a `Ut1Provider`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Ut1Interpolation {
+    /// Use the last tabulated value at or before the epoch, i.e. a step function.
+    #[default]
+    Step,
+    /// Linear interpolation between the tabulated values around the epoch.
+    Linear,
+    /// Lagrange interpolation over the provided number of tabulated values around the epoch (the IERS `interp.f` routine uses four).
+    Lagrange(usize),
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 /// A structure storing all of the TAI-UT1 data
 pub struct Ut1Provider {
     data: Vec<DeltaTaiUt1>,
     iter_pos: usize,
+    interpolation: Ut1Interpolation,
+    remove_leap_seconds: bool,
+}
+
+impl Default for Ut1Provider {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            iter_pos: 0,
+            interpolation: Ut1Interpolation::default(),
+            remove_leap_seconds: true,
+        }
+    }
 }
 
 impl Ut1Provider {
+    /// Returns a copy of this provider which interpolates the TAI-UT1 offset with the provided method.
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: Ut1Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns a copy of this provider which removes the leap second discontinuities before interpolating (the default), or not.
+    ///
+    /// When removed, the continuous TAI-UT1 offset is interpolated. Otherwise, the tabulated values are converted to UT1-UTC
+    /// (which jumps by one second at each leap second) before interpolating, and the leap seconds are restored at the requested epoch.
+    /// This only matters when interpolating across a leap second, and is only useful to match software which interpolates UT1-UTC directly.
+    #[must_use]
+    pub fn with_leap_second_removal(mut self, remove_leap_seconds: bool) -> Self {
+        self.remove_leap_seconds = remove_leap_seconds;
+        self
+    }
+
     /// Builds a UT1 provided by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short> (short time scale UT1 data) and parsing it.
+    #[cfg(feature = "ut1-download")]
     pub fn download_short_from_jpl() -> Result<Self, Errors> {
         Self::download_from_jpl("latest_eop2.short")
     }
 
     /// Build a UT1 provider by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.long> (long time scale UT1 data) and parsing it.
+    #[cfg(feature = "ut1-download")]
     pub fn download_from_jpl(version: &str) -> Result<Self, Errors> {
-        match get(format!(
-            "https://eop2-external.jpl.nasa.gov/eop2/{}",
-            version
-        )) {
-            Ok(resp) => {
-                let eop_data = String::from_utf8(resp.bytes().unwrap().to_vec()).unwrap();
-                Self::from_eop_data(eop_data)
-            }
-            Err(e) => Err(Errors::ParseError(ParsingErrors::DownloadError(
-                e.status().unwrap_or(StatusCode::SEE_OTHER),
-            ))),
-        }
+        Self::download_from_jpl_with(version, fetch_url)
+    }
+
+    /// Builds a UT1 provider from the latest short time scale JPL EOP2 data, fetched with the provided function.
+    pub fn download_short_from_jpl_with<F>(fetch: F) -> Result<Self, Errors>
+    where
+        F: FnOnce(&str) -> Result<String, Errors>,
+    {
+        Self::download_from_jpl_with("latest_eop2.short", fetch)
+    }
+
+    /// Builds a UT1 provider from the provided version of the JPL EOP2 data (e.g. `latest_eop2.long`), fetched with the provided function.
+    ///
+    /// The `fetch` function is called with the full URL of the file (cf. [JPL_EOP2_URL]) and returns its contents.
+    /// This allows using any HTTP client, a proxy, a cache, or a local fixture. The `ut1-download` feature provides `download_from_jpl`
+    /// which fetches the file with `reqwest`.
+    pub fn download_from_jpl_with<F>(version: &str, fetch: F) -> Result<Self, Errors>
+    where
+        F: FnOnce(&str) -> Result<String, Errors>,
+    {
+        Self::from_eop_data(fetch(&format!("{JPL_EOP2_URL}{version}"))?)
     }
 
     /// Builds a UT1 provider from the provided path to an EOP file.
     pub fn from_eop_file(path: &str) -> Result<Self, Errors> {
-        let mut f = match File::open(path) {
-            Ok(f) => f,
-            Err(e) => return Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
-        };
-
-        let mut contents = String::new();
-        if let Err(e) = f.read_to_string(&mut contents) {
-            return Err(Errors::ParseError(ParsingErrors::IOError(e.kind())));
-        }
-
-        Self::from_eop_data(contents)
+        Self::from_eop_data(read_file(path)?)
     }
 
-    /// Builds a UT1 provider from the provided EOP data
+    /// Builds a UT1 provider from the provided JPL EOP2 data.
+    /// Records after the "Last UTPM Data Point" of the header are flagged as predicted.
     pub fn from_eop_data(contents: String) -> Result<Self, Errors> {
         let mut me = Self::default();
 
+        let mut last_observed: Option<Epoch> = None;
         let mut ignore = true;
         for line in contents.lines() {
-            if line == " EOP2=" {
+            if let Some(last_data_point) = line.trim().strip_prefix("$  Last UTPM Data Point") {
+                last_observed = Epoch::from_str(last_data_point.trim()).ok();
+                continue;
+            } else if line == " EOP2=" {
                 // Data will start after this line
                 ignore = false;
                 continue;
@@ -99,25 +219,182 @@ impl Ut1Provider {
                 return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
             }
 
-            let mjd_tai_days: f64 = match lexical_core::parse(data[0].trim().as_bytes()) {
-                Ok(val) => val,
-                Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+            let mjd_tai_days = parse_f64(data[0])?;
+            let delta_ut1_ms = parse_f64(data[3])?;
+            // Polar motion and celestial pole offsets are in milliarcseconds.
+            let x_p_mas = parse_f64(data[1])?;
+            let y_p_mas = parse_f64(data[2])?;
+            let (dx_mas, dy_mas) = match (data.get(10), data.get(11)) {
+                (Some(dx), Some(dy)) => (Some(parse_f64(dx)?), Some(parse_f64(dy)?)),
+                _ => (None, None),
             };
 
-            let delta_ut1_ms: f64;
-            match lexical_core::parse(data[3].trim().as_bytes()) {
-                Ok(val) => delta_ut1_ms = val,
-                Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
-            }
-
+            let epoch = Epoch::from_mjd_tai(mjd_tai_days);
             me.data.push(DeltaTaiUt1 {
-                epoch: Epoch::from_mjd_tai(mjd_tai_days),
+                epoch,
                 delta_tai_minus_ut1: delta_ut1_ms * Unit::Millisecond,
+                predicted: last_observed.is_some_and(|last| epoch > last),
+                x_p: Some(x_p_mas / 1e3),
+                y_p: Some(y_p_mas / 1e3),
+                lod: None,
+                dx: dx_mas.map(|dx| dx / 1e3),
+                dy: dy_mas.map(|dy| dy / 1e3),
             });
         }
 
         Ok(me)
     }
+
+    /// Builds a UT1 provider from the provided path to an IERS `finals2000A.all`, `finals2000A.daily`, `finals.all` or `finals.daily` file.
+    pub fn from_finals_file(path: &str) -> Result<Self, Errors> {
+        Self::from_finals_data(read_file(path)?)
+    }
+
+    /// Builds a UT1 provider from the provided IERS finals data (fixed width columns, cf. <https://datacenter.iers.org/versionMetadata.php?filename=latestVersionMeta/9_FINALS.ALL_IAU2000_V2013_019.txt>).
+    ///
+    /// The Bulletin A values are used, and the UT1-UTC flag of each record tells whether it is predicted (`P`) or observed (`I`).
+    /// Records without any UT1-UTC value (e.g. the end of the prediction span) are skipped.
+    ///
+    /// # Warning
+    /// In the IAU 1980 `finals.all` and `finals.daily` files, the celestial pole offset columns hold dPsi and dEps instead of dX and dY:
+    /// these are nonetheless stored as-is in the `dx` and `dy` fields of each record.
+    pub fn from_finals_data(contents: String) -> Result<Self, Errors> {
+        let mut me = Self::default();
+
+        for line in contents.lines() {
+            // Columns are 1-indexed in the IERS documentation: MJD in 8-15, UT1-UTC flag in 58, UT1-UTC in seconds in 59-68.
+            let ut1_utc_s = match line.get(58..68).map(str::trim) {
+                Some(ut1_utc) if !ut1_utc.is_empty() => parse_f64(ut1_utc)?,
+                _ => continue,
+            };
+            let mjd_utc_days = match line.get(7..15) {
+                Some(mjd) => parse_f64(mjd)?,
+                None => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+            };
+            let predicted = match line.get(57..58) {
+                Some("I") => false,
+                Some("P") => true,
+                _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+            };
+
+            let mut record = DeltaTaiUt1::from_ut1_utc(mjd_utc_days, ut1_utc_s, predicted);
+            // Polar motion in arcseconds in 19-27 and 38-46, LOD in milliseconds in 80-86, and celestial pole offsets in milliarcseconds in 98-106 and 117-125.
+            record.x_p = parse_optional_f64(line.get(18..27))?;
+            record.y_p = parse_optional_f64(line.get(37..46))?;
+            record.lod = parse_optional_f64(line.get(79..86))?.map(|lod| lod * Unit::Millisecond);
+            record.dx = parse_optional_f64(line.get(97..106))?.map(|dx| dx / 1e3);
+            record.dy = parse_optional_f64(line.get(116..125))?.map(|dy| dy / 1e3);
+
+            me.data.push(record);
+        }
+
+        Ok(me)
+    }
+
+    /// Builds a UT1 provider from the provided path to an IERS EOP 14 C04 or EOP 20 C04 file.
+    pub fn from_c04_file(path: &str) -> Result<Self, Errors> {
+        Self::from_c04_data(read_file(path)?)
+    }
+
+    /// Builds a UT1 provider from the provided IERS EOP 14 C04 or EOP 20 C04 data, whose records are all observed.
+    ///
+    /// EOP 14 C04 records start with the year, month, day and the integer MJD, followed by x, y, UT1-UTC, LOD, dX and dY.
+    /// EOP 20 C04 records start with the year, month, day, hour and the fractional MJD, followed by x, y, UT1-UTC, dX, dY, x rate, y rate and LOD.
+    pub fn from_c04_data(contents: String) -> Result<Self, Errors> {
+        let mut me = Self::default();
+
+        for line in contents.lines() {
+            let data: Vec<&str> = line.split_whitespace().collect();
+            // Skip the headers and comments, which do not start with a year.
+            if data.len() < 8 || lexical_core::parse::<i32>(data[0].as_bytes()).is_err() {
+                continue;
+            }
+
+            // The fourth column is the hour in EOP 20 C04, and the MJD in EOP 14 C04.
+            let eop_20 = parse_f64(data[3])? < 24.0;
+            let mjd_col = if eop_20 { 4 } else { 3 };
+            let mjd_utc_days = parse_f64(data[mjd_col])?;
+            let ut1_utc_s = parse_f64(data[mjd_col + 3])?;
+
+            let mut record = DeltaTaiUt1::from_ut1_utc(mjd_utc_days, ut1_utc_s, false);
+            // All angles are in arcseconds and the LOD is in seconds.
+            let (lod_col, dx_col) = if eop_20 {
+                (mjd_col + 8, mjd_col + 4)
+            } else {
+                (mjd_col + 4, mjd_col + 5)
+            };
+            record.x_p = Some(parse_f64(data[mjd_col + 1])?);
+            record.y_p = Some(parse_f64(data[mjd_col + 2])?);
+            record.lod =
+                parse_optional_f64(data.get(lod_col).copied())?.map(|lod| lod * Unit::Second);
+            record.dx = parse_optional_f64(data.get(dx_col).copied())?;
+            record.dy = parse_optional_f64(data.get(dx_col + 1).copied())?;
+
+            me.data.push(record);
+        }
+
+        Ok(me)
+    }
+}
+
+impl DeltaTaiUt1 {
+    /// Builds a record from the UT1-UTC value in seconds at the provided MJD in UTC, as tabulated by the IERS.
+    fn from_ut1_utc(mjd_utc_days: f64, ut1_utc_s: f64, predicted: bool) -> Self {
+        let epoch = Epoch::from_mjd_utc(mjd_utc_days);
+        // TAI - UT1 = (TAI - UTC) - (UT1 - UTC)
+        let delta_tai_utc = epoch.to_tai_duration() - epoch.to_utc_duration();
+        Self {
+            epoch,
+            delta_tai_minus_ut1: delta_tai_utc - ut1_utc_s * Unit::Second,
+            predicted,
+            ..Default::default()
+        }
+    }
+}
+
+/// Reads the whole file at the provided path.
+fn read_file(path: &str) -> Result<String, Errors> {
+    let mut f = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => return Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
+    };
+
+    let mut contents = String::new();
+    if let Err(e) = f.read_to_string(&mut contents) {
+        return Err(Errors::ParseError(ParsingErrors::IOError(e.kind())));
+    }
+
+    Ok(contents)
+}
+
+/// Fetches the contents of the provided URL with a blocking HTTP GET request.
+#[cfg(feature = "ut1-download")]
+fn fetch_url(url: &str) -> Result<String, Errors> {
+    let download_error = |e: reqwest::Error| {
+        Errors::ParseError(ParsingErrors::DownloadError(
+            e.status().unwrap_or(StatusCode::SEE_OTHER),
+        ))
+    };
+    get(url)
+        .and_then(|resp| resp.error_for_status())
+        .and_then(|resp| resp.text())
+        .map_err(download_error)
+}
+
+/// Parses a floating point value, ignoring the surrounding white spaces.
+fn parse_f64(value: &str) -> Result<f64, Errors> {
+    match lexical_core::parse(value.trim().as_bytes()) {
+        Ok(val) => Ok(val),
+        Err(_) => Err(Errors::ParseError(ParsingErrors::ValueError)),
+    }
+}
+
+/// Parses a floating point value if it is present and not blank.
+fn parse_optional_f64(value: Option<&str>) -> Result<Option<f64>, Errors> {
+    match value.map(str::trim) {
+        Some(value) if !value.is_empty() => parse_f64(value).map(Some),
+        _ => Ok(None),
+    }
 }
 
 #[cfg(feature = "python")]
@@ -141,6 +418,129 @@ impl fmt::Display for Ut1Provider {
     }
 }
 
+impl Ut1Source for Ut1Provider {
+    /// Returns the TAI - UT1 offset at the provided epoch, interpolated as configured, assuming that the data is sorted chronologically.
+    ///
+    /// Returns None before the first record, and on the first record with the step interpolation. After the last record, the last tabulated value is returned.
+    fn delta_tai_minus_ut1(&self, epoch: &Epoch) -> Option<Duration> {
+        let window = self.window(epoch)?;
+        if window.len() == 1 {
+            return Some(window[0].delta_tai_minus_ut1);
+        }
+
+        if self.remove_leap_seconds {
+            lagrange(window, epoch, |record| {
+                Some(record.delta_tai_minus_ut1.to_seconds())
+            })
+            .map(|seconds| seconds * Unit::Second)
+        } else {
+            // Interpolate UT1-UTC and restore the leap seconds at the requested epoch.
+            let ut1_utc = lagrange(window, epoch, |record| {
+                Some(
+                    record.epoch.leap_seconds(true).unwrap_or(0.0)
+                        - record.delta_tai_minus_ut1.to_seconds(),
+                )
+            })?;
+            Some((epoch.leap_seconds(true).unwrap_or(0.0) - ut1_utc) * Unit::Second)
+        }
+    }
+}
+
+impl Ut1Provider {
+    /// Returns the polar motion (x_p, y_p) in arcseconds at the provided epoch, interpolated as configured.
+    /// Returns None if this epoch is not covered, or if the records do not include the polar motion.
+    pub fn polar_motion(&self, epoch: &Epoch) -> Option<(f64, f64)> {
+        let window = self.window(epoch)?;
+        Some((
+            lagrange(window, epoch, |record| record.x_p)?,
+            lagrange(window, epoch, |record| record.y_p)?,
+        ))
+    }
+
+    /// Returns the excess length of day (LOD) at the provided epoch, interpolated as configured.
+    /// Returns None if this epoch is not covered, or if the records do not include the LOD.
+    pub fn length_of_day(&self, epoch: &Epoch) -> Option<Duration> {
+        let window = self.window(epoch)?;
+        if window.len() == 1 {
+            return window[0].lod;
+        }
+        lagrange(window, epoch, |record| {
+            record.lod.map(|lod| lod.to_seconds())
+        })
+        .map(|seconds| seconds * Unit::Second)
+    }
+
+    /// Returns the celestial pole offsets (dX, dY) in arcseconds at the provided epoch, interpolated as configured.
+    /// Returns None if this epoch is not covered, or if the records do not include the celestial pole offsets.
+    pub fn celestial_pole_offsets(&self, epoch: &Epoch) -> Option<(f64, f64)> {
+        let window = self.window(epoch)?;
+        Some((
+            lagrange(window, epoch, |record| record.dx)?,
+            lagrange(window, epoch, |record| record.dy)?,
+        ))
+    }
+
+    /// Returns the records to interpolate over at the provided epoch, assuming that the data is sorted chronologically.
+    /// A single record is returned if no interpolation is needed: after the last record, or with the step interpolation.
+    fn window(&self, epoch: &Epoch) -> Option<&[DeltaTaiUt1]> {
+        // Number of records strictly before this epoch, so the step interpolation uses the previous record on a record.
+        let idx = self.data.partition_point(|record| record.epoch < *epoch);
+        if idx == 0 {
+            // Only the interpolations other than the step use the first record on that record.
+            return match self.data.first() {
+                Some(first)
+                    if first.epoch == *epoch && self.interpolation != Ut1Interpolation::Step =>
+                {
+                    Some(&self.data[..1])
+                }
+                _ => None,
+            };
+        }
+        if idx == self.data.len() {
+            return Some(&self.data[idx - 1..idx]);
+        }
+
+        Some(match self.interpolation {
+            Ut1Interpolation::Step => &self.data[idx - 1..idx],
+            Ut1Interpolation::Linear => &self.data[idx - 1..=idx],
+            Ut1Interpolation::Lagrange(points) => {
+                let points = points.clamp(2, self.data.len());
+                let start = idx.saturating_sub(points / 2).min(self.data.len() - points);
+                &self.data[start..start + points]
+            }
+        })
+    }
+}
+
+/// Lagrange interpolation at the provided epoch of the values of the provided records, which is linear interpolation for two records.
+/// Returns None if any of these records lacks the value.
+fn lagrange<F: Fn(&DeltaTaiUt1) -> Option<f64>>(
+    window: &[DeltaTaiUt1],
+    epoch: &Epoch,
+    value_at: F,
+) -> Option<f64> {
+    // Records sharing the epoch of the previous record are skipped, since they would divide by zero.
+    let records = || {
+        window
+            .iter()
+            .enumerate()
+            .filter(|(idx, record)| *idx == 0 || window[idx - 1].epoch != record.epoch)
+            .map(|(_, record)| record)
+    };
+    let mut interpolated = 0.0;
+    for record_i in records() {
+        let mut weight = 1.0;
+        for record_j in records() {
+            if record_i.epoch != record_j.epoch {
+                weight *= (*epoch - record_j.epoch).to_seconds()
+                    / (record_i.epoch - record_j.epoch).to_seconds();
+            }
+        }
+        interpolated += weight * value_at(record_i)?;
+    }
+    Some(interpolated)
+}
+
 impl Iterator for Ut1Provider {
     type Item = DeltaTaiUt1;
 
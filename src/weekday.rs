@@ -52,6 +52,11 @@ impl Weekday {
         let c89_weekday: u8 = (self + 1).into();
         c89_weekday
     }
+
+    // Inverse of `to_c89_weekday`, where Sunday is zero (or seven)
+    pub(crate) fn from_c89_weekday(c89_weekday: u8) -> Self {
+        Self::from(c89_weekday + 6)
+    }
 }
 
 impl From<u8> for Weekday {
@@ -0,0 +1,468 @@
+use hifitime::{
+    CalendarDuration, CalendarRule, CalendarSeries, Duration, Epoch, Errors, MonthEndPolicy,
+    MonthName, ParsingErrors, TimeScale, Unit, Weekday,
+};
+
+#[test]
+fn test_add_months() {
+    let policies = [
+        MonthEndPolicy::Clamp,
+        MonthEndPolicy::Overflow,
+        MonthEndPolicy::Strict,
+    ];
+
+    // Same day next month, for all policies, including across years in both directions.
+    let e = Epoch::from_gregorian_utc(2023, 11, 15, 8, 30, 15, 123);
+    for policy in policies {
+        assert_eq!(
+            e.add_months(1, policy, TimeScale::UTC).unwrap(),
+            Epoch::from_gregorian_utc(2023, 12, 15, 8, 30, 15, 123)
+        );
+        assert_eq!(
+            e.add_months(2, policy, TimeScale::UTC).unwrap(),
+            Epoch::from_gregorian_utc(2024, 1, 15, 8, 30, 15, 123)
+        );
+        assert_eq!(
+            e.add_months(-11, policy, TimeScale::UTC).unwrap(),
+            Epoch::from_gregorian_utc(2022, 12, 15, 8, 30, 15, 123)
+        );
+        assert_eq!(
+            e.add_months(-1200, policy, TimeScale::UTC).unwrap(),
+            Epoch::from_gregorian_utc(1923, 11, 15, 8, 30, 15, 123)
+        );
+        assert_eq!(e.add_months(0, policy, TimeScale::UTC).unwrap(), e);
+    }
+
+    // End of month handling
+    let e = Epoch::from_gregorian_utc_at_noon(2024, 1, 31);
+    assert_eq!(
+        e.add_months(1, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_at_noon(2024, 2, 29))
+    );
+    assert_eq!(
+        e.add_months(1, MonthEndPolicy::Overflow, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_at_noon(2024, 3, 2))
+    );
+    assert_eq!(
+        e.add_months(1, MonthEndPolicy::Strict, TimeScale::UTC),
+        Err(Errors::Carry)
+    );
+    assert_eq!(
+        e.add_months(-2, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_at_noon(2023, 11, 30))
+    );
+    assert_eq!(
+        e.add_months(-2, MonthEndPolicy::Overflow, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_at_noon(2023, 12, 1))
+    );
+    // Clamping is not cumulative: the day of the month of the original epoch is used.
+    assert_eq!(
+        e.add_months(2, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_at_noon(2024, 3, 31))
+    );
+}
+
+#[test]
+fn test_add_years() {
+    let e = Epoch::from_gregorian_tai_hms(2024, 2, 29, 6, 0, 0);
+    assert_eq!(
+        e.add_years(1, MonthEndPolicy::Clamp, TimeScale::TAI),
+        Ok(Epoch::from_gregorian_tai_hms(2025, 2, 28, 6, 0, 0))
+    );
+    assert_eq!(
+        e.add_years(1, MonthEndPolicy::Overflow, TimeScale::TAI),
+        Ok(Epoch::from_gregorian_tai_hms(2025, 3, 1, 6, 0, 0))
+    );
+    assert_eq!(
+        e.add_years(1, MonthEndPolicy::Strict, TimeScale::TAI),
+        Err(Errors::Carry)
+    );
+    // 2100 is not a leap year but 2000 is.
+    assert_eq!(
+        e.add_years(76, MonthEndPolicy::Strict, TimeScale::TAI),
+        Err(Errors::Carry)
+    );
+    assert_eq!(
+        e.add_years(-24, MonthEndPolicy::Strict, TimeScale::TAI),
+        Ok(Epoch::from_gregorian_tai_hms(2000, 2, 29, 6, 0, 0))
+    );
+
+    // The time scale of the epoch is kept.
+    let e = Epoch::from_gregorian_utc_at_midnight(2020, 6, 1).in_time_scale(TimeScale::GPST);
+    let next = e
+        .add_years(1, MonthEndPolicy::Clamp, TimeScale::UTC)
+        .unwrap();
+    assert_eq!(next.time_scale, TimeScale::GPST);
+    assert_eq!(next, Epoch::from_gregorian_utc_at_midnight(2021, 6, 1));
+}
+
+#[test]
+fn test_calendar_time_scale() {
+    // One year later in UTC spans the leap second of 2016 December 31, but one year later in TAI does not.
+    let e = Epoch::from_gregorian_utc_at_midnight(2016, 6, 1);
+    let utc = e
+        .add_years(1, MonthEndPolicy::Clamp, TimeScale::UTC)
+        .unwrap();
+    let tai = e
+        .add_years(1, MonthEndPolicy::Clamp, TimeScale::TAI)
+        .unwrap();
+    assert_eq!(utc, Epoch::from_gregorian_utc_at_midnight(2017, 6, 1));
+    assert_eq!(utc - e, 365 * Unit::Day + Unit::Second);
+    assert_eq!(tai - e, 365 * Unit::Day);
+    assert_eq!(utc.time_scale, TimeScale::UTC);
+    assert_eq!(tai.time_scale, TimeScale::UTC);
+
+    // The inserted leap second only exists on some days.
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert_eq!(
+        leap.add_years(-1, MonthEndPolicy::Strict, TimeScale::UTC),
+        Err(Errors::Carry)
+    );
+    assert_eq!(
+        leap.add_years(-1, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc(
+            2015,
+            12,
+            31,
+            23,
+            59,
+            59,
+            500_000_000
+        ))
+    );
+    assert_eq!(
+        leap.add_years(-1, MonthEndPolicy::Overflow, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc(2016, 1, 1, 0, 0, 0, 500_000_000))
+    );
+    // June 31 is clamped to June 30, when a leap second was also inserted.
+    assert_eq!(
+        leap.add_months(-18, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc(
+            2015,
+            6,
+            30,
+            23,
+            59,
+            60,
+            500_000_000
+        ))
+    );
+}
+
+#[test]
+fn test_add_calendar_duration() {
+    let e = Epoch::from_gregorian_utc_hms(2023, 1, 31, 10, 0, 0);
+
+    // The months are applied before the days.
+    let p1m1d = CalendarDuration::new(0, 1, 1);
+    assert_eq!(
+        e.add_calendar_duration(p1m1d, MonthEndPolicy::Clamp, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_hms(2023, 3, 1, 10, 0, 0))
+    );
+    assert_eq!(
+        e.add_calendar_duration(p1m1d, MonthEndPolicy::Overflow, TimeScale::UTC),
+        Ok(Epoch::from_gregorian_utc_hms(2023, 3, 4, 10, 0, 0))
+    );
+    assert_eq!(
+        e.add_calendar_duration(p1m1d, MonthEndPolicy::Strict, TimeScale::UTC),
+        Err(Errors::Carry)
+    );
+
+    // Adding and subtracting the same calendar duration is not always the identity, but is for valid days.
+    let p1y2m3d = CalendarDuration::new(1, 2, 3);
+    let e = Epoch::from_gregorian_utc_hms(2023, 5, 10, 10, 0, 0);
+    let later = e
+        .add_calendar_duration(p1y2m3d, MonthEndPolicy::Strict, TimeScale::UTC)
+        .unwrap();
+    assert_eq!(later, Epoch::from_gregorian_utc_hms(2024, 7, 13, 10, 0, 0));
+    assert_eq!(
+        later.add_calendar_duration(
+            CalendarDuration::from_days(-3),
+            MonthEndPolicy::Strict,
+            TimeScale::UTC
+        ),
+        e.add_calendar_duration(
+            CalendarDuration::new(1, 2, 0),
+            MonthEndPolicy::Strict,
+            TimeScale::UTC
+        )
+    );
+    assert_eq!(
+        later.add_months(-14, MonthEndPolicy::Strict, TimeScale::UTC),
+        e.add_calendar_duration(
+            CalendarDuration::from_days(3),
+            MonthEndPolicy::Strict,
+            TimeScale::UTC
+        )
+    );
+
+    // Days are calendar days: the time of day is kept across leap seconds.
+    let e = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    let next = e
+        .add_calendar_duration(
+            CalendarDuration::from_days(1),
+            MonthEndPolicy::Strict,
+            TimeScale::UTC,
+        )
+        .unwrap();
+    assert_eq!(next, Epoch::from_gregorian_utc_at_noon(2017, 1, 1));
+    assert_eq!(next - e, Unit::Day + Unit::Second);
+
+    // Monthly recurring events
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 8, 31);
+    let expected = [(2023, 8, 31), (2023, 9, 30), (2023, 10, 31), (2023, 11, 30)];
+    for (k, (y, m, d)) in expected.iter().enumerate() {
+        assert_eq!(
+            start.add_calendar_duration(
+                CalendarDuration::from_months(1) * k as i32,
+                MonthEndPolicy::Clamp,
+                TimeScale::UTC
+            ),
+            Ok(Epoch::from_gregorian_utc_at_midnight(*y, *m, *d))
+        );
+    }
+}
+
+#[test]
+fn test_calendar_overflow() {
+    // Dates beyond the range of Duration are errors instead of saturating.
+    let e = Epoch::from_gregorian_utc_hms(2023, 1, 31, 10, 0, 0);
+    for years in [i32::MIN, i32::MAX] {
+        assert_eq!(
+            e.add_years(years, MonthEndPolicy::Clamp, TimeScale::UTC),
+            Err(Errors::Overflow)
+        );
+    }
+    for days in [i32::MIN, i32::MAX] {
+        assert_eq!(
+            e.add_calendar_duration(
+                CalendarDuration::new(0, 0, days),
+                MonthEndPolicy::Clamp,
+                TimeScale::UTC
+            ),
+            Err(Errors::Overflow)
+        );
+    }
+
+    // Close to the bounds of Duration, only the dates within its range are valid.
+    for (bound, sign) in [(Duration::MAX, 1_i32), (Duration::MIN, -1)] {
+        let e = Epoch::from_tai_duration(bound - i64::from(sign) * 40 * Unit::Day);
+        for time_scale in [TimeScale::TAI, TimeScale::UTC] {
+            assert_eq!(
+                e.add_months(sign * 2, MonthEndPolicy::Clamp, time_scale),
+                Err(Errors::Overflow)
+            );
+            assert_eq!(
+                e.add_months(-sign, MonthEndPolicy::Clamp, time_scale)
+                    .map(|earlier| (e - earlier).abs() < 32 * Unit::Day),
+                Ok(true)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_calendar_series_monthly() {
+    // Every month on the 31st: months without a 31st are skipped.
+    let start = Epoch::from_gregorian_utc_hms(2023, 1, 15, 9, 30, 0);
+    let end = Epoch::from_gregorian_utc_at_midnight(2023, 9, 1);
+    let series = CalendarSeries::new(start, end, CalendarRule::MonthlyOnDay { day: 31 }).unwrap();
+    let days: Vec<Epoch> = series.collect();
+    let expected: Vec<Epoch> = [(1, 31), (3, 31), (5, 31), (7, 31), (8, 31)]
+        .iter()
+        .map(|(m, d)| Epoch::from_gregorian_utc_hms(2023, *m, *d, 9, 30, 0))
+        .collect();
+    assert_eq!(days, expected);
+
+    // Every month on the 15th, inclusive on the start and the end.
+    let end = Epoch::from_gregorian_utc_hms(2023, 4, 15, 9, 30, 0);
+    let series = CalendarSeries::new(start, end, CalendarRule::MonthlyOnDay { day: 15 }).unwrap();
+    assert_eq!(
+        series.collect::<Vec<Epoch>>(),
+        vec![
+            start,
+            Epoch::from_gregorian_utc_hms(2023, 2, 15, 9, 30, 0),
+            Epoch::from_gregorian_utc_hms(2023, 3, 15, 9, 30, 0),
+            end
+        ]
+    );
+
+    // Last day of each month, across a leap year.
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 11, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2024, 4, 1);
+    let series = CalendarSeries::new(start, end, CalendarRule::MonthlyOnDay { day: -1 }).unwrap();
+    let expected: Vec<Epoch> = [
+        (2023, 11, 30),
+        (2023, 12, 31),
+        (2024, 1, 31),
+        (2024, 2, 29),
+        (2024, 3, 31),
+    ]
+    .iter()
+    .map(|(y, m, d)| Epoch::from_gregorian_utc_at_midnight(*y, *m, *d))
+    .collect();
+    assert_eq!(series.collect::<Vec<Epoch>>(), expected);
+
+    // Last Friday of each month
+    let series = CalendarSeries::new(
+        start,
+        end,
+        CalendarRule::MonthlyOnWeekday {
+            nth: -1,
+            weekday: Weekday::Friday,
+        },
+    )
+    .unwrap();
+    let expected: Vec<Epoch> = [
+        (2023, 11, 24),
+        (2023, 12, 29),
+        (2024, 1, 26),
+        (2024, 2, 23),
+        (2024, 3, 29),
+    ]
+    .iter()
+    .map(|(y, m, d)| Epoch::from_gregorian_utc_at_midnight(*y, *m, *d))
+    .collect();
+    assert_eq!(series.collect::<Vec<Epoch>>(), expected);
+
+    // Fifth Wednesday of each month only exists in some months.
+    let series = CalendarSeries::new(
+        start,
+        end,
+        CalendarRule::MonthlyOnWeekday {
+            nth: 5,
+            weekday: Weekday::Wednesday,
+        },
+    )
+    .unwrap();
+    let expected: Vec<Epoch> = [(2023, 11, 29), (2024, 1, 31)]
+        .iter()
+        .map(|(y, m, d)| Epoch::from_gregorian_utc_at_midnight(*y, *m, *d))
+        .collect();
+    assert_eq!(series.collect::<Vec<Epoch>>(), expected);
+
+    // Invalid rules
+    for rule in [
+        CalendarRule::MonthlyOnDay { day: 0 },
+        CalendarRule::MonthlyOnDay { day: 32 },
+        CalendarRule::MonthlyOnWeekday {
+            nth: 6,
+            weekday: Weekday::Monday,
+        },
+        CalendarRule::YearlyOnDay {
+            month: MonthName::February,
+            day: 30,
+        },
+    ] {
+        assert_eq!(
+            CalendarSeries::new(start, end, rule),
+            Err(Errors::ParseError(ParsingErrors::ValueError)),
+            "{rule}"
+        );
+    }
+}
+
+#[test]
+fn test_calendar_series_weekly() {
+    // Every Tuesday at 14:00 UTC, starting on a Wednesday.
+    let start = Epoch::from_gregorian_utc_hms(2024, 1, 3, 14, 0, 0);
+    let end = Epoch::from_gregorian_utc_hms(2024, 1, 30, 14, 0, 0);
+    let rule = CalendarRule::Weekly {
+        weekday: Weekday::Tuesday,
+    };
+    let tuesdays: Vec<Epoch> = CalendarSeries::new(start, end, rule).unwrap().collect();
+    assert_eq!(
+        tuesdays,
+        vec![
+            Epoch::from_gregorian_utc_hms(2024, 1, 9, 14, 0, 0),
+            Epoch::from_gregorian_utc_hms(2024, 1, 16, 14, 0, 0),
+            Epoch::from_gregorian_utc_hms(2024, 1, 23, 14, 0, 0),
+            Epoch::from_gregorian_utc_hms(2024, 1, 30, 14, 0, 0),
+        ]
+    );
+    for tuesday in &tuesdays {
+        assert_eq!(tuesday.weekday_utc(), Weekday::Tuesday);
+    }
+    // Consistent with the next weekday
+    assert_eq!(start.next(Weekday::Tuesday), tuesdays[0]);
+
+    // Every other week
+    let series = CalendarSeries::new(start, end, rule)
+        .unwrap()
+        .with_interval(2);
+    assert_eq!(
+        series.collect::<Vec<Epoch>>(),
+        vec![tuesdays[0], tuesdays[2]]
+    );
+
+    // Daily at the same UTC time of day, across the leap second of 2016 December 31.
+    let start = Epoch::from_gregorian_utc_at_noon(2016, 12, 30);
+    let end = Epoch::from_gregorian_utc_at_noon(2017, 1, 2);
+    let days: Vec<Epoch> = CalendarSeries::new(start, end, CalendarRule::Daily)
+        .unwrap()
+        .collect();
+    assert_eq!(days.len(), 4);
+    assert_eq!(days[1] - days[0], Unit::Day);
+    assert_eq!(days[2] - days[1], Unit::Day + Unit::Second);
+    assert_eq!(days[3], end);
+}
+
+#[test]
+fn test_calendar_series_quarterly_and_yearly() {
+    // First Monday of each quarter
+    let start = Epoch::from_gregorian_utc_hms(2023, 1, 1, 8, 0, 0);
+    let end = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    let rule = CalendarRule::MonthlyOnWeekday {
+        nth: 1,
+        weekday: Weekday::Monday,
+    };
+    let series = CalendarSeries::new(start, end, rule)
+        .unwrap()
+        .with_interval(3);
+    assert_eq!(
+        format!("{series}"),
+        "CalendarSeries [2023-01-01T08:00:00 UTC : 2024-01-01T00:00:00 UTC : FREQ=MONTHLY;BYDAY=1MO;INTERVAL=3]"
+    );
+    let mondays: Vec<Epoch> = series.collect();
+    let expected: Vec<Epoch> = [(1, 2), (4, 3), (7, 3), (10, 2)]
+        .iter()
+        .map(|(m, d)| Epoch::from_gregorian_utc_hms(2023, *m, *d, 8, 0, 0))
+        .collect();
+    assert_eq!(mondays, expected);
+
+    // Every February 29
+    let start = Epoch::from_gregorian_utc_at_midnight(1999, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2013, 1, 1);
+    let rule = CalendarRule::YearlyOnDay {
+        month: MonthName::February,
+        day: 29,
+    };
+    assert_eq!(format!("{rule}"), "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29");
+    let leap_days: Vec<Epoch> = CalendarSeries::new(start, end, rule).unwrap().collect();
+    let expected: Vec<Epoch> = [2000, 2004, 2008, 2012]
+        .iter()
+        .map(|y| Epoch::from_gregorian_utc_at_midnight(*y, 2, 29))
+        .collect();
+    assert_eq!(leap_days, expected);
+}
+
+#[test]
+fn test_calendar_series_time_scale() {
+    // Last day of each month at midnight TAI, returned in UTC.
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2023, 4, 1);
+    let rule = CalendarRule::MonthlyOnDay { day: -1 };
+    let series = CalendarSeries::new(start, end, rule)
+        .unwrap()
+        .with_time_scale(TimeScale::TAI);
+    let days: Vec<Epoch> = series.collect();
+    assert_eq!(days.len(), 3);
+    for (epoch, (m, d)) in days.iter().zip([(1, 31), (2, 28), (3, 31)]) {
+        assert_eq!(epoch.time_scale, TimeScale::UTC);
+        // The start of the series is 37 seconds past midnight in TAI, so this is the time of day used.
+        assert_eq!(
+            *epoch,
+            Epoch::from_gregorian_hms(2023, m, d, 0, 0, 37, TimeScale::TAI)
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use hifitime::ccsds::{CcsFormat, CdsFormat, CdsResolution, CucFormat, TimeCode, CCSDS_REF_EPOCH};
+use hifitime::{Epoch, Errors, ParsingErrors, TimeScale, Unit, GPST_REF_EPOCH};
+
+#[test]
+fn test_cuc() {
+    let mut buf = [0_u8; 32];
+
+    // Level 1 with four octets of seconds and two octets of fractions of a second.
+    let cuc = CucFormat::new(4, 2).unwrap();
+    let epoch = CCSDS_REF_EPOCH + Unit::Second * 258.5;
+    assert_eq!(cuc.encode(epoch, &mut buf), Ok(7));
+    assert_eq!(buf[..7], [0x1E, 0x00, 0x00, 0x01, 0x02, 0x80, 0x00]);
+    assert_eq!(cuc.decode(&buf[..7]), Ok(epoch));
+    assert_eq!(cuc.encode_tfield(epoch, &mut buf), Ok(6));
+    assert_eq!(cuc.decode_tfield(&buf[..6]), Ok(epoch));
+
+    // The fine time is truncated to its resolution of 1/65536 s.
+    let epoch = CCSDS_REF_EPOCH + Unit::Second * 1 + Unit::Microsecond * 20;
+    cuc.encode_tfield(epoch, &mut buf).unwrap();
+    assert_eq!(buf[..6], [0x00, 0x00, 0x00, 0x01, 0x00, 0x01]);
+    assert_eq!(
+        cuc.decode_tfield(&buf),
+        Ok(CCSDS_REF_EPOCH + Unit::Second * 1 + Unit::Nanosecond * 15_259)
+    );
+
+    // Extended P-field, whose fine time is precise enough for nanoseconds to round trip.
+    let cuc = CucFormat::new(5, 4).unwrap();
+    let epoch = Epoch::from_gregorian_tai(2023, 11, 28, 12, 34, 56, 123_456_789);
+    assert_eq!(cuc.encode(epoch, &mut buf), Ok(11));
+    assert_eq!(buf[..2], [0x9F, 0x24]);
+    assert_eq!(cuc.decode(&buf), Ok(epoch));
+    let (decoded, len) = CucFormat::new(1, 0).unwrap().with_pfield(&buf).unwrap();
+    assert_eq!((decoded, len), (cuc, 2));
+
+    for (coarse, fine) in [(7, 10), (1, 0), (4, 3), (7, 4)] {
+        let cuc = CucFormat::new(coarse, fine).unwrap();
+        let len = cuc
+            .encode(CCSDS_REF_EPOCH + Unit::Second * 200, &mut buf)
+            .unwrap();
+        assert_eq!(cuc.with_pfield(&buf).unwrap().0, cuc);
+        assert_eq!(
+            cuc.decode(&buf[..len]),
+            Ok(CCSDS_REF_EPOCH + Unit::Second * 200)
+        );
+    }
+
+    // Level 2 with the GPS epoch and time scale.
+    let cuc = CucFormat::new(4, 3)
+        .unwrap()
+        .with_agency_epoch(GPST_REF_EPOCH)
+        .with_time_scale(TimeScale::GPST);
+    let epoch = Epoch::from_gpst_seconds(1_234.25);
+    assert_eq!(cuc.encode(epoch, &mut buf), Ok(8));
+    assert_eq!(buf[..8], [0x2F, 0x00, 0x00, 0x04, 0xD2, 0x40, 0x00, 0x00]);
+    assert_eq!(cuc.decode(&buf), Ok(epoch));
+    assert_eq!(cuc.decode(&buf).unwrap().time_scale, TimeScale::GPST);
+    // The agency epoch is not part of the P-field.
+    assert_eq!(
+        CucFormat::new(4, 3).unwrap().decode(&buf),
+        Err(Errors::ParseError(ParsingErrors::InvalidPField))
+    );
+
+    // Errors
+    assert_eq!(
+        CucFormat::new(0, 2),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        CucFormat::new(4, 11),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    let cuc = CucFormat::new(1, 1).unwrap();
+    assert_eq!(
+        cuc.encode(CCSDS_REF_EPOCH + Unit::Second * 256, &mut buf),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        cuc.encode(CCSDS_REF_EPOCH - Unit::Second * 1, &mut buf),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        cuc.encode(CCSDS_REF_EPOCH, &mut buf[..2]),
+        Err(Errors::ParseError(ParsingErrors::BufferTooSmall))
+    );
+    assert_eq!(
+        cuc.decode(&[0x11, 0x01]),
+        Err(Errors::ParseError(ParsingErrors::BufferTooSmall))
+    );
+    assert_eq!(
+        cuc.decode(&[0x40, 0x00, 0x00, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::InvalidPField))
+    );
+}
+
+#[test]
+fn test_cds() {
+    let mut buf = [0_u8; 16];
+
+    let cds = CdsFormat::new(2, CdsResolution::Microseconds).unwrap();
+    let epoch = CCSDS_REF_EPOCH + Unit::Day * 1 + Unit::Second * 1.5 + Unit::Microsecond * 2;
+    assert_eq!(cds.encode(epoch, &mut buf), Ok(9));
+    assert_eq!(
+        buf[..9],
+        [0x41, 0x00, 0x01, 0x00, 0x00, 0x05, 0xDC, 0x00, 0x02]
+    );
+    assert_eq!(cds.decode(&buf), Ok(epoch));
+
+    // Three octets of days, and picoseconds
+    let cds = CdsFormat::new(3, CdsResolution::Picoseconds).unwrap();
+    let epoch = Epoch::from_gregorian_tai(2023, 11, 28, 12, 34, 56, 123_456_789);
+    assert_eq!(cds.encode(epoch, &mut buf), Ok(12));
+    assert_eq!(buf[0], 0x46);
+    assert_eq!(buf[8..12], [0x1B, 0x3A, 0x0C, 0x08]);
+    assert_eq!(cds.decode(&buf), Ok(epoch));
+    assert_eq!(
+        CdsFormat::new(2, CdsResolution::Milliseconds)
+            .unwrap()
+            .with_pfield(&buf)
+            .unwrap()
+            .0,
+        cds
+    );
+
+    // Days in UTC include the leap seconds, up to 86,400,999 milliseconds.
+    let cds = CdsFormat::new(2, CdsResolution::Milliseconds)
+        .unwrap()
+        .with_agency_epoch(Epoch::from_gregorian_utc_at_midnight(2000, 1, 1))
+        .with_time_scale(TimeScale::UTC);
+    let leap_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert_eq!(cds.encode(leap_second, &mut buf), Ok(7));
+    assert_eq!(buf[..7], [0x48, 0x18, 0x41, 0x05, 0x26, 0x5D, 0xF4]);
+    assert_eq!(cds.decode(&buf), Ok(leap_second));
+    let next_day = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    cds.encode_tfield(next_day, &mut buf).unwrap();
+    assert_eq!(buf[..6], [0x18, 0x42, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(cds.decode_tfield(&buf), Ok(next_day));
+    // There was no leap second at the end of 2017 January 01.
+    assert_eq!(
+        cds.decode_tfield(&[0x18, 0x42, 0x05, 0x26, 0x5D, 0xF4]),
+        Err(Errors::Carry)
+    );
+
+    // Errors
+    assert_eq!(
+        CdsFormat::new(4, CdsResolution::Milliseconds),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    let cds = CdsFormat::new(2, CdsResolution::Microseconds).unwrap();
+    assert_eq!(
+        cds.encode(CCSDS_REF_EPOCH + Unit::Day * 65_536, &mut buf),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        cds.decode_tfield(&[0x00, 0x01, 0x05, 0x26, 0x5C, 0x00, 0x00, 0x00]),
+        Err(Errors::Carry)
+    );
+    assert_eq!(
+        cds.decode_tfield(&[0x00, 0x01, 0x05, 0x26, 0x5F, 0xE8, 0x00, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        cds.decode_tfield(&[0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x03, 0xE8]),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        cds.decode(&[0x43, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::InvalidPField))
+    );
+    assert_eq!(
+        cds.decode(&[0x1E, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::InvalidPField))
+    );
+    assert_eq!(
+        cds.decode_tfield(&[0x00, 0x01, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::BufferTooSmall))
+    );
+}
+
+#[test]
+fn test_ccs() {
+    let mut buf = [0_u8; 16];
+    let epoch = Epoch::from_gregorian_tai(2024, 2, 29, 12, 34, 56, 789_000_000);
+
+    let ccs = CcsFormat::month_day(2).unwrap();
+    assert_eq!(ccs.encode(epoch, &mut buf), Ok(10));
+    assert_eq!(
+        buf[..10],
+        [0x52, 0x20, 0x24, 0x02, 0x29, 0x12, 0x34, 0x56, 0x78, 0x90]
+    );
+    assert_eq!(ccs.decode(&buf), Ok(epoch));
+
+    let ccs = CcsFormat::day_of_year(1).unwrap();
+    assert_eq!(ccs.encode(epoch, &mut buf), Ok(9));
+    assert_eq!(
+        buf[..9],
+        [0x59, 0x20, 0x24, 0x00, 0x60, 0x12, 0x34, 0x56, 0x78]
+    );
+    assert_eq!(ccs.decode(&buf), Ok(epoch - Unit::Millisecond * 9));
+
+    // Picoseconds round trip, and the P-field sets the layout.
+    let ccs = CcsFormat::month_day(6).unwrap();
+    let epoch = Epoch::from_gregorian_tai(1999, 12, 31, 23, 59, 59, 999_999_999);
+    assert_eq!(ccs.encode(epoch, &mut buf), Ok(14));
+    assert_eq!(buf[8..14], [0x99, 0x99, 0x99, 0x99, 0x90, 0x00]);
+    assert_eq!(CcsFormat::day_of_year(0).unwrap().decode(&buf), Ok(epoch));
+
+    // Leap second of UTC
+    let ccs = CcsFormat::month_day(0)
+        .unwrap()
+        .with_time_scale(TimeScale::UTC);
+    let leap_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    assert_eq!(ccs.encode_tfield(leap_second, &mut buf), Ok(7));
+    assert_eq!(buf[..7], [0x20, 0x16, 0x12, 0x31, 0x23, 0x59, 0x60]);
+    assert_eq!(ccs.decode_tfield(&buf), Ok(leap_second));
+
+    // Errors
+    assert_eq!(
+        CcsFormat::month_day(7),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        ccs.decode_tfield(&[0x20, 0x16, 0x12, 0x30, 0x23, 0x59, 0x60]),
+        Err(Errors::Carry)
+    );
+    for invalid in [
+        [0x20, 0x1A, 0x12, 0x31, 0x23, 0x59, 0x00],
+        [0x20, 0x23, 0x02, 0x29, 0x23, 0x59, 0x00],
+        [0x20, 0x23, 0x13, 0x01, 0x23, 0x59, 0x00],
+        [0x20, 0x23, 0x12, 0x31, 0x24, 0x00, 0x00],
+        [0x00, 0x00, 0x12, 0x31, 0x23, 0x59, 0x00],
+    ] {
+        assert_eq!(
+            ccs.decode_tfield(&invalid),
+            Err(Errors::ParseError(ParsingErrors::ValueError))
+        );
+    }
+    assert_eq!(
+        CcsFormat::day_of_year(0)
+            .unwrap()
+            .decode_tfield(&[0x20, 0x23, 0x03, 0x66, 0x23, 0x59, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        ccs.decode(&[0x57, 0x20, 0x23, 0x12, 0x31, 0x23, 0x59, 0x00]),
+        Err(Errors::ParseError(ParsingErrors::InvalidPField))
+    );
+    assert_eq!(
+        CcsFormat::month_day(0).unwrap().encode(
+            Epoch::from_gregorian_tai_at_midnight(10_000, 1, 1),
+            &mut buf
+        ),
+        Err(Errors::Overflow)
+    );
+}
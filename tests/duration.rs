@@ -384,9 +384,10 @@ fn duration_floor_ceil_round() {
     assert_eq!(d.floor(1.seconds()), 4.minutes() + 13.seconds());
     assert_eq!(d.floor(3.seconds()), 4.minutes() + 12.seconds());
     assert_eq!(d.floor(9.minutes()), 0.minutes());
+    // A century is an integer number of seconds, so the MIN is aligned on 10 seconds.
     assert_eq!(
         (Duration::MIN + 10.seconds()).floor(10.seconds()),
-        Duration::MIN
+        Duration::MIN + 10.seconds()
     );
 
     // Ceil
@@ -587,3 +588,159 @@ fn test_min_max() {
     assert_eq!(d1, d1.max(d0));
     assert_eq!(d1, d0.max(d1));
 }
+
+#[test]
+fn test_checked_arithmetic() {
+    let one_ns = Unit::Nanosecond * 1;
+
+    // Addition and subtraction
+    assert_eq!(Duration::MAX.checked_add(one_ns), Err(Errors::Overflow));
+    assert_eq!(Duration::MIN.checked_sub(one_ns), Err(Errors::Overflow));
+    assert_eq!(
+        Duration::MAX.checked_sub(one_ns),
+        Ok(Duration::MAX - one_ns)
+    );
+    assert_eq!(
+        Duration::MIN.checked_add(one_ns),
+        Ok(Duration::MIN + one_ns)
+    );
+    assert_eq!(
+        (Duration::MAX - one_ns).checked_add(one_ns),
+        Ok(Duration::MAX)
+    );
+    assert_eq!(Duration::MIN.checked_add(Duration::MAX), Ok(Duration::ZERO));
+    assert_eq!(Duration::ZERO.checked_sub(Duration::MIN), Ok(Duration::MAX));
+    assert_eq!(
+        Duration::ZERO.checked_sub(Duration::MIN + one_ns),
+        Ok(Duration::MAX - one_ns)
+    );
+    assert_eq!(
+        (-5 * Unit::Century).checked_sub(3 * Unit::Century + one_ns),
+        Ok(-8 * Unit::Century - one_ns)
+    );
+    assert_eq!(
+        Duration::MAX.checked_sub(Duration::MIN),
+        Err(Errors::Overflow)
+    );
+
+    // The operators saturate towards the direction of the overflow.
+    assert_eq!(Duration::MAX + one_ns, Duration::MAX);
+    assert_eq!(Duration::MAX - Duration::MIN, Duration::MAX);
+    assert_eq!(Duration::MIN - Duration::MAX, Duration::MIN);
+    assert_eq!(Duration::MAX.saturating_sub(Duration::MIN), Duration::MAX);
+    assert_eq!(Duration::MIN.saturating_add(-one_ns), Duration::MIN);
+    // Representable sums close to the MIN do not saturate.
+    let near_min = Duration::MIN + 10 * Unit::Day;
+    assert_eq!(near_min + -1.days(), Duration::MIN + 9 * Unit::Day);
+    assert_eq!(
+        (Duration::MIN + 2 * Unit::Day).total_nanoseconds(),
+        Duration::MIN.total_nanoseconds() + 2 * 86_400_000_000_000
+    );
+
+    // Wrapping around the bounds
+    assert_eq!(Duration::MAX.wrapping_add(one_ns), Duration::MIN + one_ns);
+    assert_eq!(Duration::MIN.wrapping_sub(one_ns), Duration::MAX - one_ns);
+    assert_eq!(Duration::MAX.wrapping_sub(one_ns), Duration::MAX - one_ns);
+    assert_eq!(1.days().wrapping_mul(-3), -3 * Unit::Day);
+    assert_eq!((Unit::Century * 32_767).wrapping_mul(2), -2 * Unit::Century);
+
+    // Multiplication and division
+    assert_eq!(1.hours().checked_mul(24), Ok(Unit::Day * 1));
+    assert_eq!(
+        1.centuries().checked_mul(i64::from(i16::MAX) + 1),
+        Ok(Duration::MAX)
+    );
+    assert_eq!(
+        1.centuries().checked_mul(i64::from(i16::MAX) + 2),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(Duration::MAX.checked_mul(i64::MAX), Err(Errors::Overflow));
+    assert_eq!(Duration::MAX.saturating_mul(-2), Duration::MIN);
+    assert_eq!(1.days().checked_div(24), Ok(Unit::Hour * 1));
+    assert_eq!(1.days().checked_div(0), Err(Errors::Overflow));
+    assert_eq!(Duration::MIN.checked_div(-1), Ok(Duration::MAX));
+    assert_eq!(1.days().saturating_div(0), Duration::MAX);
+    assert_eq!((-1.days()).saturating_div(0), Duration::MIN);
+    assert_eq!(Duration::ZERO.saturating_div(0), Duration::ZERO);
+
+    // Conversion from the total nanoseconds
+    assert_eq!(
+        Duration::try_from_total_nanoseconds(Duration::MAX.total_nanoseconds()),
+        Ok(Duration::MAX)
+    );
+    assert_eq!(
+        Duration::try_from_total_nanoseconds(Duration::MAX.total_nanoseconds() + 1),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        Duration::try_from_total_nanoseconds(Duration::MIN.total_nanoseconds() - 1),
+        Err(Errors::Overflow)
+    );
+}
+
+#[test]
+fn test_total_nanoseconds_reciprocity() {
+    // The nanoseconds are always into the current century, including for negative centuries.
+    for centuries in [i16::MIN, -200, -2, -1, 0, 1, 2, 200, i16::MAX] {
+        for nanoseconds in [
+            0,
+            1,
+            NANOSECONDS_PER_CENTURY / 2,
+            NANOSECONDS_PER_CENTURY - 1,
+        ] {
+            let dur = Duration::from_parts(centuries, nanoseconds);
+            assert_eq!(
+                dur.total_nanoseconds(),
+                i128::from(centuries) * i128::from(NANOSECONDS_PER_CENTURY)
+                    + i128::from(nanoseconds)
+            );
+            assert_eq!(
+                Duration::from_total_nanoseconds(dur.total_nanoseconds()).to_parts(),
+                dur.to_parts()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_duration_division_remainder() {
+    // Number of whole frames in a pass, and the leftover.
+    let pass = 12.minutes() + 34.seconds() + 567.milliseconds();
+    let frame = 250.milliseconds();
+    assert_eq!(pass.div_euclid(frame), 3018);
+    assert_eq!(pass.rem_euclid(frame), 67.milliseconds());
+    assert_eq!(pass % frame, 67.milliseconds());
+    assert_eq!(pass / frame, 3018.268);
+
+    // Ratios are exact in their whole part, even over many centuries.
+    assert_eq!(Duration::MAX / 1.centuries(), 32768.0);
+    assert_eq!(Duration::MIN / 1.centuries(), -32768.0);
+    assert_eq!(1.days() / 1.nanoseconds(), 86_400_000_000_000.0);
+    assert_eq!(1.seconds() / Duration::ZERO, f64::INFINITY);
+    assert_eq!((-1).seconds() / Duration::ZERO, f64::NEG_INFINITY);
+    assert!((Duration::ZERO / Duration::ZERO).is_nan());
+
+    // Negative values: `%` has the sign of the dividend, `rem_euclid` is always positive.
+    let neg = (-1).seconds() - 100.milliseconds();
+    assert_eq!(neg % frame, (-100).milliseconds());
+    assert_eq!(neg % -frame, (-100).milliseconds());
+    assert_eq!(neg.div_euclid(frame), -5);
+    assert_eq!(neg.rem_euclid(frame), 150.milliseconds());
+    assert_eq!(neg.div_euclid(-frame), 5);
+    assert_eq!(neg.rem_euclid(-frame), 150.milliseconds());
+    assert_eq!(
+        frame * neg.div_euclid(frame) as i64 + neg.rem_euclid(frame),
+        neg
+    );
+
+    // Remainders across century boundaries are exact.
+    let century = 1.centuries();
+    let long = Duration::from_parts(-3, 7);
+    assert_eq!(long % century, 7.nanoseconds() - century);
+    assert_eq!(long.rem_euclid(century), 7.nanoseconds());
+    assert_eq!(long.div_euclid(century), -3);
+
+    let mut rem = 100.minutes();
+    rem %= 1.hours();
+    assert_eq!(rem, 40.minutes());
+}
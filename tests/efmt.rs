@@ -101,3 +101,221 @@ fn epoch_format_rfc2822() {
         epoch
     );
 }
+
+#[test]
+fn epoch_parse_names_and_weekdays() {
+    use core::str::FromStr;
+    use hifitime::ParsingErrors;
+
+    let bday = Epoch::from_gregorian_utc_hms(2000, 2, 29, 14, 57, 29);
+    let midnight = Epoch::from_gregorian_utc_at_midnight(2000, 2, 29);
+
+    assert_eq!(RFC2822.parse("Tue, 29 Feb 2000 14:57:29").unwrap(), bday);
+    assert_eq!(
+        RFC2822_LONG
+            .parse("Tuesday, 29 February 2000 14:57:29")
+            .unwrap(),
+        bday
+    );
+
+    // The time zone of a mail header is not part of the format: it is rejected instead of dropping the seconds.
+    let header = "Date: Tue, 29 Feb 2000 14:57:29 +0000";
+    let date = header.strip_prefix("Date: ").unwrap();
+    for date in [date, "Tue, 29 Feb 2000 14:57:29 GMT"] {
+        assert_eq!(
+            RFC2822.parse(date),
+            Err(Errors::ParseError(ParsingErrors::UnexpectedCharacter {
+                found: ' ',
+                option1: None,
+                option2: None,
+            })),
+            "{date}"
+        );
+    }
+    assert_eq!(
+        RFC2822.parse(date.trim_end_matches(" +0000")).unwrap(),
+        bday
+    );
+
+    // Vendor log formats, with upper case month names
+    assert_eq!(
+        Epoch::from_format_str("29-FEB-2000", "%d-%b-%Y").unwrap(),
+        midnight
+    );
+    assert_eq!(
+        Epoch::from_format_str("29-FEB-2000 14:57:29", "%d-%b-%Y %H:%M:%S").unwrap(),
+        bday
+    );
+    assert_eq!(
+        Epoch::from_format_str("Tue Feb 29 14:57:29 2000", "%a %b %d %H:%M:%S %Y").unwrap(),
+        bday
+    );
+
+    // Names at the end of the string are parsed and checked too
+    assert_eq!(
+        Epoch::from_format_str("2000 29 Feb", "%Y %d %b").unwrap(),
+        midnight
+    );
+    assert_eq!(
+        Epoch::from_format_str("2000-02-29 Tuesday", "%Y-%m-%d %A").unwrap(),
+        midnight
+    );
+    assert_eq!(
+        Epoch::from_format_str("2000-02-29 Wed", "%Y-%m-%d %a"),
+        Err(Errors::ParseError(ParsingErrors::WeekdayMismatch {
+            found: Weekday::Wednesday,
+            expected: Weekday::Tuesday
+        }))
+    );
+    assert_eq!(
+        Epoch::from_format_str("2000-02-29 Tuesdax", "%Y-%m-%d %A"),
+        Err(Errors::ParseError(ParsingErrors::UnknownWeekday))
+    );
+    assert_eq!(
+        Epoch::from_format_str("2000 29 Fex", "%Y %d %b"),
+        Err(Errors::ParseError(ParsingErrors::UnknownMonthName))
+    );
+
+    // The C89 decimal weekday starts on Sunday as zero, and seven is also accepted for Sunday
+    let fmt = Format::from_str("%w %Y-%m-%d").unwrap();
+    assert_eq!(fmt.parse("2 2000-02-29").unwrap(), midnight);
+    assert_eq!(
+        fmt.parse("3 2000-02-29"),
+        Err(Errors::ParseError(ParsingErrors::WeekdayMismatch {
+            found: Weekday::Wednesday,
+            expected: Weekday::Tuesday
+        }))
+    );
+    let sunday = Epoch::from_gregorian_utc_at_midnight(2000, 3, 5);
+    assert_eq!(fmt.parse("0 2000-03-05").unwrap(), sunday);
+    assert_eq!(fmt.parse("7 2000-03-05").unwrap(), sunday);
+    assert_eq!(
+        fmt.parse("8 2000-03-05"),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+    assert_eq!(
+        Epoch::from_format_str("2000-02-29 2", "%Y-%m-%d %w").unwrap(),
+        midnight
+    );
+
+    // A single digit at the end of the string is not skipped
+    assert_eq!(
+        Epoch::from_format_str("2000-02-29 1", "%Y-%m-%d %H").unwrap(),
+        midnight + Unit::Hour * 1
+    );
+
+    // The weekday is also checked with a day of year
+    assert_eq!(
+        Epoch::from_format_str("2000-060 Wed", "%Y-%j %a").unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2000, 3, 1)
+    );
+}
+
+#[test]
+fn epoch_ccsds_ascii() {
+    let e = Epoch::from_gregorian_utc(2024, 2, 29, 12, 34, 56, 789_000_000);
+
+    assert_eq!(
+        format!("{}", Formatter::new(e, CCSDS_ASCII_A)),
+        "2024-02-29T12:34:56.789000000Z"
+    );
+    assert_eq!(
+        format!("{}", Formatter::new(e, CCSDS_ASCII_B)),
+        "2024-060T12:34:56.789000000Z"
+    );
+    for s in [
+        "2024-02-29T12:34:56.789Z",
+        "2024-02-29T12:34:56.789000000Z",
+        "2024-02-29T12:34:56.789000000000Z",
+        "2024-02-29T12:34:56.789",
+    ] {
+        assert_eq!(CCSDS_ASCII_A.parse(s).unwrap(), e, "{s}");
+    }
+    for s in ["2024-060T12:34:56.789Z", "2024-060T12:34:56.7890000001Z"] {
+        assert_eq!(CCSDS_ASCII_B.parse(s).unwrap(), e, "{s}");
+    }
+
+    // Whole seconds, and a single digit of the fraction
+    let e = Epoch::from_gregorian_utc_hms(2023, 1, 1, 0, 0, 7);
+    assert_eq!(
+        format!("{}", Formatter::new(e, CCSDS_ASCII_A)),
+        "2023-01-01T00:00:07Z"
+    );
+    assert_eq!(
+        format!("{}", Formatter::new(e, CCSDS_ASCII_B)),
+        "2023-001T00:00:07Z"
+    );
+    assert_eq!(CCSDS_ASCII_A.parse("2023-01-01T00:00:07Z").unwrap(), e);
+    assert_eq!(CCSDS_ASCII_B.parse("2023-001T00:00:07Z").unwrap(), e);
+    assert_eq!(
+        CCSDS_ASCII_B.parse("2023-365T23:59:59.5Z").unwrap(),
+        Epoch::from_gregorian_utc(2023, 12, 31, 23, 59, 59, 500_000_000)
+    );
+
+    // Nanoseconds and leap seconds survive a round trip.
+    for e in [
+        Epoch::from_gregorian_utc(1999, 12, 31, 23, 59, 59, 999_999_999),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 1),
+        Epoch::from_gregorian_utc(2000, 1, 1, 0, 0, 0, 10),
+    ] {
+        for format in [CCSDS_ASCII_A, CCSDS_ASCII_B] {
+            let s = format!("{}", Formatter::new(e, format));
+            assert_eq!(format.parse(&s).unwrap(), e, "{s}");
+        }
+    }
+    // Epochs in other time scales are printed in UTC, and parsed back to the same instant.
+    let utc = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    for ts in [TimeScale::TAI, TimeScale::GPST, TimeScale::TT] {
+        let e = utc.in_time_scale(ts);
+        assert_eq!(
+            format!("{}", Formatter::new(e, CCSDS_ASCII_A)),
+            "2023-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            format!("{}", Formatter::new(e, CCSDS_ASCII_B)),
+            "2023-001T00:00:00Z"
+        );
+        for format in [CCSDS_ASCII_A, CCSDS_ASCII_B] {
+            let s = format!("{}", Formatter::new(e, format));
+            assert_eq!(format.parse(&s).unwrap(), e, "{s}");
+        }
+    }
+
+    // Errors
+    assert!(CCSDS_ASCII_B.parse("2023-366T00:00:00Z").is_err());
+    assert!(CCSDS_ASCII_B.parse("2023-000T00:00:00Z").is_err());
+    assert!(CCSDS_ASCII_A.parse("2023-01-01T00:00:00.1x2Z").is_err());
+}
+
+#[test]
+fn epoch_tai64n() {
+    use core::str::FromStr;
+    let e = Epoch::from_gregorian_tai(1999, 8, 24, 4, 4, 15, 787_492_500);
+
+    assert_eq!(
+        format!("{}", Formatter::new(e, TAI64N)),
+        "@4000000037c219bf2ef02e94"
+    );
+    // The label is always in TAI.
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::new(e.in_time_scale(TimeScale::GPST), TAI64N)
+        ),
+        "@4000000037c219bf2ef02e94"
+    );
+    assert_eq!(TAI64N.parse("@4000000037c219bf2ef02e94").unwrap(), e);
+    assert_eq!(
+        TAI64N.parse("@4000000037c219bf").unwrap(),
+        e.floor(Unit::Second * 1)
+    );
+    assert!(TAI64N.parse("@4000000037c219bf2ef02e9").is_err());
+
+    // Within other tokens, e.g. a log line
+    let fmt = Format::from_str("%@ %T").unwrap();
+    assert_eq!(
+        format!("{}", Formatter::new(e, fmt)),
+        "@4000000037c219bf2ef02e94 TAI"
+    );
+    assert_eq!(fmt.parse("@4000000037c219bf2ef02e94 TAI").unwrap(), e);
+}
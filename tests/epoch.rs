@@ -5,7 +5,7 @@ use hifitime::{
     is_gregorian_valid, Duration, Epoch, Errors, ParsingErrors, TimeScale, TimeUnits, Unit,
     Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, GPST_REF_EPOCH, GST_REF_EPOCH, J1900_OFFSET,
     J1900_REF_EPOCH, J2000_OFFSET, MJD_OFFSET, SECONDS_BDT_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET,
-    SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY,
+    SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY, UNIX_REF_EPOCH,
 };
 
 use hifitime::efmt::{Format, Formatter};
@@ -328,6 +328,11 @@ fn datetime_invalid_dates() {
     assert!(!is_gregorian_valid(2001, 2, 29, 22, 8, 47, 0));
     assert!(!is_gregorian_valid(2016, 12, 31, 23, 59, 61, 0));
     assert!(!is_gregorian_valid(2015, 6, 30, 23, 59, 61, 0));
+    // Any day may end with a leap second, which is checked when building an Epoch.
+    assert!(is_gregorian_valid(2020, 5, 5, 23, 59, 60, 0));
+    assert!(!is_gregorian_valid(2016, 12, 31, 23, 58, 60, 0));
+    assert!(Epoch::maybe_from_gregorian_utc(2020, 5, 5, 23, 59, 60, 0).is_err());
+    assert!(Epoch::maybe_from_gregorian(2020, 5, 5, 23, 59, 60, 0, TimeScale::TAI).is_err());
 }
 
 #[test]
@@ -525,10 +530,12 @@ fn unix() {
         format!("{}", unix_epoch.in_time_scale(TimeScale::UTC)),
         "1970-01-01T00:00:00 UTC"
     );
+    // TAI - UTC was 8.000082 seconds on 1970 January 01 (SOFA `iauDat`).
     assert_eq!(
         format!("{:x}", unix_epoch.in_time_scale(TimeScale::TAI)),
-        "1970-01-01T00:00:00 TAI"
+        "1970-01-01T00:00:08.000082000 TAI"
     );
+    assert_eq!(unix_epoch, UNIX_REF_EPOCH);
     // Print as UNIX seconds
     assert_eq!(format!("{:p}", unix_epoch), "0");
 
@@ -561,9 +568,9 @@ fn unix() {
 fn naif_spice_et_tdb_verification() {
     // The maximum error due to small perturbations accounted for in ESA algorithm but not SPICE algorithm.
     let max_tdb_et_err = 32 * Unit::Microsecond;
-    // Prior to 01 JAN 1972, IERS claims that there is no leap second at all but SPICE claims that there are nine (9) leap seconds
-    // between TAI and UTC. Hifitime also claims that there are zero leap seconds (to ensure correct computation of UNIX time at its reference time).
-    let spice_utc_tai_ls_err = 9.0;
+    // Prior to 01 JAN 1972, SPICE claims that there are nine (9) leap seconds between TAI and UTC, whereas Hifitime
+    // uses the drifting offsets of SOFA's `iauDat` from 1960 to 1972.
+    let spice_utc_tai_ls = 9.0;
     // SPICE will only output up to 6 digits for the JDE computation. This is likely due to the precision limitation of the `double`s type.
     // This means that a SPICE JDE is precise to 0.008 seconds, whereas a JDE in Hifitime maintains its nanosecond precision.
     let spice_jde_precision = 1e-7;
@@ -591,13 +598,18 @@ fn naif_spice_et_tdb_verification() {
         );
 
         // Test ET computation
-        let extra_seconds = if epoch.leap_seconds_iers() == 0 {
-            spice_utc_tai_ls_err
+        // The SOFA offsets are not whole seconds, so the ET seconds of both libraries are only equal to the precision of a double
+        // (0.24 microseconds in the 1960s).
+        let (extra_seconds, et_err_s) = if epoch.leap_seconds_iers() == 0 {
+            (
+                spice_utc_tai_ls - epoch.leap_seconds(false).unwrap_or(0.0),
+                5e-7,
+            )
         } else {
-            0.0
+            (0.0, EPSILON)
         };
         assert!(
-            (epoch.to_et_seconds() - et_s + extra_seconds).abs() < EPSILON,
+            (epoch.to_et_seconds() - et_s + extra_seconds).abs() < et_err_s,
             "{} failed ET test",
             epoch
         );
@@ -911,7 +923,7 @@ fn test_format() {
     assert_eq!(format!("{epoch:o}"), "1346541887000000000"); // GPS nanoseconds
 
     // Ensure that the appropriate time system is used in the debug print.
-    for ts_u8 in 0..=7 {
+    for ts_u8 in 0..=10 {
         let ts: TimeScale = ts_u8.into();
 
         let recent = Epoch::from_gregorian(2020, 9, 6, 23, 24, 29, 2, ts);
@@ -920,8 +932,15 @@ fn test_format() {
         let way_old = Epoch::from_gregorian(1820, 9, 6, 23, 24, 29, 2, ts);
 
         // TDB building may have a 2 nanosecond error is seems
+        // TCB and TCG seconds are shorter than TAI seconds by L_B and L_G respectively,
+        // i.e. 31 ns and 1.4 ns over two seconds.
+        let tolerance = match ts {
+            TimeScale::TCB => 32 * Unit::Nanosecond,
+            TimeScale::TCG => 4 * Unit::Nanosecond,
+            _ => 2 * Unit::Nanosecond,
+        };
         assert!(
-            ((post_ref - pre_ref) - 2 * Unit::Second).abs() < 2 * Unit::Nanosecond,
+            ((post_ref - pre_ref) - 2 * Unit::Second).abs() < tolerance,
             "delta time should be 2 s in {ts:?} but is {}",
             post_ref - pre_ref
         );
@@ -955,6 +974,10 @@ fn test_format() {
                     TimeScale::GPST => format!("{epoch:x}").replace("TAI", "GPST"),
                     TimeScale::GST => format!("{epoch:x}").replace("TAI", "GST"),
                     TimeScale::BDT => format!("{epoch:x}").replace("TAI", "BDT"),
+                    TimeScale::TCB | TimeScale::TCG | TimeScale::UT1 => {
+                        let in_tai = Epoch::from_tai_duration(epoch.to_duration_since_j1900());
+                        format!("{in_tai:x}").replace("TAI", &ts.to_string())
+                    }
                 }
             );
 
@@ -1053,8 +1076,8 @@ fn test_leap_seconds_iers() {
         epoch_from_utc_greg.duration_in_year(),
         (31 + 29 + 31 + 30 + 31 + 30) * Unit::Day - Unit::Second
     );
-    // Just after it.
-    let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 0);
+    // Just after it, which is ten seconds after midnight in TAI.
+    let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 10);
     assert_eq!(epoch_from_utc_greg.leap_seconds_iers(), 10);
     assert_eq!(epoch_from_utc_greg1.leap_seconds_iers(), 11);
 }
@@ -1212,8 +1235,14 @@ fn test_timescale_recip() {
                 // There is limitation in the ET scale due to the Newton Raphson iteration.
                 // So let's check for a near equality
                 // TODO: Make this more strict
+                // Before 1972, the SOFA offsets are not whole seconds, so the error is that of a double (0.24 microseconds in the 1960s).
+                let max_err = if utc_epoch.leap_seconds_iers() == 0 {
+                    500 * Unit::Nanosecond
+                } else {
+                    150 * Unit::Nanosecond
+                };
                 assert!(
-                    (utc_epoch - from_dur).abs() < 150 * Unit::Nanosecond,
+                    (utc_epoch - from_dur).abs() < max_err,
                     "ET recip error = {} for {}",
                     utc_epoch - from_dur,
                     utc_epoch
@@ -1277,6 +1306,61 @@ fn test_timescale_recip() {
     recip_func(Epoch::from_gregorian_utc(2075, 4, 30, 23, 59, 54, 0));
 }
 
+/// From 1960 to 1972, TAI - UTC drifted linearly as defined by SOFA's `iauDat`.
+#[test]
+fn test_utc_drift_before_1972() {
+    // TAI - UTC in nanoseconds as computed by the `iauDat` algorithm at these UTC dates.
+    for (year, month, day, hour, minute, second, delta_at_ns) in [
+        (1960, 6, 15, 12, 0, 0, 1_159_266_000),
+        (1961, 3, 1, 6, 30, 0, 1_499_633_000),
+        (1961, 9, 20, 0, 0, 0, 1_712_370_000),
+        (1962, 7, 4, 9, 15, 30, 2_052_960_090),
+        (1963, 12, 1, 0, 0, 0, 2_730_974_800),
+        (1964, 5, 17, 18, 45, 12, 3_044_358_680),
+        (1964, 10, 1, 0, 0, 0, 3_320_898_000),
+        (1965, 2, 1, 3, 0, 0, 3_580_468_000),
+        (1965, 5, 5, 5, 5, 5, 3_801_108_575),
+        (1965, 8, 1, 0, 0, 0, 4_014_882_000),
+        (1965, 11, 30, 23, 0, 0, 4_272_940_000),
+        (1967, 6, 1, 12, 0, 0, 5_651_938_000),
+        (1969, 7, 20, 20, 17, 40, 7_574_593_800),
+        (1970, 1, 1, 0, 0, 0, 8_000_082_000),
+        (1971, 12, 31, 12, 0, 0, 9_890_946_000),
+    ] {
+        let utc = Epoch::from_gregorian_utc_hms(year, month, day, hour, minute, second);
+        let tai = Epoch::from_gregorian_tai_hms(year, month, day, hour, minute, second);
+        let delta_at = delta_at_ns * Unit::Nanosecond;
+
+        assert_eq!(utc - tai, delta_at, "{utc} TAI - UTC");
+        assert!(
+            (utc.leap_seconds(false).unwrap() - delta_at.to_seconds()).abs() < 1e-9,
+            "{utc} leap seconds"
+        );
+        assert_eq!(utc.leap_seconds(true), None, "{utc} IERS leap seconds");
+        // Converting back to UTC must be exact.
+        assert_eq!(utc.to_utc_duration(), tai.to_tai_duration(), "{utc} UTC");
+        assert_eq!(
+            utc.to_gregorian_utc(),
+            (year, month, day, hour, minute, second, 0),
+            "{utc} Gregorian"
+        );
+    }
+
+    // UTC is not defined before 1960, and the offset jumps to ten seconds with the first leap second of the IERS.
+    let pre_utc = Epoch::from_gregorian_utc_hms(1959, 12, 31, 23, 0, 0);
+    assert_eq!(pre_utc.leap_seconds(false), None);
+    assert_eq!(
+        pre_utc,
+        Epoch::from_gregorian_tai_hms(1959, 12, 31, 23, 0, 0)
+    );
+    let first_ls = Epoch::from_gregorian_utc_at_midnight(1972, 1, 1);
+    assert_eq!(first_ls.leap_seconds(false), Some(10.0));
+    assert_eq!(
+        first_ls - Epoch::from_gregorian_tai_at_midnight(1972, 1, 1),
+        10 * Unit::Second
+    );
+}
+
 /// Tests that the time scales are included when performing operations on Epochs.
 #[test]
 fn test_add_durations_over_leap_seconds() {
@@ -1285,13 +1369,14 @@ fn test_add_durations_over_leap_seconds() {
     let pre_ls_utc = Epoch::from_gregorian_utc_at_noon(1971, 12, 31);
     let pre_ls_tai = pre_ls_utc.in_time_scale(TimeScale::TAI);
 
-    // Before the first leap second, there is no time difference between both epochs (because only IERS announced leap seconds are accounted for by default).
+    // Both epochs are the same instant, only their time scales differ.
     assert_eq!(pre_ls_utc - pre_ls_tai, Duration::ZERO);
     // When add 24 hours to either of the them, the UTC initialized epoch will increase the duration by 36 hours in UTC, which will cause a leap second jump.
-    // Therefore the difference between both epochs then becomes 10 seconds.
+    // Before 1972, TAI - UTC drifted by 2.592 ms per day since 1966, and was 9.890946 seconds on 1971 Dec 31 at noon UTC (SOFA `iauDat`).
+    // Therefore the difference between both epochs then becomes the remainder of the jump to 10 seconds.
     assert_eq!(
         (pre_ls_utc + 1 * Unit::Day) - (pre_ls_tai + 1 * Unit::Day),
-        10 * Unit::Second
+        10 * Unit::Second - 9_890_946 * Unit::Microsecond
     );
     // Of course this works the same way the other way around
     let post_ls_utc = pre_ls_utc + Unit::Day;
@@ -1845,6 +1930,164 @@ fn test_leap_seconds_file() {
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_seconds_file_validation() {
+    use hifitime::leap_seconds::{LeapSecondProvider, LeapSecondsFile, LeapSecondsValidation};
+    use hifitime::ParsingErrors;
+    use std::fs;
+
+    let expires_at = Epoch::from_gregorian_utc_at_midnight(2023, 6, 28);
+    let provider = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    assert_eq!(provider.expires_at(), Some(expires_at));
+    assert!(!provider.is_expired_at(expires_at - Unit::Second * 1));
+    assert!(provider.is_expired_at(expires_at));
+
+    // In strict mode, the file must not have expired at the reference epoch.
+    let before_expiry = expires_at - Unit::Day * 1;
+    let strict = LeapSecondsFile::from_path_with(
+        "data/leap-seconds.list",
+        LeapSecondsValidation::Strict,
+        before_expiry,
+    )
+    .unwrap();
+    assert_eq!(strict.expires_at(), Some(expires_at));
+    assert_eq!(strict.leap_seconds(), provider.leap_seconds());
+    for at in [expires_at, Epoch::now().unwrap()] {
+        assert_eq!(
+            LeapSecondsFile::from_path_with(
+                "data/leap-seconds.list",
+                LeapSecondsValidation::Strict,
+                at
+            )
+            .unwrap_err(),
+            Errors::ParseError(ParsingErrors::LeapSecondsFileExpired(expires_at))
+        );
+    }
+    // An expired file is accepted in lenient mode.
+    assert!(LeapSecondsFile::from_path_with(
+        "data/leap-seconds.list",
+        LeapSecondsValidation::Lenient,
+        expires_at
+    )
+    .is_ok());
+
+    let contents = fs::read_to_string("data/leap-seconds.list").unwrap();
+    let tmp_dir = std::env::temp_dir();
+
+    // Tampering with the data is detected in either mode.
+    let tampered_path = tmp_dir.join("hifitime-tampered-leap-seconds.list");
+    fs::write(
+        &tampered_path,
+        contents.replace("3692217600\t37", "3692217600\t38"),
+    )
+    .unwrap();
+    for validation in [
+        LeapSecondsValidation::Strict,
+        LeapSecondsValidation::Lenient,
+    ] {
+        assert_eq!(
+            LeapSecondsFile::from_path_with(&tampered_path, validation, before_expiry).unwrap_err(),
+            Errors::ParseError(ParsingErrors::LeapSecondsFileCorrupted)
+        );
+    }
+
+    // A missing hash is only accepted in lenient mode.
+    let unhashed_path = tmp_dir.join("hifitime-unhashed-leap-seconds.list");
+    let unhashed: Vec<&str> = contents
+        .lines()
+        .filter(|line| !line.starts_with("#h"))
+        .collect();
+    fs::write(&unhashed_path, unhashed.join("\n")).unwrap();
+    assert!(LeapSecondsFile::from_path(&unhashed_path).is_ok());
+    assert_eq!(
+        LeapSecondsFile::from_path_with(
+            &unhashed_path,
+            LeapSecondsValidation::Strict,
+            before_expiry
+        )
+        .unwrap_err(),
+        Errors::ParseError(ParsingErrors::LeapSecondsFileCorrupted)
+    );
+
+    // A missing expiration date is only accepted in lenient mode.
+    let unexpiring_path = tmp_dir.join("hifitime-unexpiring-leap-seconds.list");
+    let unexpiring: Vec<&str> = contents
+        .lines()
+        .filter(|line| !line.starts_with("#@") && !line.starts_with("#h"))
+        .collect();
+    fs::write(&unexpiring_path, unexpiring.join("\n")).unwrap();
+    let unexpiring_provider = LeapSecondsFile::from_path(&unexpiring_path).unwrap();
+    assert_eq!(unexpiring_provider.expires_at(), None);
+    assert!(!unexpiring_provider.is_expired_at(Epoch::from_gregorian_utc_at_midnight(2100, 1, 1)));
+    assert_eq!(unexpiring_provider.leap_seconds(), provider.leap_seconds());
+    assert_eq!(
+        LeapSecondsFile::from_path_with(
+            &unexpiring_path,
+            LeapSecondsValidation::Strict,
+            before_expiry
+        )
+        .unwrap_err(),
+        Errors::ParseError(ParsingErrors::LeapSecondsFileExpirationMissing)
+    );
+
+    // The leap seconds are sorted chronologically, whatever their order in the file.
+    let reversed_path = tmp_dir.join("hifitime-reversed-leap-seconds.list");
+    let (comments, mut data): (Vec<&str>, Vec<&str>) = unhashed
+        .iter()
+        .partition(|line| line.starts_with('#') || line.is_empty());
+    data.reverse();
+    fs::write(&reversed_path, [comments, data].concat().join("\n")).unwrap();
+    assert_eq!(
+        LeapSecondsFile::from_path(&reversed_path)
+            .unwrap()
+            .leap_seconds(),
+        provider.leap_seconds()
+    );
+
+    fs::remove_file(tampered_path).unwrap();
+    fs::remove_file(unhashed_path).unwrap();
+    fs::remove_file(unexpiring_path).unwrap();
+    fs::remove_file(reversed_path).unwrap();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_seconds_kernel() {
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondsKernel};
+    use hifitime::DeltetConstants;
+
+    let kernel = LeapSecondsKernel::from_path("naif0012.txt").unwrap();
+    let deltet = kernel.deltet();
+
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 11, 29, 12, 34, 56);
+    assert_eq!(
+        epoch.leap_seconds_with(true, kernel),
+        epoch.leap_seconds_with(true, LatestLeapSeconds::default())
+    );
+
+    // The constants of naif0012.txt are the default ones.
+    assert_eq!(epoch.to_et_duration_with(&deltet), epoch.to_et_duration());
+    let et = epoch.to_et_duration();
+    assert_eq!(
+        Epoch::from_et_duration_with(et, &deltet),
+        Epoch::from_et_duration(et)
+    );
+
+    // Without the periodic term, ET is exactly TT.
+    let no_periodic = DeltetConstants {
+        k: 0.0,
+        ..Default::default()
+    };
+    let tt_since_j2000 = epoch.to_tt_duration() - hifitime::J2000_TO_J1900_DURATION;
+    let et_no_periodic = epoch.to_et_duration_with(&no_periodic);
+    assert!((et_no_periodic - tt_since_j2000).abs() < 2 * Unit::Nanosecond);
+    assert!(
+        (Epoch::from_et_duration_with(et_no_periodic, &no_periodic) - epoch).abs()
+            < 2 * Unit::Nanosecond
+    );
+}
+
 #[test]
 fn regression_test_gh_204() {
     use core::str::FromStr;
@@ -1865,3 +2108,396 @@ fn regression_test_gh_204() {
     let e1900_m1 = Epoch::from_str("1899-12-31T23:59:59 TAI").unwrap();
     assert_eq!(format!("{e1900_m1:x}"), "1899-12-31T23:59:59 TAI");
 }
+
+#[test]
+fn test_tcb_tcg() {
+    use core::str::FromStr;
+
+    // At J2000 TT, TCG - TT is about 0.5058 s and TCB - TDB is about 11.2537 s (IERS Conventions 2010, chapter 10).
+    let j2000_tt = Epoch::from_tt_duration(Unit::Second * 3_155_716_800);
+    let tcg_tt = j2000_tt.to_tcg_duration() - j2000_tt.to_tt_duration();
+    assert!((tcg_tt - 505_833_286 * Unit::Nanosecond).abs() < Unit::Microsecond);
+    let tcb_tdb = j2000_tt.to_tcb_duration() - j2000_tt.to_tdb_duration();
+    assert!((tcb_tdb - 11.253_787 * Unit::Second).abs() < Unit::Microsecond);
+
+    // On 1977 January 01 at midnight TAI, TCG matches TT, and TCB only differs from TDB by TDB_0.
+    let t0 = Epoch::from_gregorian_tai_at_midnight(1977, 1, 1);
+    assert_eq!(t0.to_tcg_duration(), t0.to_tt_duration());
+    assert_eq!(
+        t0.to_tcb_duration() - t0.to_tdb_duration(),
+        65.5 * Unit::Microsecond
+    );
+
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 9, 6, 23, 24, 29);
+    for ts in [TimeScale::TCB, TimeScale::TCG] {
+        let in_ts = epoch.in_time_scale(ts);
+        assert_eq!(in_ts.time_scale, ts);
+        // Round trip through the duration in that time scale
+        assert_eq!(Epoch::from_duration(in_ts.to_duration(), ts), epoch);
+        // Round trip through the Gregorian representation
+        let as_str = format!("{in_ts:?}");
+        assert!(as_str.ends_with(&format!(" {ts}")));
+        assert_eq!(Epoch::from_str(&as_str).unwrap(), epoch);
+        assert_eq!(
+            format!(
+                "{}",
+                Formatter::new(in_ts, Format::from_str("%Y-%m-%dT%H:%M:%S.%f %T").unwrap())
+            ),
+            as_str
+        );
+        assert_eq!(
+            Epoch::from_format_str(&as_str, "%Y-%m-%dT%H:%M:%S.%f %T").unwrap(),
+            epoch
+        );
+    }
+
+    assert_eq!(
+        format!("{:?}", epoch.in_time_scale(TimeScale::TCG)),
+        "2022-09-06T23:25:39.188680256 TCG"
+    );
+    assert_eq!(
+        format!("{:?}", epoch.in_time_scale(TimeScale::TCB)),
+        "2022-09-06T23:26:00.534619529 TCB"
+    );
+    // Seconds past the reference epoch are also supported
+    let tcb = Epoch::from_tcb_seconds(epoch.to_tcb_seconds());
+    assert!((tcb - epoch).abs() < Unit::Microsecond);
+    let tcg = Epoch::from_tcg_seconds(epoch.to_tcg_seconds());
+    assert!((tcg - epoch).abs() < Unit::Microsecond);
+}
+
+#[test]
+fn test_smeared_utc() {
+    use hifitime::leap_seconds::SmearModel;
+
+    // TAI - UTC went from 36 to 37 seconds with the leap second of 2016 December 31.
+    let noon_before = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    let noon_after = Epoch::from_gregorian_utc_at_noon(2017, 1, 1);
+    let leap_second = Epoch::from_gregorian_tai_hms(2017, 1, 1, 0, 0, 36); // 23:59:60 UTC
+    let midnight = Epoch::from_gregorian_tai_hms(2017, 1, 1, 0, 0, 37);
+
+    let google = SmearModel::Linear24h;
+    // Outside of the smear window and at its edges, the smeared clock matches UTC.
+    for epoch in [
+        noon_before - 1 * Unit::Hour,
+        noon_before,
+        noon_after,
+        noon_after + 1 * Unit::Hour,
+    ] {
+        assert_eq!(
+            epoch.to_smeared_utc_duration(google),
+            epoch.to_utc_duration()
+        );
+        assert_eq!(
+            Epoch::from_smeared_utc_duration(epoch.to_utc_duration(), google),
+            epoch
+        );
+    }
+    // Within the window, each smeared second lasts 86401 / 86400 SI seconds.
+    let start = noon_before.to_utc_duration();
+    assert_eq!(
+        leap_second.to_smeared_utc_duration(google),
+        start + 43_199_500_005_787_i64 * Unit::Nanosecond
+    );
+    assert_eq!(
+        midnight.to_smeared_utc_duration(google),
+        start + 43_200_499_994_213_i64 * Unit::Nanosecond
+    );
+    // Smeared midnight is the middle of the leap second.
+    assert_eq!(
+        Epoch::from_smeared_utc_duration(start + 12 * Unit::Hour, google),
+        leap_second + 500 * Unit::Millisecond
+    );
+
+    let utc_sls = SmearModel::UtcSls;
+    // UTC-SLS only smears the last 1000 seconds of UTC before the leap second.
+    let sls_start = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 43, 20);
+    assert_eq!(
+        noon_before.to_smeared_utc_duration(utc_sls),
+        noon_before.to_utc_duration()
+    );
+    assert_eq!(
+        sls_start.to_smeared_utc_duration(utc_sls),
+        sls_start.to_utc_duration()
+    );
+    // The leap second starts at 23:59:59.000999001 UTC-SLS, and smeared midnight is true midnight.
+    assert_eq!(
+        leap_second.to_smeared_utc_duration(utc_sls),
+        sls_start.to_utc_duration() + 999_000_999_001_i64 * Unit::Nanosecond
+    );
+    assert_eq!(
+        midnight.to_smeared_utc_duration(utc_sls),
+        midnight.to_utc_duration()
+    );
+    assert_eq!(
+        Epoch::from_smeared_utc_duration(sls_start.to_utc_duration() + 500 * Unit::Second, utc_sls),
+        sls_start + 500_500 * Unit::Millisecond
+    );
+
+    // Smeared timestamps convert back exactly, epochs within a nanosecond because the smeared clock ticks fewer times.
+    for model in [google, utc_sls] {
+        for epoch in [
+            noon_before + 6 * Unit::Hour + 123_456_789 * Unit::Nanosecond,
+            sls_start + 1 * Unit::Nanosecond,
+            leap_second,
+            leap_second + 999_999_999 * Unit::Nanosecond,
+            midnight - 1 * Unit::Nanosecond,
+            midnight,
+            noon_after - 1 * Unit::Nanosecond,
+        ] {
+            let smeared = epoch.to_smeared_utc_duration(model);
+            let back = Epoch::from_smeared_utc_duration(smeared, model);
+            assert!(
+                (back - epoch).abs() <= 1 * Unit::Nanosecond,
+                "{model:?} {epoch}"
+            );
+            assert_eq!(
+                back.to_smeared_utc_duration(model),
+                smeared,
+                "{model:?} {epoch}"
+            );
+            assert_eq!(back.time_scale, TimeScale::UTC);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_second_gregorian() {
+    use core::str::FromStr;
+    use hifitime::efmt::consts::ISO8601;
+
+    // A leap second was inserted at the end of 2016 December 31: TAI - UTC went from 36 to 37 seconds.
+    let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    for (tai_offset_ns, utc_str, rfc3339) in [
+        (
+            33_000_000_000,
+            "2016-12-31T23:59:57 UTC",
+            "2016-12-31T23:59:57+00:00",
+        ),
+        (
+            35_000_000_000,
+            "2016-12-31T23:59:59 UTC",
+            "2016-12-31T23:59:59+00:00",
+        ),
+        (
+            36_000_000_000,
+            "2016-12-31T23:59:60 UTC",
+            "2016-12-31T23:59:60+00:00",
+        ),
+        (
+            36_500_000_000,
+            "2016-12-31T23:59:60.500000000 UTC",
+            "2016-12-31T23:59:60.500000000+00:00",
+        ),
+        (
+            37_000_000_000,
+            "2017-01-01T00:00:00 UTC",
+            "2017-01-01T00:00:00+00:00",
+        ),
+    ] {
+        let epoch = Epoch::from_tai_duration(
+            midnight.to_tai_duration() - 37 * Unit::Second
+                + Duration::from_total_nanoseconds(tai_offset_ns),
+        )
+        .in_time_scale(TimeScale::UTC);
+
+        assert_eq!(format!("{epoch}"), utc_str);
+        assert_eq!(epoch.to_rfc3339(), rfc3339);
+        assert_eq!(Epoch::from_str(utc_str).unwrap(), epoch);
+        assert_eq!(Epoch::from_str(rfc3339).unwrap(), epoch);
+
+        let formatted = format!("{}", Formatter::new(epoch, ISO8601));
+        assert_eq!(ISO8601.parse(&formatted).unwrap(), epoch);
+    }
+
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 250_000_000);
+    assert_eq!(
+        leap.to_gregorian_utc(),
+        (2016, 12, 31, 23, 59, 60, 250_000_000)
+    );
+    assert_eq!(
+        leap.to_tai_duration(),
+        midnight.to_tai_duration() - 750 * Unit::Millisecond
+    );
+    assert_eq!(
+        format!("{}", Formatter::new(leap, ISO8601)),
+        "2016-12-31T23:59:60.250000000 UTC"
+    );
+
+    // The first leap second of UTC
+    let first = Epoch::from_gregorian_utc(1972, 6, 30, 23, 59, 60, 0);
+    assert_eq!(format!("{first}"), "1972-06-30T23:59:60 UTC");
+    assert_eq!(format!("{first:x}"), "1972-07-01T00:00:10 TAI");
+
+    // Second 60 only exists on days when a leap second was inserted.
+    assert!(Epoch::maybe_from_gregorian_utc(2016, 12, 30, 23, 59, 60, 0).is_err());
+    assert!(Epoch::maybe_from_gregorian_utc(2017, 6, 30, 23, 59, 60, 0).is_err());
+    assert!(Epoch::from_str("2016-12-30T23:59:60 UTC").is_err());
+}
+
+#[test]
+fn test_gregorian_duration_bounds() {
+    // The Gregorian conversions are valid across the whole range of a Duration, i.e. +/- 32768 centuries around J1900.
+    for (duration, expected) in [
+        (Duration::MIN, (-3_274_968, 9, 18, 0, 0, 0, 0)),
+        (
+            Duration::MIN + Unit::Nanosecond,
+            (-3_274_968, 9, 18, 0, 0, 0, 1),
+        ),
+        (
+            Duration::MAX - Unit::Nanosecond,
+            (3_278_767, 4, 15, 23, 59, 59, 999_999_999),
+        ),
+        (Duration::MAX, (3_278_767, 4, 16, 0, 0, 0, 0)),
+    ] {
+        let epoch = Epoch::from_tai_duration(duration);
+        assert_eq!(epoch.to_gregorian_tai(), expected);
+
+        let (y, mm, dd, hh, min, s, nanos) = expected;
+        assert_eq!(
+            Epoch::from_gregorian_tai(y, mm, dd, hh, min, s, nanos),
+            epoch
+        );
+    }
+
+    // Dates far from J1900 are computed as exactly as those near it.
+    let epoch = Epoch::from_gregorian_tai(-1_000_000, 2, 29, 12, 34, 56, 789);
+    assert_eq!(
+        epoch.to_gregorian_tai(),
+        (-1_000_000, 2, 29, 12, 34, 56, 789)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(-1_000_000, 3, 1) - epoch,
+        11 * Unit::Hour + 25 * Unit::Minute + 4 * Unit::Second - 789 * Unit::Nanosecond
+    );
+}
+
+#[test]
+fn test_epoch_checked_arithmetic() {
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    assert_eq!(epoch.checked_add(Unit::Day * 1), Ok(epoch + Unit::Day));
+    assert_eq!(epoch.checked_sub(Unit::Day * 1), Ok(epoch - Unit::Day));
+    // Leap seconds are accounted for just like the operators
+    let leap = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    assert_eq!(leap.checked_add(Unit::Day * 1), Ok(leap + Unit::Day));
+    assert_eq!(leap.saturating_add(Unit::Day * 1), leap + Unit::Day);
+
+    assert_eq!(epoch.checked_add(Duration::MAX), Err(Errors::Overflow));
+    // 2023 is a bit more than one century after the reference epoch of 1900.
+    let start = epoch.checked_sub(Duration::MAX).unwrap();
+    assert_eq!(start, epoch - Duration::MAX);
+    assert_eq!(start.checked_sub(Unit::Century * 2), Err(Errors::Overflow));
+    assert_eq!(
+        epoch.saturating_add(Duration::MAX),
+        Epoch::from_utc_duration(Duration::MAX)
+    );
+
+    // Overflowing in the conversion to TAI is detected too.
+    let end_of_time = Epoch::from_tai_duration(Duration::MAX - Unit::Minute);
+    assert!(end_of_time.checked_add(30 * Unit::Second).is_ok());
+    assert_eq!(
+        end_of_time.checked_add(Unit::Minute * 1),
+        Err(Errors::Overflow)
+    );
+    let end_of_tt = end_of_time.in_time_scale(TimeScale::TT);
+    // TT is 32.184 seconds ahead of TAI, so it reaches the bounds first.
+    assert_eq!(
+        end_of_tt.checked_add(30 * Unit::Second),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        end_of_time
+            .in_time_scale(TimeScale::GPST)
+            .checked_add(Unit::Minute * 1),
+        Err(Errors::Overflow)
+    );
+
+    // Wrapping
+    let start_of_time = Epoch::from_tai_duration(Duration::MIN);
+    assert_eq!(
+        end_of_time.wrapping_add(Unit::Minute * 1 + Unit::Nanosecond * 1),
+        start_of_time + Unit::Nanosecond * 1
+    );
+    assert_eq!(
+        start_of_time.wrapping_sub(Unit::Nanosecond * 1),
+        Epoch::from_tai_duration(Duration::MAX - Unit::Nanosecond * 1)
+    );
+}
+
+#[test]
+fn test_tai64() {
+    let e = Epoch::from_gregorian_tai(1999, 8, 24, 4, 4, 15, 787_492_500);
+    let tai64n = [
+        0x40, 0x00, 0x00, 0x00, 0x37, 0xc2, 0x19, 0xbf, 0x2e, 0xf0, 0x2e, 0x94,
+    ];
+    assert_eq!(e.to_tai64n(), tai64n);
+    assert_eq!(Epoch::from_tai64n(tai64n), Ok(e));
+    assert_eq!(Epoch::from_tai64_str("@4000000037c219bf2ef02e94"), Ok(e));
+    assert_eq!(Epoch::from_tai64_str("@4000000037C219BF2EF02E94"), Ok(e));
+
+    // The seconds are truncated in TAI64, and the attoseconds are zero in TAI64NA.
+    assert_eq!(e.to_tai64(), tai64n[..8]);
+    assert_eq!(
+        Epoch::from_tai64(e.to_tai64()),
+        Ok(Epoch::from_gregorian_tai_hms(1999, 8, 24, 4, 4, 15))
+    );
+    assert_eq!(e.to_tai64na()[..12], tai64n);
+    assert_eq!(e.to_tai64na()[12..], [0; 4]);
+    assert_eq!(Epoch::from_tai64na(e.to_tai64na()), Ok(e));
+    let mut tai64na = e.to_tai64na();
+    tai64na[15] = 1;
+    assert_eq!(Epoch::from_tai64na(tai64na), Ok(e));
+    assert_eq!(
+        Epoch::from_tai64_str("@4000000037c219bf2ef02e9400000001"),
+        Ok(e)
+    );
+    assert_eq!(
+        Epoch::from_tai64_str("@4000000037c219bf"),
+        Ok(Epoch::from_gregorian_tai_hms(1999, 8, 24, 4, 4, 15))
+    );
+
+    // 2^62 is 1970 January 01 at midnight TAI, and UTC was ten seconds behind TAI in 1972.
+    let tai_midnight = Epoch::from_gregorian_tai_at_midnight(1970, 1, 1);
+    assert_eq!(Epoch::from_tai64_str("@4000000000000000"), Ok(tai_midnight));
+    assert_eq!(
+        Epoch::from_tai64_str("@4000000003c2670a"),
+        Ok(Epoch::from_gregorian_utc_at_midnight(1972, 1, 1))
+    );
+    // Labels before 1970 and across a leap second
+    let before = tai_midnight - Unit::Nanosecond * 1;
+    assert_eq!(Epoch::from_tai64n(before.to_tai64n()), Ok(before));
+    assert_eq!(
+        before.to_tai64n(),
+        [0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3b, 0x9a, 0xc9, 0xff]
+    );
+    let leap_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert_eq!(Epoch::from_tai64n(leap_second.to_tai64n()), Ok(leap_second));
+    assert_eq!(
+        Epoch::from_tai64n((leap_second + Unit::Second * 1).to_tai64n()),
+        Ok(Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 500_000_000))
+    );
+
+    // Errors
+    let value_error = Err(Errors::ParseError(ParsingErrors::ValueError));
+    let mut reserved = tai64n;
+    reserved[0] = 0x80;
+    assert_eq!(Epoch::from_tai64n(reserved), value_error);
+    let mut nanoseconds = tai64n;
+    nanoseconds[8..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+    assert_eq!(Epoch::from_tai64n(nanoseconds), value_error);
+    tai64na[12..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+    assert_eq!(Epoch::from_tai64na(tai64na), value_error);
+    assert_eq!(
+        Epoch::from_tai64([0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Err(Errors::Overflow)
+    );
+    for s in [
+        "4000000037c219bf2ef02e94",
+        "@4000000037c219bf2ef02e9",
+        "@4000000037c219bf2ef02e9g",
+        "@+000000037c219bf2ef02e94",
+    ] {
+        assert_eq!(Epoch::from_tai64_str(s), value_error, "{s}");
+    }
+}
@@ -0,0 +1,237 @@
+use hifitime::{Epoch, Interval, TimeScale, TimeUnits, Unit};
+
+#[cfg(feature = "std")]
+use hifitime::IntervalSet;
+
+#[test]
+fn test_interval_bounds() {
+    let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let t1 = t0 + 1.hours();
+
+    let closed = Interval::inclusive(t0, t1);
+    let half_open = Interval::exclusive(t0, t1);
+    let open = Interval::new(t0, t1, false, false);
+
+    for interval in [closed, half_open, open] {
+        assert_eq!(interval.duration(), 1.hours());
+        assert!(!interval.is_empty());
+        assert!(interval.contains(t0 + 30.minutes()));
+        assert!(!interval.contains(t0 - 1.nanoseconds()));
+        assert!(!interval.contains(t1 + 1.nanoseconds()));
+    }
+    assert!(closed.contains(t0) && closed.contains(t1));
+    assert!(half_open.contains(t0) && !half_open.contains(t1));
+    assert!(!open.contains(t0) && !open.contains(t1));
+
+    // Bounds are compared in TAI, whatever their time scale.
+    assert!(closed.contains(t1.in_time_scale(TimeScale::TDB)));
+    let mixed = Interval::exclusive(t0.in_time_scale(TimeScale::GPST), t1);
+    assert_eq!(mixed.duration(), 1.hours());
+
+    // Empty intervals
+    assert!(Interval::exclusive(t0, t0).is_empty());
+    assert!(!Interval::inclusive(t0, t0).is_empty());
+    assert!(Interval::inclusive(t1, t0).is_empty());
+    assert_eq!(Interval::inclusive(t1, t0).duration(), Unit::Second * 0);
+    assert!(!Interval::inclusive(t1, t0).contains(t0));
+
+    #[cfg(feature = "std")]
+    {
+        assert_eq!(
+            format!("{half_open}"),
+            "[2023-01-01T00:00:00 UTC, 2023-01-01T01:00:00 UTC)"
+        );
+        assert_eq!(
+            format!("{open}"),
+            "(2023-01-01T00:00:00 UTC, 2023-01-01T01:00:00 UTC)"
+        );
+    }
+}
+
+#[test]
+fn test_interval_operations() {
+    let t = |hours: i64| Epoch::from_gregorian_utc_at_midnight(2023, 1, 1) + hours.hours();
+
+    // Overlapping
+    let a = Interval::exclusive(t(0), t(4));
+    let b = Interval::inclusive(t(2), t(6));
+    assert!(a.overlaps(b) && b.overlaps(a));
+    assert_eq!(a.intersection(b), Some(Interval::exclusive(t(2), t(4))));
+    assert_eq!(a.union(b), Some(Interval::inclusive(t(0), t(6))));
+    assert_eq!(a.union(b), b.union(a));
+    assert_eq!(
+        a.difference(b),
+        (Some(Interval::exclusive(t(0), t(2))), None)
+    );
+    assert_eq!(
+        b.difference(a),
+        (None, Some(Interval::inclusive(t(4), t(6))))
+    );
+
+    // Adjacent, which only share an epoch if both bounds are inclusive
+    let c = Interval::exclusive(t(4), t(8));
+    assert!(!a.overlaps(c));
+    assert_eq!(a.intersection(c), None);
+    assert_eq!(a.union(c), Some(Interval::exclusive(t(0), t(8))));
+    let d = Interval::new(t(4), t(8), false, true);
+    assert_eq!(a.union(d), None);
+    assert!(Interval::inclusive(t(0), t(4)).overlaps(Interval::inclusive(t(4), t(8))));
+    assert_eq!(
+        Interval::inclusive(t(0), t(4)).intersection(Interval::inclusive(t(4), t(8))),
+        Some(Interval::inclusive(t(4), t(4)))
+    );
+
+    // Disjoint
+    let e = Interval::exclusive(t(10), t(12));
+    assert_eq!(a.union(e), None);
+    assert_eq!(a.difference(e), (Some(a), None));
+    assert_eq!(e.difference(a), (None, Some(e)));
+
+    // Nested
+    let inner = Interval::inclusive(t(1), t(2));
+    assert_eq!(a.intersection(inner), Some(inner));
+    assert_eq!(a.union(inner), Some(a));
+    assert_eq!(
+        a.difference(inner),
+        (
+            Some(Interval::exclusive(t(0), t(1))),
+            Some(Interval::new(t(2), t(4), false, false))
+        )
+    );
+    assert_eq!(inner.difference(a), (None, None));
+
+    // With an empty interval
+    let empty = Interval::exclusive(t(1), t(1));
+    assert_eq!(a.intersection(empty), None);
+    assert_eq!(a.union(empty), Some(a));
+    assert_eq!(empty.union(a), Some(a));
+    assert_eq!(a.difference(empty), (Some(a), None));
+}
+
+#[test]
+fn test_interval_time_series() {
+    let t0 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let t1 = t0 + 1.hours();
+
+    let epochs: Vec<Epoch> = Interval::inclusive(t0, t1)
+        .time_series(15.minutes())
+        .collect();
+    assert_eq!(epochs.len(), 5);
+    assert_eq!(epochs[0], t0);
+    assert_eq!(epochs[4], t1);
+
+    let series = Interval::new(t0, t1, false, false).time_series(15.minutes());
+    assert_eq!(series.len(), 3);
+    let epochs: Vec<Epoch> = series.collect();
+    assert_eq!(epochs[0], t0 + 15.minutes());
+    assert_eq!(epochs[2], t0 + 45.minutes());
+
+    assert_eq!(
+        Interval::exclusive(t1, t0)
+            .time_series(15.minutes())
+            .count(),
+        0
+    );
+    assert_eq!(
+        Interval::new(t0, t0, false, true)
+            .time_series(15.minutes())
+            .count(),
+        0
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_interval_set() {
+    let t = |hours: i64| Epoch::from_gregorian_utc_at_midnight(2023, 1, 1) + hours.hours();
+
+    // Unsorted, overlapping, adjacent, and empty windows are normalized.
+    let visibility: IntervalSet = [
+        Interval::exclusive(t(10), t(12)),
+        Interval::exclusive(t(0), t(2)),
+        Interval::exclusive(t(1), t(3)),
+        Interval::exclusive(t(3), t(4)),
+        Interval::exclusive(t(6), t(6)),
+        Interval::inclusive(t(6), t(8)),
+        Interval::new(t(8), t(9), false, false),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        visibility.intervals(),
+        &[
+            Interval::exclusive(t(0), t(4)),
+            Interval::exclusive(t(6), t(9)),
+            Interval::exclusive(t(10), t(12)),
+        ]
+    );
+    assert_eq!(visibility.len(), 3);
+    assert_eq!(visibility.duration(), 9.hours());
+    assert!(visibility.contains(t(0)));
+    assert!(visibility.contains(t(8)));
+    assert!(!visibility.contains(t(4)));
+    assert!(!visibility.contains(t(9)));
+    assert!(!visibility.contains(t(-1)));
+    assert!(!visibility.contains(t(12)));
+    assert!(IntervalSet::new().is_empty());
+
+    let mut set = visibility.clone();
+    set.insert(Interval::inclusive(t(4), t(6)));
+    assert_eq!(
+        set.intervals(),
+        &[
+            Interval::exclusive(t(0), t(9)),
+            Interval::exclusive(t(10), t(12))
+        ]
+    );
+
+    let eclipses: IntervalSet = [
+        Interval::inclusive(t(1), t(2)),
+        Interval::exclusive(t(7), t(11)),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        visibility.intersection(&eclipses).intervals(),
+        &[
+            Interval::inclusive(t(1), t(2)),
+            Interval::exclusive(t(7), t(9)),
+            Interval::exclusive(t(10), t(11)),
+        ]
+    );
+    assert_eq!(
+        visibility.difference(&eclipses).intervals(),
+        &[
+            Interval::exclusive(t(0), t(1)),
+            Interval::new(t(2), t(4), false, false),
+            Interval::exclusive(t(6), t(7)),
+            Interval::exclusive(t(11), t(12)),
+        ]
+    );
+    assert_eq!(
+        visibility.union(&eclipses).intervals(),
+        &[
+            Interval::exclusive(t(0), t(4)),
+            Interval::exclusive(t(6), t(12)),
+        ]
+    );
+
+    // The sunlit and eclipsed visibility make up the whole visibility.
+    let sunlit = visibility.difference(&eclipses);
+    let eclipsed = visibility.intersection(&eclipses);
+    assert_eq!(sunlit.intersection(&eclipsed), IntervalSet::new());
+    assert_eq!(sunlit.union(&eclipsed), visibility);
+    assert_eq!(
+        sunlit.duration() + eclipsed.duration(),
+        visibility.duration()
+    );
+
+    assert_eq!(
+        format!(
+            "{}",
+            IntervalSet::from(vec![Interval::exclusive(t(0), t(1))])
+        ),
+        "{[2023-01-01T00:00:00 UTC, 2023-01-01T01:00:00 UTC)}"
+    );
+}
@@ -0,0 +1,230 @@
+use core::str::FromStr;
+
+use hifitime::efmt::{consts::RFC3339, Formatter};
+use hifitime::leap_seconds::{
+    register_leap_seconds, unregister_leap_seconds, LatestLeapSeconds, LeapSecond,
+    LeapSecondProvider, LeapSecondsTable,
+};
+use hifitime::{Epoch, TimeScale, Unit};
+
+/// The registered leap seconds apply to the whole program, so all of the checks are in this single test.
+#[test]
+fn test_registered_leap_seconds() {
+    // The 2017 leap second, followed by a hypothetical negative leap second on 2035 January 01, and a positive one on 2040 January 01.
+    static TABLE: LeapSecondsTable = LeapSecondsTable::new(&[
+        LeapSecond::from_tai_seconds(2_272_060_800, 10.0, true),
+        LeapSecond::from_tai_seconds(3_692_217_600, 37.0, true),
+        LeapSecond::from_tai_seconds(4_260_211_200, 36.0, true),
+        LeapSecond::from_tai_seconds(4_417_977_600, 37.0, true),
+    ]);
+
+    let tai_2036 = Epoch::from_gregorian_tai_at_midnight(2036, 1, 1);
+    assert_eq!(tai_2036.leap_seconds_iers(), 37);
+
+    register_leap_seconds(&TABLE);
+
+    // Accessors
+    assert_eq!(tai_2036.leap_seconds_iers(), 36);
+    assert_eq!(tai_2036.leap_seconds(false), Some(36.0));
+    assert_eq!(
+        tai_2036.to_tai_duration() - tai_2036.to_utc_duration(),
+        36 * Unit::Second
+    );
+    assert_eq!(tai_2036.to_gregorian_utc(), (2035, 12, 31, 23, 59, 24, 0));
+
+    // Constructors
+    let utc_2036 = Epoch::from_gregorian_utc_at_midnight(2036, 1, 1);
+    assert_eq!(utc_2036 - tai_2036, 36 * Unit::Second);
+    assert_eq!(
+        Epoch::from_utc_duration(utc_2036.to_utc_duration()),
+        utc_2036
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2041, 1, 1)
+            - Epoch::from_gregorian_utc_at_midnight(2034, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(2041, 1, 1)
+            - Epoch::from_gregorian_tai_at_midnight(2034, 1, 1)
+    );
+
+    // The UNIX epoch does not depend on the registered leap seconds.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2022, 1, 1).to_unix_seconds(),
+        1_640_995_200.0
+    );
+    assert_eq!(
+        format!("{}", Epoch::from_unix_seconds(0.0)),
+        "1970-01-01T00:00:00 UTC"
+    );
+
+    // The negative leap second shortens 2034 by one second, and the positive leap second lengthens 2039 by one second.
+    let utc_2034 = Epoch::from_gregorian_utc_at_midnight(2034, 1, 1);
+    let utc_2035 = Epoch::from_gregorian_utc_at_midnight(2035, 1, 1);
+    assert_eq!(utc_2035 - utc_2034, 365 * Unit::Day - Unit::Second);
+    let utc_2039 = Epoch::from_gregorian_utc_at_midnight(2039, 1, 1);
+    let utc_2040 = Epoch::from_gregorian_utc_at_midnight(2040, 1, 1);
+    assert_eq!(utc_2040 - utc_2039, 365 * Unit::Day + Unit::Second);
+
+    // Formatting and parsing
+    assert_eq!(format!("{utc_2036}"), "2036-01-01T00:00:00 UTC");
+    assert_eq!(
+        format!("{}", tai_2036.in_time_scale(TimeScale::UTC)),
+        "2035-12-31T23:59:24 UTC"
+    );
+    assert_eq!(
+        format!("{}", Formatter::new(utc_2036, RFC3339)),
+        "2036-01-01T00:00:00.000000000+00:00"
+    );
+    assert_eq!(
+        Epoch::from_str("2036-01-01T00:00:00 UTC").unwrap(),
+        utc_2036
+    );
+    assert_eq!(
+        RFC3339
+            .parse("2036-01-01T00:00:00.000000000+00:00")
+            .unwrap(),
+        utc_2036
+    );
+
+    // The registered leap second inserted at the end of 2039 round trips as second 60.
+    let leap = Epoch::from_tai_duration(utc_2040.to_tai_duration() - 500 * Unit::Millisecond)
+        .in_time_scale(TimeScale::UTC);
+    assert_eq!(format!("{leap}"), "2039-12-31T23:59:60.500000000 UTC");
+    assert_eq!(Epoch::from_str(&format!("{leap}")).unwrap(), leap);
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc(2039, 12, 31, 23, 59, 60, 500_000_000).unwrap(),
+        leap
+    );
+    assert_eq!(
+        RFC3339
+            .parse(&format!("{}", Formatter::new(leap, RFC3339)))
+            .unwrap(),
+        leap
+    );
+    // No leap second is inserted at the end of 2034, since the registered one is negative.
+    assert!(Epoch::maybe_from_gregorian_utc(2034, 12, 31, 23, 59, 60, 0).is_err());
+
+    // The leap seconds of any provider may be registered.
+    #[cfg(feature = "std")]
+    {
+        let no_leap_seconds = LeapSecondsTable::leak(LeapSecondsTable::new(&[]));
+        register_leap_seconds(no_leap_seconds);
+        assert_eq!(utc_2036.to_utc_duration(), utc_2036.to_tai_duration());
+        assert_eq!(tai_2036.leap_seconds(true), None);
+    }
+
+    // Back to the latest leap seconds
+    unregister_leap_seconds();
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2036, 1, 1) - tai_2036,
+        37 * Unit::Second
+    );
+}
+
+#[test]
+fn test_leap_second_constructors() {
+    // Both constructors may build constant tables, and agree on whole seconds.
+    static TABLE: [LeapSecond; 2] = [
+        LeapSecond::new(2_272_060_800.0, 10.0, true),
+        LeapSecond::from_tai_seconds(2_272_060_800, 10.0, true),
+    ];
+    assert_eq!(TABLE[0], TABLE[1]);
+    assert_eq!(
+        LeapSecond::new(2_272_060_800.25, 10.0, true).timestamp_tai,
+        2_272_060_800_i64 * Unit::Second + 250 * Unit::Millisecond
+    );
+}
+
+#[test]
+fn test_leap_seconds_lookup() {
+    // The binary search must match a linear search through the table, including right at each leap second.
+    // In TAI, a leap second applies from the instant UTC reaches its midnight label, or from the start of 23:59:60 UTC if inserted.
+    let provider = LatestLeapSeconds::default();
+    let leap_seconds = provider.leap_seconds();
+    let to_duration = |seconds: f64| ((seconds * 1e9).round() as i64) * Unit::Nanosecond;
+    let starts = leap_seconds
+        .iter()
+        .enumerate()
+        .map(|(idx, ls)| {
+            let before = match idx {
+                0 => Unit::Second * 0,
+                _ => to_duration(leap_seconds[idx - 1].delta_at_utc(ls.timestamp_tai)),
+            };
+            let after = to_duration(ls.delta_at_utc(ls.timestamp_tai));
+            ls.timestamp_tai + before.min(after)
+        })
+        .collect::<Vec<_>>();
+
+    for (idx, leap_second) in leap_seconds.iter().enumerate() {
+        for instant in [leap_second.timestamp_tai, starts[idx]] {
+            for offset in [-1_i64, 0, 1] {
+                let epoch = Epoch::from_tai_duration(instant + offset * Unit::Nanosecond);
+                let expected = leap_seconds
+                    .iter()
+                    .zip(&starts)
+                    .rev()
+                    .find(|(_, start)| epoch.to_tai_duration() >= **start)
+                    .map(|(ls, _)| ls.delta_at_tai(epoch.to_tai_duration()));
+                assert_eq!(epoch.leap_seconds_with(false, &provider), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_smeared_negative_leap_second() {
+    use hifitime::leap_seconds::SmearModel;
+
+    // A hypothetical negative leap second on 2035 January 01: 23:59:59 UTC is skipped.
+    static TABLE: LeapSecondsTable = LeapSecondsTable::new(&[
+        LeapSecond::from_tai_seconds(2_272_060_800, 10.0, true),
+        LeapSecond::from_tai_seconds(3_692_217_600, 37.0, true),
+        LeapSecond::from_tai_seconds(4_260_211_200, 36.0, true),
+    ]);
+
+    let noon_before = Epoch::from_gregorian_tai_hms(2034, 12, 31, 12, 0, 37);
+    let midnight = Epoch::from_gregorian_tai_hms(2035, 1, 1, 0, 0, 36);
+    let noon_after = Epoch::from_gregorian_tai_hms(2035, 1, 1, 12, 0, 36);
+    let start = Epoch::from_gregorian_tai_hms(2034, 12, 31, 12, 0, 0).to_tai_duration();
+
+    for model in [SmearModel::Linear24h, SmearModel::UtcSls] {
+        assert_eq!(
+            noon_before.to_smeared_utc_duration_with(model, TABLE),
+            start,
+            "{model:?}"
+        );
+        assert_eq!(
+            noon_after.to_smeared_utc_duration_with(model, TABLE),
+            start + 1 * Unit::Day,
+            "{model:?}"
+        );
+        // The smeared clock reaches midnight with UTC.
+        if model == SmearModel::UtcSls {
+            assert_eq!(
+                midnight.to_smeared_utc_duration_with(model, TABLE),
+                start + 12 * Unit::Hour
+            );
+        }
+
+        // The smeared clock ticks more often than TAI, so converting an epoch to a smeared duration and back is exact.
+        for epoch in [
+            noon_before + 1 * Unit::Nanosecond,
+            midnight - 1 * Unit::Second,
+            midnight - 1 * Unit::Nanosecond,
+            midnight,
+            noon_after - 1 * Unit::Nanosecond,
+        ] {
+            let smeared = epoch.to_smeared_utc_duration_with(model, TABLE);
+            assert_eq!(
+                Epoch::from_smeared_utc_duration_with(smeared, model, TABLE),
+                epoch,
+                "{model:?} {epoch}"
+            );
+        }
+    }
+
+    // Each smeared second of the Google and AWS smear lasts 86399 / 86400 SI seconds, so the smeared clock lags at midnight.
+    assert_eq!(
+        midnight.to_smeared_utc_duration_with(SmearModel::Linear24h, TABLE),
+        start + 43_199_499_994_213_i64 * Unit::Nanosecond
+    );
+}
@@ -1,4 +1,4 @@
-from hifitime import Epoch, TimeSeries, Unit, Duration
+from hifitime import CalendarDuration, Epoch, Interval, IntervalSet, MonthEndPolicy, TimeScale, TimeSeries, Unit, Duration
 from datetime import datetime
 
 
@@ -16,6 +16,11 @@ def test_strtime():
 
     assert Epoch.strptime(dt_fmt, "%A, %d %B %Y %H:%M:%S") == epoch
 
+    # TAI64N labels in external form
+    label = epoch.strftime("%@")
+    assert label == "@40000000643890ea00000000"
+    assert Epoch.strptime(label, "%@") == epoch
+
 
 def test_utcnow():
     epoch = Epoch.system_now()
@@ -44,6 +49,29 @@ def test_time_series():
         print(f"#{num}:\t{epoch}")
 
     assert num == 10
+    assert len(time_series) == 0
+
+    linspace = TimeSeries.linspace(nye, nye + Unit.Second * 1, 4)
+    assert len(linspace) == 4
+    epochs = list(linspace)
+    assert epochs[0] == nye
+    assert epochs[1] == nye + Unit.Nanosecond * 333_333_333
+    assert epochs[-1] == nye + Unit.Second * 1
+
+def test_checked_arithmetic():
+    epoch = Epoch("2023-01-01 00:00:00 UTC")
+    assert epoch.checked_add(Unit.Day * 1) == epoch + Unit.Day * 1
+    try:
+        epoch.checked_add(Duration.init_from_max())
+    except Exception as e:
+        assert "overflow" in str(e)
+    else:
+        assert False, "overflow not raised"
+    assert Duration.init_from_max().saturating_add(Unit.Second * 1) == Duration.init_from_max()
+    frame = Unit.Millisecond * 250
+    assert (Unit.Second * -1.1).div_euclid(frame) == -5
+    assert (Unit.Second * -1.1).rem_euclid(frame) == Unit.Millisecond * 150
+
 
 def test_duration_eq():
     """
@@ -54,4 +82,44 @@ def test_duration_eq():
     assert Unit.Second * 1.0 >= Duration("0 ns")
     assert Unit.Second * 1.0 > Duration("0 ns")
     assert Duration("0 ns") <= Unit.Second * 1.0
-    assert Duration("0 ns") < Unit.Second * 1.0
\ No newline at end of file
+    assert Duration("0 ns") < Unit.Second * 1.0
+
+
+def test_calendar_arithmetic():
+    """
+    Adds months, years and calendar durations to an epoch
+    """
+    e = Epoch("2024-01-31T12:00:00 UTC")
+
+    assert e.add_months(1, MonthEndPolicy.Clamp, TimeScale.UTC) == Epoch("2024-02-29T12:00:00 UTC")
+    assert e.add_months(1, MonthEndPolicy.Overflow, TimeScale.UTC) == Epoch("2024-03-02T12:00:00 UTC")
+    assert e.add_years(-1, MonthEndPolicy.Strict, TimeScale.UTC) == Epoch("2023-01-31T12:00:00 UTC")
+
+    p1y1m1d = CalendarDuration(1, 1, 1)
+    assert f"{p1y1m1d}" == "P1Y1M1D"
+    assert e.add_calendar_duration(p1y1m1d, MonthEndPolicy.Clamp, TimeScale.UTC) == Epoch(
+        "2025-03-01T12:00:00 UTC"
+    )
+
+
+def test_intervals():
+    t0 = Epoch("2023-01-01 00:00:00 UTC")
+    visibility = Interval(t0, t0 + Unit.Hour * 4, True, False)
+    eclipse = Interval(t0 + Unit.Hour * 1, t0 + Unit.Hour * 2, True, True)
+
+    assert visibility.duration() == Unit.Hour * 4
+    assert t0 in visibility
+    assert not visibility.contains(t0 + Unit.Hour * 4)
+    assert visibility.overlaps(eclipse)
+    assert visibility.intersection(eclipse) == eclipse
+    before, after = visibility.difference(eclipse)
+    assert before == Interval(t0, t0 + Unit.Hour * 1, True, False)
+    assert after == Interval(t0 + Unit.Hour * 2, t0 + Unit.Hour * 4, False, False)
+    assert len(visibility.time_series(Unit.Hour * 1)) == 4
+
+    windows = IntervalSet([visibility, Interval(t0 + Unit.Hour * 3, t0 + Unit.Hour * 5, True, False)])
+    assert len(windows) == 1
+    sunlit = windows.difference(IntervalSet([eclipse]))
+    assert len(sunlit) == 2
+    assert sunlit.duration() == Unit.Hour * 4
+    assert (t0 + Unit.Hour * 1) not in sunlit
@@ -12,6 +12,8 @@ fn test_from_str() {
         ("GPST", TimeScale::GPST),
         ("GST", TimeScale::GST),
         ("BDT", TimeScale::BDT),
+        ("TCB", TimeScale::TCB),
+        ("TCG", TimeScale::TCG),
     ];
     for value in values {
         let (descriptor, expected) = value;
@@ -1,6 +1,6 @@
 extern crate hifitime;
 
-use hifitime::{Epoch, TimeSeries, TimeUnits, Unit};
+use hifitime::{Duration, Epoch, TimeSeries, TimeUnits, Unit};
 
 #[test]
 fn test_timeseries() {
@@ -94,13 +94,13 @@ fn gh131_regression() {
     let steps = 1_000_000_000;
     let end = start + steps * step; // This is 500 ms later
     let times = TimeSeries::exclusive(start, end, step);
-    // For an _exclusive_ time series, we skip the last item, so it's steps minus one
-    assert_eq!(times.len(), steps as usize - 1);
+    // For an _exclusive_ time series, we skip the end, so it's the steps count
+    assert_eq!(times.len(), steps as usize);
     assert_eq!(times.len(), times.size_hint().0);
 
-    // For an _inclusive_ time series, we skip the last item, so it's the steps count
+    // For an _inclusive_ time series, we also include the end, so it's one more than the steps count
     let times = TimeSeries::inclusive(start, end, step);
-    assert_eq!(times.len(), steps as usize);
+    assert_eq!(times.len(), steps as usize + 1);
     assert_eq!(times.len(), times.size_hint().0);
 }
 
@@ -120,3 +120,145 @@ fn gh154_reciprocity() {
         assert_eq!(rebuilt, epoch, "got: {rebuilt:x}\nexp: {epoch:x}");
     }
 }
+
+#[test]
+fn test_timeseries_linspace() {
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let end = start + 1.seconds();
+
+    // A step of a third of a second drifts off the end...
+    let third = TimeSeries::inclusive(start, end, 333_333_333.nanoseconds());
+    assert_eq!(third.len(), 4);
+    assert_ne!(third.last(), Some(end));
+
+    // ... but a linspace hits both endpoints exactly.
+    let linspace = TimeSeries::linspace(start, end, 4);
+    assert_eq!(linspace.len(), 4);
+    assert_eq!(linspace.step(), 333_333_333.nanoseconds());
+    let epochs: Vec<Epoch> = linspace.clone().collect();
+    assert_eq!(
+        epochs,
+        vec![
+            start,
+            start + 333_333_333.nanoseconds(),
+            start + 666_666_666.nanoseconds(),
+            end
+        ]
+    );
+    let mut reversed: Vec<Epoch> = linspace.rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, epochs);
+
+    // Backward in time
+    let backward: Vec<Epoch> = TimeSeries::linspace(end, start, 4).collect();
+    assert_eq!(backward[0], end);
+    assert_eq!(backward[3], start);
+    assert_eq!(backward[1], end - 333_333_333.nanoseconds());
+
+    // Edge cases
+    assert_eq!(TimeSeries::linspace(start, end, 0).count(), 0);
+    assert_eq!(
+        TimeSeries::linspace(start, end, 1).collect::<Vec<Epoch>>(),
+        vec![start]
+    );
+    assert_eq!(
+        TimeSeries::linspace(start, end, 2).collect::<Vec<Epoch>>(),
+        vec![start, end]
+    );
+
+    // Endpoints in another time scale than the start
+    let end_tdb =
+        Epoch::from_gregorian_utc_at_noon(2023, 1, 1).in_time_scale(hifitime::TimeScale::TDB);
+    let linspace = TimeSeries::linspace(start, end_tdb, 1_001);
+    assert_eq!(linspace.last(), Some(end_tdb));
+}
+
+#[test]
+fn test_timeseries_negative_step() {
+    let start = Epoch::from_gregorian_utc_at_noon(2023, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+
+    let times = TimeSeries::inclusive(start, end, -2.hours());
+    assert_eq!(times.len(), 7);
+    let epochs: Vec<Epoch> = times.collect();
+    assert_eq!(epochs[0], start);
+    assert_eq!(epochs[1], start - 2.hours());
+    assert_eq!(epochs[6], end);
+
+    let times = TimeSeries::exclusive(start, end, -2.hours());
+    assert_eq!(times.len(), 6);
+    assert_eq!(times.last(), Some(end + 2.hours()));
+
+    // Non-divisible steps
+    assert_eq!(TimeSeries::exclusive(start, end, -5.hours()).len(), 3);
+    assert_eq!(TimeSeries::inclusive(start, end, -5.hours()).len(), 3);
+    assert_eq!(TimeSeries::exclusive(end, start, 5.hours()).len(), 3);
+    assert_eq!(TimeSeries::inclusive(end, start, 5.hours()).len(), 3);
+
+    // The end is never reached when the step goes the other way, or is zero.
+    assert_eq!(TimeSeries::inclusive(start, end, 2.hours()).count(), 0);
+    assert_eq!(TimeSeries::exclusive(end, start, -2.hours()).count(), 0);
+    assert_eq!(TimeSeries::inclusive(end, start, Duration::ZERO).count(), 0);
+
+    // Spanning more than a century
+    let centuries = TimeSeries::inclusive(end, end + 2 * Unit::Century, 10.days());
+    assert_eq!(centuries.len(), 7_306);
+    assert_eq!(centuries.last(), Some(end + 2 * Unit::Century));
+    assert_eq!(
+        TimeSeries::exclusive(end + 2 * Unit::Century, end, -1.days()).len(),
+        73_050
+    );
+
+    // Empty ranges
+    assert_eq!(TimeSeries::exclusive(start, start, 1.hours()).count(), 0);
+    assert_eq!(
+        TimeSeries::inclusive(start, start, Duration::ZERO).collect::<Vec<Epoch>>(),
+        vec![start]
+    );
+}
+
+#[test]
+fn test_timeseries_double_ended() {
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let end = start + 10.minutes();
+    let mut times = TimeSeries::inclusive(start, end, 1.minutes());
+    assert_eq!(times.len(), 11);
+    assert_eq!(times.size_hint(), (11, Some(11)));
+
+    // Both ends may be consumed without overlap.
+    assert_eq!(times.next(), Some(start));
+    assert_eq!(times.next_back(), Some(end));
+    assert_eq!(times.len(), 9);
+    assert_eq!(times.nth(2), Some(start + 3.minutes()));
+    assert_eq!(times.nth_back(1), Some(start + 8.minutes()));
+    assert_eq!(times.len(), 4);
+    assert_eq!(
+        times.clone().collect::<Vec<Epoch>>(),
+        vec![
+            start + 4.minutes(),
+            start + 5.minutes(),
+            start + 6.minutes(),
+            start + 7.minutes()
+        ]
+    );
+    assert_eq!(times.nth(10), None);
+    assert_eq!(times.len(), 0);
+    assert_eq!(times.next_back(), None);
+
+    // Indexing a large series is constant time.
+    let mut times = TimeSeries::exclusive(start, start + 1.days(), 1.nanoseconds());
+    assert_eq!(times.len(), 86_400_000_000_000);
+    assert_eq!(
+        times.nth(43_200_000_000_000),
+        Some(Epoch::from_gregorian_utc_at_noon(2023, 1, 1))
+    );
+    assert_eq!(times.nth_back(0), Some(start + 1.days() - 1.nanoseconds()));
+    assert_eq!(times.len(), 43_199_999_999_998);
+
+    // Preallocation
+    let times = TimeSeries::linspace(start, end, 101);
+    let mut epochs = Vec::with_capacity(times.len());
+    epochs.extend(times);
+    assert_eq!(epochs.len(), 101);
+    assert_eq!(epochs[100], end);
+}
@@ -0,0 +1,417 @@
+#[cfg(feature = "std")]
+use hifitime::{
+    efmt::{consts::RFC3339_ZONED, Format},
+    Epoch, Errors, LocalTimePolicy, ParsingErrors, TimeScale, TimeUnits, TimeZone, Unit, Weekday,
+};
+
+#[cfg(feature = "std")]
+fn denver() -> TimeZone {
+    TimeZone::from_tzif(
+        "America/Denver",
+        include_bytes!("../data/tzif/America/Denver"),
+    )
+    .unwrap()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_to_local() {
+    use core::str::FromStr;
+
+    let denver = denver();
+    assert_eq!(denver.name(), "America/Denver");
+
+    let summer = Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0).to_local(&denver);
+    assert_eq!(summer.abbreviation(), "MDT");
+    assert_eq!(summer.zone_name(), "America/Denver");
+    assert!(summer.is_dst());
+    assert_eq!(summer.offset(), -6 * Unit::Hour);
+    assert_eq!(summer.to_gregorian(), (2023, 7, 4, 12, 0, 0, 0));
+    assert_eq!(summer.weekday(), Weekday::Tuesday);
+    assert_eq!(format!("{summer}"), "2023-07-04T12:00:00-06:00 MDT");
+    assert_eq!(
+        summer.wall_clock(),
+        Epoch::from_gregorian_utc_hms(2023, 7, 4, 12, 0, 0)
+    );
+
+    let winter = Epoch::from_gregorian_utc(2023, 1, 2, 0, 30, 0, 500_000_000).to_local(&denver);
+    assert_eq!(winter.abbreviation(), "MST");
+    assert!(!winter.is_dst());
+    assert_eq!(winter.to_gregorian(), (2023, 1, 1, 17, 30, 0, 500_000_000));
+    assert_eq!(
+        format!("{winter}"),
+        "2023-01-01T17:30:00.500000000-07:00 MST"
+    );
+
+    let fmt = Format::from_str("%A %d %B %Y %H:%M %Z %K").unwrap();
+    assert_eq!(
+        format!("{}", winter.format(fmt)),
+        "Sunday 01 January 2023 17:30 MST America/Denver"
+    );
+
+    // The local time does not depend on the time scale of the epoch.
+    let tai = Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0).in_time_scale(TimeScale::TAI);
+    assert_eq!(
+        tai.to_local(&denver).to_gregorian(),
+        (2023, 7, 4, 12, 0, 0, 0)
+    );
+    assert_eq!(tai.to_local(&denver).epoch(), tai);
+
+    // Without a time zone, the time zone tokens print UTC or the offset.
+    let fmt = Format::from_str("%H:%M %Z %K").unwrap();
+    let epoch = Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0);
+    assert_eq!(
+        format!("{}", hifitime::efmt::Formatter::new(epoch, fmt)),
+        "18:00 UTC UTC"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            hifitime::efmt::Formatter::with_timezone(epoch, 2 * Unit::Hour, fmt)
+        ),
+        "20:00 +02:00 +02:00"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_transitions() {
+    let denver = denver();
+    // Daylight saving time starts on 2023 March 12 at 02:00 MST, i.e. 09:00 UTC.
+    let start = Epoch::from_gregorian_utc_hms(2023, 3, 12, 9, 0, 0);
+    assert_eq!(
+        denver.local_time_type(start - 1.seconds()).abbreviation,
+        "MST"
+    );
+    assert_eq!(denver.local_time_type(start).abbreviation, "MDT");
+    assert_eq!(
+        (start - 1.seconds()).to_local(&denver).to_gregorian(),
+        (2023, 3, 12, 1, 59, 59, 0)
+    );
+    assert_eq!(
+        start.to_local(&denver).to_gregorian(),
+        (2023, 3, 12, 3, 0, 0, 0)
+    );
+    // And ends on 2023 November 05 at 02:00 MDT, i.e. 08:00 UTC.
+    let end = Epoch::from_gregorian_utc_hms(2023, 11, 5, 8, 0, 0);
+    assert_eq!(denver.offset(end - 1.seconds()), -6 * Unit::Hour);
+    assert_eq!(denver.offset(end), -7 * Unit::Hour);
+
+    // Before any daylight saving time in Denver.
+    let old = Epoch::from_gregorian_utc_at_midnight(1900, 1, 1);
+    assert_eq!(denver.local_time_type(old).abbreviation, "MST");
+
+    // The transitions of the TZif file are followed by its rule, which must match the POSIX rule.
+    let rule = TimeZone::from_posix_rule("MST7MDT,M3.2.0,M11.1.0").unwrap();
+    assert_eq!(rule.name(), "MST7MDT,M3.2.0,M11.1.0");
+    let mut epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+    while epoch < Epoch::from_gregorian_utc_at_midnight(2045, 1, 1) {
+        assert_eq!(
+            denver.local_time_type(epoch),
+            rule.local_time_type(epoch),
+            "{epoch}"
+        );
+        epoch += 1.hours();
+    }
+
+    // European rules change at 01:00 UTC.
+    let berlin = TimeZone::from_tzif(
+        "Europe/Berlin",
+        include_bytes!("../data/tzif/Europe/Berlin"),
+    )
+    .unwrap();
+    for year in [2023, 2040] {
+        // Last Sunday of March and of October
+        let (march, october) = if year == 2023 { (26, 29) } else { (25, 28) };
+        let start = Epoch::from_gregorian_utc_hms(year, 3, march, 1, 0, 0);
+        let end = Epoch::from_gregorian_utc_hms(year, 10, october, 1, 0, 0);
+        assert_eq!(
+            berlin.local_time_type(start - 1.seconds()).abbreviation,
+            "CET"
+        );
+        assert_eq!(berlin.local_time_type(start).abbreviation, "CEST");
+        assert_eq!(
+            berlin.local_time_type(end - 1.seconds()).abbreviation,
+            "CEST"
+        );
+        assert_eq!(berlin.local_time_type(end).abbreviation, "CET");
+    }
+
+    // Lord Howe Island has a daylight saving time of thirty minutes, in the southern hemisphere.
+    let lord_howe = TimeZone::from_tzif(
+        "Australia/Lord_Howe",
+        include_bytes!("../data/tzif/Australia/Lord_Howe"),
+    )
+    .unwrap();
+    for year in [2023, 2050] {
+        let summer = Epoch::from_gregorian_utc_at_noon(year, 1, 15).to_local(&lord_howe);
+        assert_eq!(summer.abbreviation(), "+11");
+        assert!(summer.is_dst());
+        let winter = Epoch::from_gregorian_utc_at_noon(year, 7, 15).to_local(&lord_howe);
+        assert_eq!(winter.abbreviation(), "+1030");
+        assert_eq!(winter.offset(), 10.hours() + 30.minutes());
+    }
+
+    let fixed = TimeZone::fixed("MCC", 2 * Unit::Hour);
+    assert_eq!(
+        format!("{}", epoch.to_local(&fixed)),
+        "2045-01-01T02:00:00+02:00 MCC"
+    );
+    assert_eq!(TimeZone::utc().offset(epoch), 0.seconds());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_resolve_wall_clock() {
+    let denver = denver();
+    let policies = [
+        LocalTimePolicy::Earliest,
+        LocalTimePolicy::Latest,
+        LocalTimePolicy::Compatible,
+        LocalTimePolicy::Reject,
+    ];
+
+    // A usual local time
+    let wall_clock = Epoch::from_gregorian_utc_hms(2023, 7, 4, 12, 0, 0);
+    for policy in policies {
+        assert_eq!(
+            denver.resolve(wall_clock, policy).unwrap(),
+            Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0)
+        );
+    }
+
+    // 02:30 is skipped on 2023 March 12.
+    let skipped = Epoch::from_gregorian_utc_hms(2023, 3, 12, 2, 30, 0);
+    // Read with the MDT offset, this is 01:30 MST.
+    let earliest = Epoch::from_gregorian_utc_hms(2023, 3, 12, 8, 30, 0);
+    // Read with the MST offset, this is 03:30 MDT.
+    let latest = Epoch::from_gregorian_utc_hms(2023, 3, 12, 9, 30, 0);
+    assert_eq!(
+        denver.resolve(skipped, LocalTimePolicy::Earliest),
+        Ok(earliest)
+    );
+    assert_eq!(denver.resolve(skipped, LocalTimePolicy::Latest), Ok(latest));
+    assert_eq!(
+        denver.resolve(skipped, LocalTimePolicy::Compatible),
+        Ok(latest)
+    );
+    assert_eq!(
+        denver.resolve(skipped, LocalTimePolicy::Reject),
+        Err(Errors::ParseError(ParsingErrors::NonexistentLocalTime))
+    );
+    assert_eq!(
+        latest.to_local(&denver).to_gregorian(),
+        (2023, 3, 12, 3, 30, 0, 0)
+    );
+
+    // 01:30 occurs twice on 2023 November 05.
+    let ambiguous = Epoch::from_gregorian_utc_hms(2023, 11, 5, 1, 30, 0);
+    let earliest = Epoch::from_gregorian_utc_hms(2023, 11, 5, 7, 30, 0);
+    let latest = Epoch::from_gregorian_utc_hms(2023, 11, 5, 8, 30, 0);
+    assert_eq!(
+        denver.resolve(ambiguous, LocalTimePolicy::Earliest),
+        Ok(earliest)
+    );
+    assert_eq!(
+        denver.resolve(ambiguous, LocalTimePolicy::Latest),
+        Ok(latest)
+    );
+    assert_eq!(
+        denver.resolve(ambiguous, LocalTimePolicy::Compatible),
+        Ok(earliest)
+    );
+    assert_eq!(
+        denver.resolve(ambiguous, LocalTimePolicy::Reject),
+        Err(Errors::ParseError(ParsingErrors::AmbiguousLocalTime))
+    );
+    for epoch in [earliest, latest] {
+        assert_eq!(epoch.to_local(&denver).wall_clock(), ambiguous);
+    }
+
+    // Every local time of a usual day can be resolved back.
+    let mut epoch = Epoch::from_gregorian_utc_at_midnight(2023, 11, 4);
+    while epoch < Epoch::from_gregorian_utc_at_midnight(2023, 11, 7) {
+        let wall_clock = epoch.to_local(&denver).wall_clock();
+        let resolved = if epoch.to_local(&denver).is_dst() {
+            denver.resolve(wall_clock, LocalTimePolicy::Earliest)
+        } else {
+            denver.resolve(wall_clock, LocalTimePolicy::Latest)
+        };
+        assert_eq!(resolved, Ok(epoch));
+        epoch += 17.minutes();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parse_local() {
+    use core::str::FromStr;
+
+    let denver = denver();
+    let fmt = Format::from_str("%a, %d %b %Y %H:%M:%S %Z").unwrap();
+    let earliest = Epoch::from_gregorian_utc_hms(2023, 11, 5, 7, 30, 0);
+    let latest = Epoch::from_gregorian_utc_hms(2023, 11, 5, 8, 30, 0);
+
+    // The abbreviation resolves the ambiguity, whatever the policy.
+    assert_eq!(
+        fmt.parse_in(
+            "Sun, 05 Nov 2023 01:30:00 MDT",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Ok(earliest)
+    );
+    assert_eq!(
+        fmt.parse_in(
+            "Sun, 05 Nov 2023 01:30:00 MST",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Ok(latest)
+    );
+    // UTC is also understood.
+    assert_eq!(
+        fmt.parse_in(
+            "Sun, 05 Nov 2023 07:30:00 UTC",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Ok(earliest)
+    );
+    // But not an abbreviation which is not in use.
+    assert_eq!(
+        fmt.parse_in(
+            "Tue, 04 Jul 2023 12:00:00 MST",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Err(Errors::ParseError(ParsingErrors::UnknownTimeZone))
+    );
+    // And the weekday is that of the local date.
+    assert_eq!(
+        fmt.parse_in(
+            "Mon, 05 Nov 2023 01:30:00 MST",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Err(Errors::ParseError(ParsingErrors::WeekdayMismatch {
+            found: Weekday::Monday,
+            expected: Weekday::Sunday
+        }))
+    );
+
+    // Without an abbreviation, the policy resolves the local time.
+    let fmt = Format::from_str("%Y-%m-%d %H:%M:%S").unwrap();
+    assert_eq!(
+        fmt.parse_in("2023-11-05 01:30:00", &denver, LocalTimePolicy::Latest),
+        Ok(latest)
+    );
+    assert_eq!(
+        fmt.parse_in("2023-11-05 01:30:00", &denver, LocalTimePolicy::Reject),
+        Err(Errors::ParseError(ParsingErrors::AmbiguousLocalTime))
+    );
+    assert_eq!(
+        fmt.parse_in("2023-03-12 02:30:00", &denver, LocalTimePolicy::Reject),
+        Err(Errors::ParseError(ParsingErrors::NonexistentLocalTime))
+    );
+
+    // The name of the time zone must match.
+    let fmt = Format::from_str("%Y-%m-%d %H:%M:%S %K").unwrap();
+    assert_eq!(
+        fmt.parse_in(
+            "2023-07-04 12:00:00 America/Denver",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Ok(Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0))
+    );
+    assert_eq!(
+        fmt.parse_in(
+            "2023-07-04 12:00:00 Europe/Berlin",
+            &denver,
+            LocalTimePolicy::Reject
+        ),
+        Err(Errors::ParseError(ParsingErrors::UnknownTimeZone))
+    );
+
+    // Formatting and parsing round trips, including during the ambiguous hour.
+    let fmt = Format::from_str("%Y-%m-%d %H:%M:%S.%f %Z").unwrap();
+    let mut epoch = Epoch::from_gregorian_utc_hms(2023, 11, 5, 6, 0, 0);
+    while epoch < Epoch::from_gregorian_utc_hms(2023, 11, 5, 10, 0, 0) {
+        let local = format!("{}", epoch.to_local(&denver).format(fmt));
+        assert_eq!(
+            fmt.parse_in(&local, &denver, LocalTimePolicy::Reject),
+            Ok(epoch),
+            "{local}"
+        );
+        epoch += 7.minutes() + 1.milliseconds();
+    }
+
+    // Without a time zone, only UTC is understood.
+    assert_eq!(
+        Epoch::from_format_str("2023-07-04 18:00:00 UTC", "%Y-%m-%d %H:%M:%S %Z"),
+        Ok(Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0))
+    );
+    assert_eq!(
+        Epoch::from_format_str("2023-07-04 12:00:00 MDT", "%Y-%m-%d %H:%M:%S %Z"),
+        Err(Errors::ParseError(ParsingErrors::UnknownTimeZone))
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Epoch::from_gregorian_utc_hms(2023, 7, 4, 18, 0, 0)
+                .to_local(&denver)
+                .format(RFC3339_ZONED)
+        ),
+        "2023-07-04T12:00:00-06:00 MDT"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_load_time_zones() {
+    let denver = denver();
+    assert_eq!(
+        TimeZone::from_zoneinfo_dir("data/tzif", "America/Denver").unwrap(),
+        denver
+    );
+    assert_eq!(
+        TimeZone::from_path("America/Denver", "data/tzif/America/Denver").unwrap(),
+        denver
+    );
+
+    for name in ["", "../tzif/America/Denver", "/etc/localtime"] {
+        assert_eq!(
+            TimeZone::from_zoneinfo_dir("data/tzif", name),
+            Err(Errors::ParseError(ParsingErrors::UnknownTimeZone)),
+            "{name}"
+        );
+    }
+    assert_eq!(
+        TimeZone::from_zoneinfo_dir("data/tzif", "Mars/Olympus_Mons"),
+        Err(Errors::ParseError(ParsingErrors::IOError(
+            std::io::ErrorKind::NotFound
+        )))
+    );
+
+    // Truncated or invalid data
+    let data = include_bytes!("../data/tzif/America/Denver");
+    for len in [0, 4, 44, 100, data.len() - 1] {
+        assert_eq!(
+            TimeZone::from_tzif("America/Denver", &data[..len]),
+            Err(Errors::ParseError(ParsingErrors::TimeZoneFileCorrupted)),
+            "{len}"
+        );
+    }
+    let mut invalid = data.to_vec();
+    invalid[0] = b'X';
+    assert_eq!(
+        TimeZone::from_tzif("America/Denver", &invalid),
+        Err(Errors::ParseError(ParsingErrors::TimeZoneFileCorrupted))
+    );
+
+    assert_eq!(
+        TimeZone::from_posix_rule("MST7MDT,M3.2.0"),
+        Err(Errors::ParseError(ParsingErrors::ValueError))
+    );
+}
@@ -18,13 +18,77 @@ fn test_ut1_from_file() {
     //
     let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
     assert_eq!(
-        format!("{:x}", epoch.to_ut1(provider)),
+        format!("{:x}", epoch.to_ut1(&provider)),
         "2022-01-03T03:05:06.679020600 TAI"
     );
 }
 
 #[cfg(feature = "ut1")]
 #[test]
+fn test_ut1_time_scale() {
+    use core::str::FromStr;
+    use hifitime::ut1::{register_ut1_source, unregister_ut1_source, Ut1Provider};
+    use hifitime::{Epoch, TimeScale, Unit};
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    // Explicit UT1 source
+    let ut1_duration = epoch.to_ut1_duration(&provider);
+    assert_eq!(Epoch::from_ut1_duration(ut1_duration, &provider), epoch);
+    assert_eq!(
+        Epoch::from_ut1_duration(ut1_duration, &provider).time_scale,
+        TimeScale::UT1
+    );
+
+    // Outside of the source, UT1 is assumed to be equal to UTC, whether the source is explicit or registered.
+    let uncovered = Epoch::from_gregorian_utc_at_midnight(2000, 1, 1);
+    assert_eq!(
+        uncovered.to_ut1_duration(&provider),
+        uncovered.to_utc_duration()
+    );
+    assert_eq!(
+        Epoch::from_ut1_duration(uncovered.to_utc_duration(), &provider),
+        uncovered
+    );
+
+    // Registered UT1 source
+    register_ut1_source(provider.clone());
+    assert_eq!(
+        uncovered.in_time_scale(TimeScale::UT1).to_duration(),
+        uncovered.to_ut1_duration(&provider)
+    );
+    let in_ut1 = epoch.in_time_scale(TimeScale::UT1);
+    assert_eq!(in_ut1.to_duration(), ut1_duration);
+    assert_eq!(
+        epoch.to_gregorian_str(TimeScale::UT1),
+        "2022-01-03T03:05:06.679020600 UT1"
+    );
+    assert_eq!(format!("{in_ut1:?}"), "2022-01-03T03:05:06.679020600 UT1");
+
+    let parsed = Epoch::from_str("2022-01-03T03:05:06.679020600 UT1").unwrap();
+    assert_eq!(parsed.time_scale, TimeScale::UT1);
+    assert_eq!(parsed, epoch);
+    assert_eq!(Epoch::from_str(&format!("{in_ut1:?}")).unwrap(), epoch);
+
+    // Arithmetic in UT1 keeps the UT1 time scale
+    let later = in_ut1 + Unit::Day * 1;
+    assert_eq!(later.time_scale, TimeScale::UT1);
+    assert_eq!(later.to_duration(), ut1_duration + Unit::Day * 1);
+
+    // Without any UT1 source, UT1 is equal to UTC, which is within 0.9 s of UT1.
+    assert!(unregister_ut1_source().is_some());
+    assert_eq!(
+        epoch.to_gregorian_str(TimeScale::UT1),
+        "2022-01-03T03:05:06.789100000 UT1"
+    );
+    let in_ut1 = epoch.in_time_scale(TimeScale::UT1);
+    assert_eq!(in_ut1.to_duration(), epoch.to_utc_duration());
+    assert_eq!(Epoch::from_str(&format!("{in_ut1:?}")).unwrap(), epoch);
+}
+
+#[cfg(feature = "ut1-download")]
+#[test]
 fn test_ut1_from_jpl() {
     use core::str::FromStr;
     use hifitime::ut1::Ut1Provider;
@@ -43,9 +107,323 @@ fn test_ut1_from_jpl() {
     // >>>
     //
     let epoch = Epoch::from_str("2022-01-03 03:05:06.7891 UTC").unwrap();
-    let ut1_epoch = epoch.to_ut1(provider);
+    // This file does not cover this epoch, so UT1 is assumed to be equal to UTC.
+    let ut1_epoch = epoch.to_ut1(&provider);
     assert_eq!(
         format!("{:x}", ut1_epoch),
-        "2022-01-03T03:05:43.789100000 TAI",
+        "2022-01-03T03:05:06.789100000 TAI",
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_interpolation() {
+    use hifitime::ut1::{Ut1Interpolation, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, Unit};
+
+    let step = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let linear = step.clone().with_interpolation(Ut1Interpolation::Linear);
+    let lagrange = step
+        .clone()
+        .with_interpolation(Ut1Interpolation::Lagrange(4));
+
+    // MJD 59500 and 59501 TAI are tabulated as 37105.3665 ms and 37105.0474 ms.
+    // On a record, the step interpolation still uses the previous record, as it only uses records strictly before the epoch.
+    let node = Epoch::from_mjd_tai(59501.0);
+    assert_eq!(
+        step.delta_tai_minus_ut1(&node),
+        Some(37105.3665 * Unit::Millisecond)
+    );
+    assert_eq!(
+        step.delta_tai_minus_ut1(&(node + Unit::Nanosecond * 1)),
+        Some(37105.0474 * Unit::Millisecond)
+    );
+    for provider in [&linear, &lagrange] {
+        assert_eq!(
+            provider.delta_tai_minus_ut1(&node),
+            Some(37105.0474 * Unit::Millisecond)
+        );
+    }
+    // Only the step interpolation excludes the first record.
+    let first = Epoch::from_mjd_tai(59500.0);
+    assert_eq!(step.delta_tai_minus_ut1(&first), None);
+    for provider in [&linear, &lagrange] {
+        assert_eq!(
+            provider.delta_tai_minus_ut1(&first),
+            Some(37105.3665 * Unit::Millisecond)
+        );
+    }
+    for provider in [&step, &linear, &lagrange] {
+        // No data before the first record.
+        assert_eq!(
+            provider.delta_tai_minus_ut1(&Epoch::from_mjd_tai(59499.5)),
+            None
+        );
+    }
+
+    let midpoint = Epoch::from_mjd_tai(59500.5);
+    assert_eq!(
+        step.delta_tai_minus_ut1(&midpoint),
+        Some(37105.3665 * Unit::Millisecond)
+    );
+    let linear_offset = linear.delta_tai_minus_ut1(&midpoint).unwrap();
+    assert!((linear_offset - 37105.20695 * Unit::Millisecond).abs() < 2 * Unit::Nanosecond);
+    // The Lagrange interpolation accounts for the curvature: a cubic through the first four records.
+    let lagrange_offset = lagrange.delta_tai_minus_ut1(&midpoint).unwrap();
+    assert!((lagrange_offset - 37_105.199_356 * Unit::Millisecond).abs() < 10 * Unit::Nanosecond);
+
+    // Records which share an epoch are only interpolated once.
+    let duplicates = Ut1Provider::from_eop_data(
+        " EOP2=
+ 59500.0,   0.0,   0.0,  37105.0,
+ 59501.0,   0.0,   0.0,  37104.0,
+ 59501.0,   0.0,   0.0,  37104.0,
+ 59502.0,   0.0,   0.0,  37103.0,
+ $END"
+            .to_string(),
+    )
+    .unwrap();
+    for interpolation in [Ut1Interpolation::Linear, Ut1Interpolation::Lagrange(4)] {
+        let provider = duplicates.clone().with_interpolation(interpolation);
+        let offset = provider
+            .delta_tai_minus_ut1(&Epoch::from_mjd_tai(59501.5))
+            .unwrap();
+        assert!(
+            (offset - 37103.5 * Unit::Millisecond).abs() < 2 * Unit::Nanosecond,
+            "{interpolation:?}: {offset}"
+        );
+    }
+
+    // Across the 2017 January 01 leap second, TAI-UT1 is continuous but UT1-UTC jumps by one second.
+    let eop_data = " EOP2=
+ 57753.0,   0.0,   0.0,  36408.5,
+ 57753.5,   0.0,   0.0,  36408.5,
+ 57754.0,   0.0,   0.0,  36408.5,
+ 57754.5,   0.0,   0.0,  36408.5,
+ $END"
+        .to_string();
+    let removed = Ut1Provider::from_eop_data(eop_data.clone())
+        .unwrap()
+        .with_interpolation(Ut1Interpolation::Linear);
+    let kept = removed.clone().with_leap_second_removal(false);
+
+    // 2017-01-01T00:00:00 TAI is still 2016-12-31T23:59:24 UTC, so the leap second is between that record and the next one.
+    let epoch = Epoch::from_mjd_tai(57754.25);
+    assert_eq!(
+        removed.delta_tai_minus_ut1(&epoch),
+        Some(36408.5 * Unit::Millisecond)
+    );
+    assert_eq!(
+        kept.delta_tai_minus_ut1(&epoch),
+        Some(36908.5 * Unit::Millisecond)
+    );
+    // Both agree on the records themselves.
+    let record = Epoch::from_mjd_tai(57754.0);
+    assert_eq!(
+        kept.delta_tai_minus_ut1(&record),
+        removed.delta_tai_minus_ut1(&record)
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_from_iers() {
+    use hifitime::ut1::{Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, Unit};
+
+    // IERS finals2000A, with observed and predicted values, and a trailing record without UT1-UTC.
+    let finals =
+        Ut1Provider::from_finals_file("data/finals2000A-2021-10-13--2021-10-23.daily").unwrap();
+    assert_eq!(finals.clone().count(), 10);
+    assert_eq!(finals.clone().filter(|record| record.predicted).count(), 3);
+    assert!(!finals[6].predicted);
+    assert!(finals[7].predicted);
+
+    // UT1-UTC = -0.1053665 s on 2021-10-13 at midnight UTC, when TAI-UTC = 37 s.
+    assert_eq!(
+        finals[0].epoch,
+        Epoch::from_gregorian_utc_at_midnight(2021, 10, 13)
+    );
+    assert_eq!(finals[0].delta_tai_minus_ut1, 37.1053665 * Unit::Second);
+    assert_eq!(
+        finals.delta_tai_minus_ut1(&Epoch::from_gregorian_utc_hms(2021, 10, 14, 12, 0, 0)),
+        Some(37.1050474 * Unit::Second)
+    );
+
+    // Malformed UT1-UTC flag
+    assert!(Ut1Provider::from_finals_data(
+        "211013 59500.00 I  0.201332 0.000050  0.265057 0.000050  X-0.1053665 0.0000100"
+            .to_string()
+    )
+    .is_err());
+
+    // EOP 14 C04, whose records are all observed.
+    let c04_14 = Ut1Provider::from_c04_data(
+        "                          EARTH ORIENTATION PARAMETER (EOP) PRODUCT CENTER CENTER (PARIS OBSERVATORY)
+      Date      MJD      x          y        UT1-UTC       LOD         dX        dY        x Err     y Err   UT1-UTC Err  LOD Err     dX Err       dY Err
+                         \"          \"           s           s          \"         \"          \"          \"          s         s            \"           \"
+     (0 h UTC)
+
+2017   1   1  57754   0.033120   0.282050   0.5919212   0.0012064   0.000114  -0.000072   0.000026   0.000025  0.0000100  0.0000091    0.000060    0.000060
+2017   1   2  57755   0.031543   0.282558   0.5905955   0.0013829   0.000114  -0.000076   0.000026   0.000027  0.0000101  0.0000089    0.000060    0.000060"
+            .to_string(),
+    )
+    .unwrap();
+    assert_eq!(c04_14.clone().count(), 2);
+    assert!(c04_14.clone().all(|record| !record.predicted));
+    assert_eq!(
+        c04_14[0].epoch,
+        Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+    );
+    assert_eq!(c04_14[0].delta_tai_minus_ut1, 36.4080788 * Unit::Second);
+
+    // EOP 20 C04, with an hour column and a fractional MJD.
+    let c04_20 = Ut1Provider::from_c04_data(
+        "# EOP 20 C04 - Earth Orientation Parameters
+# YR  MM  DD  HH       MJD        x(\")        y(\")  UT1-UTC(s)       dX(\")      dY(\")       xrt(\")      yrt(\")      LOD(s)        x Er        y Er  UT1-UTC Er      dX Er       dY Er       xrt Er      yrt Er      LOD Er
+2017   1   1   0  57754.00    0.033120    0.282050   0.5919212    0.000114   -0.000072   -0.001578    0.000508   0.0012064    0.000026    0.000025   0.0000100    0.000060    0.000060    0.000050    0.000050   0.0000091"
+            .to_string(),
+    )
+    .unwrap();
+    assert_eq!(c04_20.clone().count(), 1);
+    assert_eq!(c04_20[0].epoch, c04_14[0].epoch);
+    assert_eq!(c04_20[0].delta_tai_minus_ut1, c04_14[0].delta_tai_minus_ut1);
+
+    // The JPL EOP2 header tells which records are predicted.
+    let jpl = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let last_observed = Epoch::from_gregorian_utc_at_midnight(2022, 10, 12);
+    assert!(jpl
+        .clone()
+        .all(|record| record.predicted == (record.epoch > last_observed)));
+    assert!(jpl.clone().any(|record| record.predicted));
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_eop_parameters() {
+    use hifitime::ut1::{Ut1Interpolation, Ut1Provider};
+    use hifitime::{Epoch, Unit};
+
+    let finals =
+        Ut1Provider::from_finals_file("data/finals2000A-2021-10-13--2021-10-23.daily").unwrap();
+    assert_eq!(finals[0].x_p, Some(0.201332));
+    assert_eq!(finals[0].y_p, Some(0.265057));
+    assert_eq!(finals[0].lod, Some(-0.3191 * Unit::Millisecond));
+    assert_eq!(finals[0].dx, Some(0.281 / 1e3));
+    assert_eq!(finals[0].dy, Some(-0.008 / 1e3));
+    // Blank or missing columns are not reported.
+    let truncated = Ut1Provider::from_finals_data(
+        "211013 59500.00 I  0.201332 0.000050  0.265057 0.000050  I-0.1053665 0.0000100"
+            .to_string(),
+    )
+    .unwrap();
+    assert_eq!(truncated[0].x_p, Some(0.201332));
+    assert_eq!(truncated[0].lod, None);
+    assert_eq!(truncated[0].dx, None);
+    assert_eq!(truncated.length_of_day(&truncated[0].epoch), None);
+
+    // The step interpolation uses the latest record strictly before the epoch.
+    let node = Epoch::from_gregorian_utc_at_midnight(2021, 10, 13);
+    let after_node = node + Unit::Nanosecond * 1;
+    assert_eq!(finals.polar_motion(&after_node), Some((0.201332, 0.265057)));
+    assert_eq!(
+        finals.length_of_day(&after_node),
+        Some(-0.3191 * Unit::Millisecond)
+    );
+    assert_eq!(
+        finals.celestial_pole_offsets(&after_node),
+        Some((0.281 / 1e3, -0.008 / 1e3))
+    );
+    // The step interpolation has no data at or before the first record.
+    assert_eq!(finals.polar_motion(&node), None);
+    assert_eq!(finals.polar_motion(&(node - Unit::Hour * 1)), None);
+
+    // Linear interpolation halfway between the first two records.
+    let linear = finals.with_interpolation(Ut1Interpolation::Linear);
+    // The other interpolations cover the first record.
+    assert_eq!(linear.polar_motion(&node), Some((0.201332, 0.265057)));
+    assert_eq!(
+        linear.length_of_day(&node),
+        Some(-0.3191 * Unit::Millisecond)
+    );
+    assert_eq!(
+        linear.celestial_pole_offsets(&node),
+        Some((0.281 / 1e3, -0.008 / 1e3))
+    );
+    assert_eq!(linear.polar_motion(&(node - Unit::Hour * 1)), None);
+    let midpoint = node + Unit::Hour * 12;
+    let (x_p, y_p) = linear.polar_motion(&midpoint).unwrap();
+    assert!((x_p - 0.2003715).abs() < 1e-12);
+    assert!((y_p - 0.264781).abs() < 1e-12);
+    let lod = linear.length_of_day(&midpoint).unwrap();
+    assert!((lod - -0.27585 * Unit::Millisecond).abs() < 2 * Unit::Nanosecond);
+    let (dx, dy) = linear.celestial_pole_offsets(&midpoint).unwrap();
+    assert!((dx - 0.287e-3).abs() < 1e-12);
+    assert!((dy - -0.02e-3).abs() < 1e-12);
+
+    // EOP 14 C04 and EOP 20 C04 columns are in a different order.
+    let c04_14 = Ut1Provider::from_c04_data(
+        "2017   1   1  57754   0.033120   0.282050   0.5919212   0.0012064   0.000114  -0.000072   0.000026   0.000025  0.0000100  0.0000091    0.000060    0.000060"
+            .to_string(),
+    )
+    .unwrap();
+    let c04_20 = Ut1Provider::from_c04_data(
+        "2017   1   1   0  57754.00    0.033120    0.282050   0.5919212    0.000114   -0.000072   -0.001578    0.000508   0.0012064    0.000026    0.000025   0.0000100    0.000060    0.000060    0.000050    0.000050   0.0000091"
+            .to_string(),
+    )
+    .unwrap();
+    for record in [c04_14[0], c04_20[0]] {
+        assert_eq!(record.x_p, Some(0.033120));
+        assert_eq!(record.y_p, Some(0.282050));
+        assert_eq!(record.lod, Some(1.2064 * Unit::Millisecond));
+        assert_eq!(record.dx, Some(0.000114));
+        assert_eq!(record.dy, Some(-0.000072));
+    }
+
+    // JPL EOP2 files have the polar motion and celestial pole offsets in milliarcseconds, but no LOD.
+    let jpl = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    // Just after the first record
+    let first = Epoch::from_mjd_tai(59500.0) + Unit::Nanosecond * 1;
+    let (x_p, y_p) = jpl.polar_motion(&first).unwrap();
+    assert!((x_p - 0.2013317).abs() < 1e-12);
+    assert!((y_p - 0.2650565).abs() < 1e-12);
+    assert_eq!(jpl.length_of_day(&first), None);
+    let (dx, dy) = jpl.celestial_pole_offsets(&first).unwrap();
+    assert!((dx - 0.281e-3).abs() < 1e-12);
+    assert!((dy - -0.008e-3).abs() < 1e-12);
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_download_with() {
+    use hifitime::ut1::{Ut1Provider, JPL_EOP2_URL};
+    use hifitime::{Errors, ParsingErrors};
+
+    // The fetch function is called with the full URL, here served from a local fixture.
+    let provider = Ut1Provider::download_from_jpl_with("221222_190002-marge_eop2.short", |url| {
+        assert_eq!(
+            url,
+            "https://eop2-external.jpl.nasa.gov/eop2/221222_190002-marge_eop2.short"
+        );
+        std::fs::read_to_string("data/eop-2021-10-12--2023-01-04.short")
+            .map_err(|e| Errors::ParseError(ParsingErrors::IOError(e.kind())))
+    })
+    .unwrap();
+    let from_file = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    assert_eq!(provider.clone().count(), from_file.clone().count());
+    assert_eq!(
+        provider[0].delta_tai_minus_ut1,
+        from_file[0].delta_tai_minus_ut1
+    );
+
+    let short = Ut1Provider::download_short_from_jpl_with(|url| {
+        assert_eq!(url, format!("{JPL_EOP2_URL}latest_eop2.short"));
+        Err(Errors::ParseError(ParsingErrors::IOError(
+            std::io::ErrorKind::NotFound,
+        )))
+    });
+    assert_eq!(
+        short.unwrap_err(),
+        Errors::ParseError(ParsingErrors::IOError(std::io::ErrorKind::NotFound))
     );
 }